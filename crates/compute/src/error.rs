@@ -0,0 +1,24 @@
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum Error {
+    #[error("unknown task kind '{0}'")]
+    UnknownKind(String),
+
+    #[error("missing parameter '{0}'")]
+    MissingParam(&'static str),
+
+    #[error("invalid parameter '{name}': {reason}")]
+    InvalidParam { name: &'static str, reason: String },
+
+    #[error("unexpected parameter '{0}'")]
+    UnexpectedParam(String),
+
+    #[error("cancelled")]
+    Cancelled,
+
+    #[error("{0}")]
+    Failed(String),
+}
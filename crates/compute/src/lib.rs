@@ -1,14 +1,14 @@
-pub fn add(left: u64, right: u64) -> u64 {
-    left + right
-}
+//! Work the daemon runs as jobs. Each [`Task`] is parsed from a kind and
+//! string parameters, runs to completion on the calling thread, checks its
+//! [`Context`] often enough to stop soon after being cancelled, and tells
+//! its [`Reporter`] how far it has come.
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+mod context;
+mod error;
+mod progress;
+mod task;
 
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
-}
+pub use context::Context;
+pub use error::{Error, Result};
+pub use progress::{Progress, Reporter};
+pub use task::{KINDS, Task};
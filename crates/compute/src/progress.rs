@@ -0,0 +1,93 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+/// How far a running task has come.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Progress {
+    /// Share of the work done, from 0.0 to 1.0; `None` until the task can tell.
+    pub fraction: Option<f64>,
+    /// What the task is doing, e.g. `sieve`; empty until it says.
+    pub phase: String,
+    /// Task-specific figures, e.g. `remaining_ms`.
+    pub metrics: BTreeMap<String, String>,
+}
+
+type Sink = dyn Fn(&Progress) + Send + Sync;
+
+/// Handle a task reports its [`Progress`] through. Every change is handed to
+/// the sink as a full snapshot, on the task's thread, so sinks should be quick.
+#[derive(Clone)]
+pub struct Reporter {
+    progress: Arc<Mutex<Progress>>,
+    sink: Option<Arc<Sink>>,
+}
+
+impl Reporter {
+    pub fn new(sink: impl Fn(&Progress) + Send + Sync + 'static) -> Self {
+        Self {
+            progress: Arc::default(),
+            sink: Some(Arc::new(sink)),
+        }
+    }
+
+    /// A reporter nobody listens to, for running tasks directly.
+    pub fn discard() -> Self {
+        Self {
+            progress: Arc::default(),
+            sink: None,
+        }
+    }
+
+    /// Changes several things at once and reports them together.
+    pub fn update(&self, change: impl FnOnce(&mut Progress)) {
+        let mut progress = self.progress.lock().unwrap_or_else(|e| e.into_inner());
+        change(&mut progress);
+        if let Some(fraction) = &mut progress.fraction {
+            *fraction = fraction.clamp(0.0, 1.0);
+        }
+        if let Some(sink) = &self.sink {
+            sink(&progress);
+        }
+    }
+
+    pub fn set_fraction(&self, fraction: f64) {
+        self.update(|progress| progress.fraction = Some(fraction));
+    }
+
+    /// Enters a new phase; its fraction starts over unknown.
+    pub fn set_phase(&self, phase: impl Into<String>) {
+        self.update(|progress| {
+            progress.phase = phase.into();
+            progress.fraction = None;
+        });
+    }
+
+    pub fn set_metric(&self, name: impl Into<String>, value: impl ToString) {
+        self.update(|progress| {
+            progress.metrics.insert(name.into(), value.to_string());
+        });
+    }
+
+    /// The latest snapshot.
+    pub fn progress(&self) -> Progress {
+        self.progress
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+}
+
+impl Default for Reporter {
+    fn default() -> Self {
+        Self::discard()
+    }
+}
+
+impl fmt::Debug for Reporter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Reporter")
+            .field("progress", &self.progress())
+            .finish_non_exhaustive()
+    }
+}
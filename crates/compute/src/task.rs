@@ -0,0 +1,318 @@
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+use crate::{Context, Error, Reporter, Result};
+
+/// Task kinds with their parameters, as shown to clients.
+pub const KINDS: &[(&str, &str)] = &[
+    ("sum", "values=<n,n,...>  adds integers"),
+    ("primes", "limit=<n>  counts the primes up to n"),
+    ("sleep", "millis=<n>  waits, for trying out the job system"),
+];
+
+/// Odd numbers per sieve segment, held as a 32 KiB bitset. Cancellation is
+/// checked and progress reported once per segment.
+const SEGMENT_ODDS: u64 = 1 << 18;
+
+/// Longest stretch `sleep` goes without checking for cancellation.
+const SLEEP_STEP: Duration = Duration::from_millis(50);
+
+/// Largest `primes` limit, which keeps a job to a few seconds of CPU time.
+const MAX_PRIMES_LIMIT: u64 = 1 << 28;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Task {
+    Sum { values: Vec<i64> },
+    Primes { limit: u64 },
+    Sleep { duration: Duration },
+}
+
+impl Task {
+    /// Validates `params` for `kind`; unknown parameters are rejected.
+    pub fn parse(kind: &str, params: &BTreeMap<String, String>) -> Result<Self> {
+        let mut params = Params(params.clone());
+        let task = match kind {
+            "sum" => Self::Sum {
+                values: params.list("values")?,
+            },
+            "primes" => {
+                let limit = params.number("limit")?;
+                if limit > MAX_PRIMES_LIMIT {
+                    return Err(Error::InvalidParam {
+                        name: "limit",
+                        reason: format!("must be at most {}", MAX_PRIMES_LIMIT),
+                    });
+                }
+                Self::Primes { limit }
+            }
+            "sleep" => Self::Sleep {
+                duration: Duration::from_millis(params.number("millis")?),
+            },
+            _ => return Err(Error::UnknownKind(kind.to_string())),
+        };
+        params.finish()?;
+        Ok(task)
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Sum { .. } => "sum",
+            Self::Primes { .. } => "primes",
+            Self::Sleep { .. } => "sleep",
+        }
+    }
+
+    /// Runs the task on this thread and returns its result as text.
+    pub fn run(&self, context: &Context, reporter: &Reporter) -> Result<String> {
+        context.check()?;
+        match self {
+            Self::Sum { values } => {
+                let sum = values
+                    .iter()
+                    .try_fold(0i64, |sum, value| sum.checked_add(*value))
+                    .ok_or_else(|| Error::Failed("sum overflows a 64-bit integer".to_string()))?;
+                reporter.update(|progress| {
+                    progress.phase = "add".to_string();
+                    progress.fraction = Some(1.0);
+                    progress
+                        .metrics
+                        .insert("values".to_string(), values.len().to_string());
+                });
+                Ok(sum.to_string())
+            }
+            Self::Primes { limit } => {
+                count_primes(*limit, context, reporter).map(|count| count.to_string())
+            }
+            Self::Sleep { duration } => {
+                reporter.set_phase("sleep");
+                let start = Instant::now();
+                // How far an instant reaches depends on the platform.
+                let deadline = start
+                    .checked_add(*duration)
+                    .ok_or_else(|| Error::InvalidParam {
+                        name: "millis",
+                        reason: "is too long".to_string(),
+                    })?;
+                loop {
+                    let left = deadline.saturating_duration_since(Instant::now());
+                    reporter.update(|progress| {
+                        progress.fraction = Some(if duration.is_zero() {
+                            1.0
+                        } else {
+                            start.elapsed().as_secs_f64() / duration.as_secs_f64()
+                        });
+                        progress
+                            .metrics
+                            .insert("remaining_ms".to_string(), left.as_millis().to_string());
+                    });
+                    if left.is_zero() {
+                        return Ok(format!("slept {}ms", duration.as_millis()));
+                    }
+                    std::thread::sleep(left.min(SLEEP_STEP));
+                    context.check()?;
+                }
+            }
+        }
+    }
+}
+
+/// Segmented sieve of Eratosthenes over odd numbers: memory stays at one
+/// segment plus the primes up to the square root of `limit`.
+fn count_primes(limit: u64, context: &Context, reporter: &Reporter) -> Result<u64> {
+    reporter.set_phase("sieve");
+    if limit < 2 {
+        reporter.set_fraction(1.0);
+        return Ok(0);
+    }
+    let base = odd_primes(limit.isqrt());
+    // Odd number 2i + 1 has index i; the segment holds indexes `start..end`.
+    let odds = limit.div_ceil(2);
+    let mut segment = vec![0u64; (SEGMENT_ODDS / 64) as usize];
+    // 2 is the only even prime.
+    let mut count = 1;
+    let mut start = 0;
+    while start < odds {
+        context.check()?;
+        let end = (start + SEGMENT_ODDS).min(odds);
+        segment.fill(0);
+        for &p in &base {
+            // The first odd multiple of p in the segment, from p * p on.
+            let mut multiple = (p * p).max((2 * start + 1).div_ceil(p) * p);
+            if multiple % 2 == 0 {
+                multiple += p;
+            }
+            // Odd multiples are 2p apart, so their indexes are p apart.
+            let mut index = multiple / 2;
+            while index < end {
+                let bit = index - start;
+                segment[(bit / 64) as usize] |= 1 << (bit % 64);
+                index += p;
+            }
+        }
+        let composite: u64 = segment.iter().map(|word| word.count_ones() as u64).sum();
+        count += end - start - composite;
+        if start == 0 {
+            // Index 0 is the number 1, which is not prime.
+            count -= 1;
+        }
+        start = end;
+        reporter.update(|progress| {
+            progress.fraction = Some(start as f64 / odds as f64);
+            progress
+                .metrics
+                .insert("primes".to_string(), count.to_string());
+        });
+    }
+    Ok(count)
+}
+
+/// Odd primes up to `limit`, by a plain sieve; only used for the square root
+/// of the real limit.
+fn odd_primes(limit: u64) -> Vec<u64> {
+    let mut composite = vec![false; (limit / 2 + 1) as usize];
+    let mut primes = Vec::new();
+    for n in (3..=limit).step_by(2) {
+        if composite[(n / 2) as usize] {
+            continue;
+        }
+        primes.push(n);
+        for multiple in (n * n..=limit).step_by(2 * n as usize) {
+            composite[(multiple / 2) as usize] = true;
+        }
+    }
+    primes
+}
+
+/// Parameters not yet consumed by the task being parsed.
+struct Params(BTreeMap<String, String>);
+
+impl Params {
+    fn take(&mut self, name: &'static str) -> Result<String> {
+        self.0.remove(name).ok_or(Error::MissingParam(name))
+    }
+
+    fn number(&mut self, name: &'static str) -> Result<u64> {
+        let value = self.take(name)?;
+        value.trim().parse().map_err(|e| Error::InvalidParam {
+            name,
+            reason: format!("'{}': {}", value, e),
+        })
+    }
+
+    fn list(&mut self, name: &'static str) -> Result<Vec<i64>> {
+        let value = self.take(name)?;
+        value
+            .split(',')
+            .filter(|item| !item.trim().is_empty())
+            .map(|item| {
+                item.trim().parse().map_err(|e| Error::InvalidParam {
+                    name,
+                    reason: format!("'{}': {}", item.trim(), e),
+                })
+            })
+            .collect()
+    }
+
+    fn finish(self) -> Result<()> {
+        match self.0.into_keys().next() {
+            Some(name) => Err(Error::UnexpectedParam(name)),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    fn run(kind: &str, pairs: &[(&str, &str)]) -> Result<String> {
+        Task::parse(kind, &params(pairs))?.run(&Context::new(), &Reporter::discard())
+    }
+
+    #[test]
+    fn test_runs_tasks() {
+        assert_eq!(run("sum", &[("values", "1, 2,3")]).unwrap(), "6");
+        assert_eq!(run("primes", &[("limit", "100")]).unwrap(), "25");
+        assert_eq!(run("primes", &[("limit", "2")]).unwrap(), "1");
+        assert_eq!(run("primes", &[("limit", "9")]).unwrap(), "4");
+        // Spans two segments.
+        assert_eq!(run("primes", &[("limit", "1000003")]).unwrap(), "78499");
+        assert_eq!(run("sleep", &[("millis", "1")]).unwrap(), "slept 1ms");
+        assert!(matches!(
+            run("sum", &[("values", &format!("{},1", i64::MAX))]),
+            Err(Error::Failed(_))
+        ));
+    }
+
+    #[test]
+    fn test_rejects_invalid_params() {
+        assert_eq!(
+            Task::parse("fold", &params(&[])),
+            Err(Error::UnknownKind("fold".to_string()))
+        );
+        assert_eq!(
+            Task::parse("primes", &params(&[])),
+            Err(Error::MissingParam("limit"))
+        );
+        assert!(matches!(
+            Task::parse("primes", &params(&[("limit", "many")])),
+            Err(Error::InvalidParam { name: "limit", .. })
+        ));
+        assert_eq!(
+            Task::parse("sleep", &params(&[("millis", "1"), ("extra", "x")])),
+            Err(Error::UnexpectedParam("extra".to_string()))
+        );
+        let endless = Task::Sleep {
+            duration: Duration::MAX,
+        };
+        assert!(matches!(
+            endless.run(&Context::new(), &Reporter::discard()),
+            Err(Error::InvalidParam { name: "millis", .. })
+        ));
+    }
+
+    #[test]
+    fn test_cancelled_task_stops() {
+        let context = Context::new();
+        let task = Task::parse("sleep", &params(&[("millis", "60000")])).unwrap();
+        let canceller = context.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(20));
+            canceller.cancel();
+        });
+        assert_eq!(
+            task.run(&context, &Reporter::discard()),
+            Err(Error::Cancelled)
+        );
+    }
+
+    #[test]
+    fn test_reports_progress() {
+        let reports = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = std::sync::Arc::clone(&reports);
+        let reporter = Reporter::new(move |progress| sink.lock().unwrap().push(progress.clone()));
+        let task = Task::parse("primes", &params(&[("limit", "10000000")])).unwrap();
+        assert_eq!(task.run(&Context::new(), &reporter).unwrap(), "664579");
+
+        let reports = reports.lock().unwrap();
+        let sieve: Vec<f64> = reports
+            .iter()
+            .filter(|progress| progress.phase == "sieve")
+            .filter_map(|progress| progress.fraction)
+            .collect();
+        assert!(sieve.len() > 1);
+        assert!(sieve.windows(2).all(|pair| pair[0] <= pair[1]));
+        let last = reports.last().unwrap();
+        assert_eq!(last.phase, "sieve");
+        assert_eq!(last.fraction, Some(1.0));
+        assert_eq!(last.metrics["primes"], "664579");
+        assert_eq!(reporter.progress(), *last);
+    }
+}
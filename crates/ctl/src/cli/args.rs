@@ -1,4 +1,8 @@
-use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+use clap::{Parser, Subcommand, ValueEnum};
+use tracing::Level;
 
 #[derive(Parser)]
 #[command(name = "ffit")]
@@ -18,10 +22,68 @@ pub enum Command {
     Start,
 
     /// Stop the daemon
-    Stop,
+    Stop {
+        /// Reason recorded in the daemon log
+        #[arg(long)]
+        reason: Option<String>,
+
+        /// Seconds the daemon may spend draining in-flight requests
+        #[arg(long, value_name = "SECS")]
+        timeout: Option<u32>,
+    },
+
+    /// Replace the daemon with the installed binary without closing its sockets
+    Upgrade {
+        /// Seconds the old daemon may spend draining in-flight requests
+        #[arg(long, value_name = "SECS")]
+        timeout: Option<u32>,
+    },
 
     /// Check daemon status
-    Status,
+    Status {
+        /// Also print the daemon's effective configuration
+        #[arg(short, long)]
+        verbose: bool,
+    },
+
+    /// Reload the daemon configuration
+    Reload,
+
+    /// Show or change the daemon's log filter
+    LogLevel {
+        /// New filter in RUST_LOG syntax (e.g. "daemon=debug"); omit to show the current one
+        directive: Option<String>,
+    },
+
+    /// Show daemon logs
+    Logs {
+        /// Keep streaming new records as they are logged
+        #[arg(short, long)]
+        follow: bool,
+
+        /// Minimum level to show (error, warn, info, debug, trace)
+        #[arg(long)]
+        level: Option<Level>,
+
+        /// Only show records newer than this (e.g. "10m", "1h 30m")
+        #[arg(long, value_parser = humantime::parse_duration)]
+        since: Option<Duration>,
+
+        /// Number of recent records to show
+        #[arg(short = 'n', long, default_value_t = 50)]
+        lines: usize,
+    },
+
+    /// Show mutating operations recorded in the audit log
+    Audit {
+        /// Only show records from this time on: an RFC 3339 timestamp or a duration ago (e.g. "1h")
+        #[arg(long, value_parser = parse_time)]
+        since: Option<SystemTime>,
+
+        /// Only show records before this time, in the same formats as --since
+        #[arg(long, value_parser = parse_time)]
+        until: Option<SystemTime>,
+    },
 
     /// Ping the daemon
     Ping {
@@ -29,4 +91,144 @@ pub enum Command {
         #[arg(default_value = "hello")]
         message: String,
     },
+
+    /// Submit and track jobs run by the daemon
+    Job {
+        #[command(subcommand)]
+        command: JobCommand,
+    },
+
+    /// Manage bearer tokens for TCP clients
+    Token {
+        #[command(subcommand)]
+        command: TokenCommand,
+    },
+
+    /// Run the daemon as a systemd (Linux) or launchd (macOS) service
+    Service {
+        /// Install a system-wide service instead of one for the current user
+        #[arg(long, global = true)]
+        system: bool,
+
+        #[command(subcommand)]
+        command: ServiceCommand,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum JobCommand {
+    /// Queue the job described by a TOML spec file
+    Submit {
+        /// File with `kind`, optional `max_retries` and `priority`, and a `[params]` table
+        spec_file: PathBuf,
+
+        /// Print only the job ID
+        #[arg(short, long)]
+        quiet: bool,
+    },
+
+    /// List jobs, oldest first
+    List {
+        /// Only show jobs in this state
+        #[arg(long)]
+        state: Option<JobStateArg>,
+
+        /// Number of recent jobs to show; all if omitted
+        #[arg(short = 'n', long)]
+        limit: Option<u32>,
+    },
+
+    /// Show a job, including its result or error
+    Show { id: u64 },
+
+    /// Cancel a queued or running job
+    Cancel { id: u64 },
+
+    /// Wait for a job to finish; exits 0 if it succeeded, 3 if it failed,
+    /// 4 if it was cancelled, 5 if it is still unfinished at the timeout
+    /// and 6 if the daemon stopped while it ran and it is out of retries
+    Wait {
+        id: u64,
+
+        /// Seconds to wait before giving up
+        #[arg(long, value_name = "SECS")]
+        timeout: Option<u32>,
+    },
+
+    /// Show a job's progress live until it finishes; exits like `wait`
+    Watch { id: u64 },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum JobStateArg {
+    Queued,
+    Running,
+    Succeeded,
+    Failed,
+    Cancelled,
+    Interrupted,
+}
+
+#[derive(Subcommand)]
+pub enum TokenCommand {
+    /// Create a token and print it once
+    Create {
+        /// Name used to revoke the token later
+        name: String,
+    },
+
+    /// Revoke a token so the daemon rejects it
+    Revoke {
+        /// Name given when the token was created
+        name: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ServiceCommand {
+    /// Write the service definition, then enable and start it
+    Install,
+
+    /// Stop and disable the service and remove its definition
+    Uninstall,
+
+    /// Show whether the service is installed and running
+    Status,
+}
+
+/// Parses an RFC 3339 timestamp ("2025-01-31T12:00:00Z"), a UTC date ("2025-01-31")
+/// or a duration before now ("1h 30m").
+fn parse_time(value: &str) -> Result<SystemTime, String> {
+    if let Ok(time) = humantime::parse_rfc3339_weak(value)
+        .or_else(|_| humantime::parse_rfc3339_weak(&format!("{} 00:00:00", value)))
+    {
+        return Ok(time);
+    }
+    let ago = humantime::parse_duration(value).map_err(|_| {
+        format!(
+            "expected a timestamp like 2025-01-31T12:00:00Z, a date or a duration like 1h, got '{}'",
+            value
+        )
+    })?;
+    SystemTime::now()
+        .checked_sub(ago)
+        .ok_or_else(|| format!("'{}' reaches too far back", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_time_formats() {
+        let epoch = |secs| SystemTime::UNIX_EPOCH + Duration::from_secs(secs);
+        assert_eq!(parse_time("1970-01-02T00:00:10Z"), Ok(epoch(86_410)));
+        assert_eq!(parse_time("1970-01-02"), Ok(epoch(86_400)));
+
+        let ago = parse_time("1h").unwrap();
+        let expected = SystemTime::now() - Duration::from_secs(3600);
+        assert!(expected.duration_since(ago).unwrap_or_default() < Duration::from_secs(5));
+
+        assert!(parse_time("yesterday").is_err());
+    }
 }
@@ -1,3 +1,3 @@
 mod args;
 
-pub use args::{Args, Command};
+pub use args::{Args, Command, JobCommand, JobStateArg, ServiceCommand, TokenCommand};
@@ -0,0 +1,51 @@
+use std::time::SystemTime;
+
+use console::style;
+use daemon::domain::{AuditQuery, AuditRecord};
+use daemon::infra::audit::AuditLog;
+
+use crate::config::CtlConfig;
+use crate::error::{CtlError, Result};
+use crate::log_dim;
+
+/// Reads the audit file directly, so it works whether or not the daemon is running.
+pub async fn execute(
+    config: &CtlConfig,
+    since: Option<SystemTime>,
+    until: Option<SystemTime>,
+) -> Result<()> {
+    let path = config.audit_file.clone();
+    let query = AuditQuery { since, until };
+    let records = tokio::task::spawn_blocking(move || AuditLog::read(&path, &query))
+        .await
+        .map_err(|e| CtlError::AuditReadFailed(e.to_string()))?
+        .map_err(|e| {
+            CtlError::AuditReadFailed(format!("{}: {}", config.audit_file.display(), e))
+        })?;
+
+    if records.is_empty() {
+        log_dim!("No audit records in {}", config.audit_file.display());
+        return Ok(());
+    }
+    for record in &records {
+        print_record(record);
+    }
+    Ok(())
+}
+
+fn print_record(record: &AuditRecord) {
+    let code = if record.code == "Ok" {
+        style(record.code.as_str()).green()
+    } else {
+        style(record.code.as_str()).red()
+    };
+    println!(
+        "{} {} {} {} {} {}",
+        style(humantime::format_rfc3339_millis(record.time)).dim(),
+        record.method,
+        code,
+        record.caller,
+        style(format!("via {}", record.transport)).dim(),
+        style(format!("{}ms", record.duration_ms)).dim(),
+    );
+}
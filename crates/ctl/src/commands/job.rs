@@ -0,0 +1,517 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use console::{Term, style};
+use daemon::generated::{
+    CancelJobRequest, GetJobRequest, Job, JobProgress, JobState, ListJobsRequest, SubmitJobRequest,
+    WaitJobRequest, WatchJobRequest, job_service_client::JobServiceClient,
+};
+use figment::Figment;
+use figment::providers::{Format, Toml};
+use serde::Deserialize;
+
+use crate::cli::{JobCommand, JobStateArg};
+use crate::config::{CtlConfig, JOB_PROGRESS_BAR_WIDTH};
+use crate::error::{CtlError, Result};
+use crate::infra::grpc::connect;
+use crate::infra::process::is_running;
+use crate::{log_dim, log_info, log_success, log_warn};
+
+/// A job spec file: `kind` plus a `[params]` table. Parameter values may be
+/// strings, numbers, booleans or arrays, which are sent comma-separated.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SpecFile {
+    kind: String,
+    /// Times to rerun the job if the daemon stops while it runs.
+    #[serde(default)]
+    max_retries: u32,
+    /// Higher runs first among the submitter's own queued jobs.
+    #[serde(default)]
+    priority: i32,
+    #[serde(default)]
+    params: BTreeMap<String, ParamValue>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ParamValue {
+    Text(String),
+    Integer(i64),
+    Float(f64),
+    Bool(bool),
+    List(Vec<ParamValue>),
+}
+
+impl std::fmt::Display for ParamValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Text(value) => f.write_str(value),
+            Self::Integer(value) => value.fmt(f),
+            Self::Float(value) => value.fmt(f),
+            Self::Bool(value) => value.fmt(f),
+            Self::List(values) => {
+                let values: Vec<String> = values.iter().map(ToString::to_string).collect();
+                f.write_str(&values.join(","))
+            }
+        }
+    }
+}
+
+pub async fn execute(config: &CtlConfig, command: &JobCommand) -> Result<()> {
+    if !is_running(&config.pid_file) {
+        return Err(CtlError::DaemonNotRunning);
+    }
+
+    // Read the spec before connecting so a bad file fails fast.
+    let spec = match command {
+        JobCommand::Submit { spec_file, .. } => Some(read_spec(spec_file)?),
+        _ => None,
+    };
+
+    let channel = connect(config).await?;
+    let mut client = JobServiceClient::new(channel);
+
+    match command {
+        JobCommand::Submit { quiet, .. } => {
+            let request = spec.expect("spec is read for submit");
+            let job = client
+                .submit(tonic::Request::new(request))
+                .await?
+                .into_inner()
+                .job
+                .unwrap_or_default();
+            if *quiet {
+                println!("{}", job.id);
+            } else if let Some(position) = job.queue_position {
+                log_success!(
+                    "Submitted job {} ({}), #{} in the queue",
+                    job.id,
+                    job.kind,
+                    position
+                );
+            } else {
+                log_success!("Submitted job {} ({})", job.id, job.kind);
+            }
+        }
+        JobCommand::List { state, limit } => {
+            let request = ListJobsRequest {
+                state: state.map_or(JobState::Unspecified, to_state).into(),
+                limit: limit.unwrap_or_default(),
+            };
+            let jobs = client
+                .list(tonic::Request::new(request))
+                .await?
+                .into_inner()
+                .jobs;
+            if jobs.is_empty() {
+                log_dim!("No jobs");
+                return Ok(());
+            }
+            print_table(&jobs);
+        }
+        JobCommand::Show { id } => {
+            let job = client
+                .get(tonic::Request::new(GetJobRequest { id: *id }))
+                .await?
+                .into_inner()
+                .job
+                .unwrap_or_default();
+            print_job(&job);
+        }
+        JobCommand::Cancel { id } => {
+            let job = client
+                .cancel(tonic::Request::new(CancelJobRequest { id: *id }))
+                .await?
+                .into_inner()
+                .job
+                .unwrap_or_default();
+            match job.state() {
+                JobState::Cancelled => log_success!("Cancelled job {}", job.id),
+                _ => log_info!("Cancelling job {}; it stops at its next check", job.id),
+            }
+        }
+        JobCommand::Wait { id, timeout } => {
+            let job = client
+                .wait(tonic::Request::new(WaitJobRequest {
+                    id: *id,
+                    timeout_secs: *timeout,
+                }))
+                .await?
+                .into_inner()
+                .job
+                .unwrap_or_default();
+            return outcome(job);
+        }
+        JobCommand::Watch { id } => {
+            let mut updates = client
+                .watch(tonic::Request::new(WatchJobRequest { id: *id }))
+                .await?
+                .into_inner();
+            let term = Term::stderr();
+            let mut job = Job::default();
+            let mut shown = None;
+            while let Some(update) = updates.message().await? {
+                job = update.job.unwrap_or_default();
+                if term.is_term() {
+                    let line = watch_line(&job, term.size().1.into());
+                    let _ = term.clear_line().and_then(|_| term.write_str(&line));
+                } else {
+                    // Without a terminal to redraw, print only state and phase changes.
+                    let phase = job.progress.as_ref().map(|progress| progress.phase.clone());
+                    if shown.as_ref() != Some(&(job.state, phase.clone())) {
+                        eprintln!("{}", watch_line(&job, usize::MAX));
+                        shown = Some((job.state, phase));
+                    }
+                }
+            }
+            if term.is_term() {
+                let _ = term.clear_line();
+            }
+            // A stream that ends early means the daemon is shutting down.
+            return outcome(job);
+        }
+    }
+    Ok(())
+}
+
+fn read_spec(path: &Path) -> Result<SubmitJobRequest> {
+    let spec: SpecFile = Figment::from(Toml::file_exact(path))
+        .extract()
+        .map_err(|e| CtlError::JobSpecError(format!("{}: {}", path.display(), e)))?;
+    Ok(to_request(spec))
+}
+
+fn to_request(spec: SpecFile) -> SubmitJobRequest {
+    SubmitJobRequest {
+        kind: spec.kind,
+        params: spec
+            .params
+            .into_iter()
+            .map(|(name, value)| (name, value.to_string()))
+            .collect(),
+        max_retries: spec.max_retries,
+        priority: spec.priority,
+    }
+}
+
+/// Prints a finished job's result, or turns any other state into the error
+/// whose exit code reports it.
+fn outcome(job: Job) -> Result<()> {
+    match job.state() {
+        JobState::Succeeded => {
+            log_success!("Job {} succeeded", job.id);
+            println!("{}", job.result);
+            Ok(())
+        }
+        JobState::Failed => Err(CtlError::JobFailed(job.id, job.error)),
+        JobState::Cancelled => Err(CtlError::JobCancelled(job.id, job.error)),
+        JobState::Interrupted => Err(CtlError::JobInterrupted(job.id, job.error)),
+        state => Err(CtlError::JobUnfinished(
+            job.id,
+            state_name(state).to_string(),
+        )),
+    }
+}
+
+fn print_table(jobs: &[Job]) {
+    let rows: Vec<[String; 6]> = jobs
+        .iter()
+        .map(|job| {
+            [
+                job.id.to_string(),
+                state_text(job),
+                job.kind.clone(),
+                humantime::format_rfc3339_seconds(from_unix_millis(job.created_unix_millis))
+                    .to_string(),
+                elapsed(job).map(format_duration).unwrap_or_default(),
+                job.submitter.clone(),
+            ]
+        })
+        .collect();
+    let width = |column: usize, header: &str| {
+        rows.iter()
+            .map(|row| row[column].len())
+            .chain([header.len()])
+            .max()
+            .unwrap_or_default()
+    };
+    let widths = [
+        width(0, "ID"),
+        width(1, "STATE"),
+        width(2, "KIND"),
+        width(3, "CREATED"),
+        width(4, "TIME"),
+    ];
+    // Two leading spaces line the header up with the rows after their marker.
+    println!(
+        "  {}",
+        style(format!(
+            "{:>id$}  {:<state$}  {:<kind$}  {:<created$}  {:<time$}  SUBMITTER",
+            "ID",
+            "STATE",
+            "KIND",
+            "CREATED",
+            "TIME",
+            id = widths[0],
+            state = widths[1],
+            kind = widths[2],
+            created = widths[3],
+            time = widths[4],
+        ))
+        .dim()
+    );
+    for (job, row) in jobs.iter().zip(&rows) {
+        let state = styled_state(job.state(), format!("{:<1$}", row[1], widths[1]));
+        let line = format!(
+            "{:>id$}  {}  {:<kind$}  {:<created$}  {:<time$}  {}",
+            row[0],
+            state,
+            row[2],
+            row[3],
+            row[4],
+            row[5],
+            id = widths[0],
+            kind = widths[2],
+            created = widths[3],
+            time = widths[4],
+        );
+        match job.state() {
+            JobState::Succeeded => log_success!("{}", line),
+            JobState::Running => log_info!("{}", line),
+            JobState::Failed | JobState::Cancelled | JobState::Interrupted => {
+                log_warn!("{}", line)
+            }
+            _ => log_dim!("{}", line),
+        }
+    }
+}
+
+fn print_job(job: &Job) {
+    let state = styled_state(job.state(), state_name(job.state()));
+    match job.state() {
+        JobState::Succeeded => log_success!("Job {} {}", job.id, state),
+        JobState::Running => log_info!("Job {} {}", job.id, state),
+        JobState::Failed | JobState::Cancelled | JobState::Interrupted => {
+            log_warn!("Job {} {}", job.id, state)
+        }
+        _ => log_dim!("Job {} {}", job.id, state),
+    }
+    if let Some(position) = job.queue_position {
+        log_dim!("Queue position: {}", position);
+    }
+    log_dim!("Kind: {}", job.kind);
+    if job.priority != 0 {
+        log_dim!("Priority: {}", job.priority);
+    }
+    let mut params: Vec<_> = job.params.iter().collect();
+    params.sort();
+    for (name, value) in params {
+        log_dim!("Param: {}={}", name, value);
+    }
+    log_dim!("Submitter: {}", job.submitter);
+    if job.attempts > 1 || job.max_retries > 0 {
+        log_dim!("Attempts: {} of {}", job.attempts, job.max_retries + 1);
+    }
+    log_dim!(
+        "Created: {}",
+        humantime::format_rfc3339_millis(from_unix_millis(job.created_unix_millis))
+    );
+    if let Some(started) = job.started_unix_millis {
+        log_dim!(
+            "Started: {}",
+            humantime::format_rfc3339_millis(from_unix_millis(started))
+        );
+    }
+    if let Some(finished) = job.finished_unix_millis {
+        log_dim!(
+            "Finished: {}",
+            humantime::format_rfc3339_millis(from_unix_millis(finished))
+        );
+    }
+    if let Some(elapsed) = elapsed(job) {
+        log_dim!("Time: {}", format_duration(elapsed));
+    }
+    if let Some(progress) = &job.progress {
+        log_dim!("Progress: {}", progress_text(progress));
+    }
+    if !job.error.is_empty() {
+        log_dim!("Error: {}", job.error);
+    }
+    if job.state() == JobState::Succeeded {
+        println!("{}", job.result);
+    }
+}
+
+/// One line on a watched job, cut to `width` columns: a bar while it runs,
+/// its state otherwise.
+fn watch_line(job: &Job, width: usize) -> String {
+    let line = match (&job.progress, job.queue_position) {
+        (Some(progress), _) => match progress.fraction {
+            Some(fraction) => format!(
+                "{} {}",
+                style(bar(fraction, JOB_PROGRESS_BAR_WIDTH)).cyan(),
+                progress_text(progress)
+            ),
+            None => format!(
+                "{} {}",
+                styled_state(job.state(), "running"),
+                progress_text(progress)
+            ),
+        },
+        (None, Some(position)) => format!("{} #{}", styled_state(job.state(), "queued"), position),
+        (None, None) => styled_state(job.state(), state_name(job.state())).to_string(),
+    };
+    let line = format!("Job {} {}", job.id, line);
+    console::truncate_str(&line, width, "…").into_owned()
+}
+
+fn bar(fraction: f64, width: usize) -> String {
+    let filled = ((fraction.clamp(0.0, 1.0) * width as f64).round() as usize).min(width);
+    format!("{}{}", "█".repeat(filled), "░".repeat(width - filled))
+}
+
+/// Percentage, phase and metrics, e.g. `42% sieve primes=1229`.
+fn progress_text(progress: &JobProgress) -> String {
+    let mut parts = Vec::new();
+    if let Some(fraction) = progress.fraction {
+        parts.push(format!("{:>3.0}%", fraction * 100.0));
+    }
+    if !progress.phase.is_empty() {
+        parts.push(progress.phase.clone());
+    }
+    let mut metrics: Vec<_> = progress.metrics.iter().collect();
+    metrics.sort();
+    parts.extend(
+        metrics
+            .into_iter()
+            .map(|(name, value)| format!("{}={}", name, value)),
+    );
+    parts.join(" ")
+}
+
+/// Time spent running: until it finished, or until now if it is still running.
+fn elapsed(job: &Job) -> Option<Duration> {
+    let started = from_unix_millis(job.started_unix_millis?);
+    let end = job
+        .finished_unix_millis
+        .map_or_else(SystemTime::now, from_unix_millis);
+    Some(end.duration_since(started).unwrap_or_default())
+}
+
+fn format_duration(duration: Duration) -> String {
+    let millis = Duration::from_millis(duration.as_millis() as u64);
+    humantime::format_duration(millis).to_string()
+}
+
+fn from_unix_millis(millis: i64) -> SystemTime {
+    SystemTime::UNIX_EPOCH + Duration::from_millis(millis.max(0) as u64)
+}
+
+/// The state, with the queue position of a queued job (e.g. `queued #3`).
+fn state_text(job: &Job) -> String {
+    match job.queue_position {
+        Some(position) => format!("{} #{}", state_name(job.state()), position),
+        None => state_name(job.state()).to_string(),
+    }
+}
+
+fn styled_state<D>(state: JobState, text: D) -> console::StyledObject<D> {
+    match state {
+        JobState::Succeeded => style(text).green(),
+        JobState::Running => style(text).cyan(),
+        JobState::Failed => style(text).red(),
+        JobState::Cancelled | JobState::Interrupted => style(text).yellow(),
+        _ => style(text).dim(),
+    }
+}
+
+fn state_name(state: JobState) -> &'static str {
+    match state {
+        JobState::Unspecified => "unknown",
+        JobState::Queued => "queued",
+        JobState::Running => "running",
+        JobState::Succeeded => "succeeded",
+        JobState::Failed => "failed",
+        JobState::Cancelled => "cancelled",
+        JobState::Interrupted => "interrupted",
+    }
+}
+
+fn to_state(state: JobStateArg) -> JobState {
+    match state {
+        JobStateArg::Queued => JobState::Queued,
+        JobStateArg::Running => JobState::Running,
+        JobStateArg::Succeeded => JobState::Succeeded,
+        JobStateArg::Failed => JobState::Failed,
+        JobStateArg::Cancelled => JobState::Cancelled,
+        JobStateArg::Interrupted => JobState::Interrupted,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spec_file_params_become_strings() {
+        let spec: SpecFile = Figment::from(Toml::string(
+            r#"
+            kind = "sum"
+            max_retries = 2
+            priority = -1
+            [params]
+            values = [1, 2, 3]
+            label = "x"
+            scale = 1.5
+            strict = true
+            "#,
+        ))
+        .extract()
+        .unwrap();
+        let request = to_request(spec);
+        assert_eq!(request.kind, "sum");
+        assert_eq!(request.max_retries, 2);
+        assert_eq!(request.priority, -1);
+        assert_eq!(request.params["values"], "1,2,3");
+        assert_eq!(request.params["label"], "x");
+        assert_eq!(request.params["scale"], "1.5");
+        assert_eq!(request.params["strict"], "true");
+
+        let unknown =
+            Figment::from(Toml::string("kind = \"sum\"\nparam = 1")).extract::<SpecFile>();
+        assert!(unknown.is_err());
+    }
+
+    #[test]
+    fn test_progress_text_and_bar() {
+        let progress = JobProgress {
+            fraction: Some(0.424),
+            phase: "sieve".to_string(),
+            metrics: [("marked", "7"), ("a", "1")]
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+        };
+        assert_eq!(progress_text(&progress), " 42% sieve a=1 marked=7");
+        assert_eq!(bar(0.5, 4), "██░░");
+        assert_eq!(bar(1.5, 4), "████");
+        assert_eq!(progress_text(&JobProgress::default()), "");
+    }
+
+    #[test]
+    fn test_outcome_exit_codes() {
+        let job = |state: JobState| Job {
+            id: 7,
+            state: state.into(),
+            ..Job::default()
+        };
+        assert!(outcome(job(JobState::Succeeded)).is_ok());
+        let code = |state| outcome(job(state)).unwrap_err().exit_code();
+        assert_eq!(code(JobState::Failed), std::process::ExitCode::from(3));
+        assert_eq!(code(JobState::Cancelled), std::process::ExitCode::from(4));
+        assert_eq!(code(JobState::Running), std::process::ExitCode::from(5));
+        assert_eq!(code(JobState::Interrupted), std::process::ExitCode::from(6));
+    }
+}
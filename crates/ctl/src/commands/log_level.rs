@@ -0,0 +1,42 @@
+use daemon::generated::{
+    GetLogLevelRequest, SetLogLevelRequest, daemon_service_client::DaemonServiceClient,
+};
+
+use crate::config::CtlConfig;
+use crate::error::{CtlError, Result};
+use crate::infra::grpc::connect;
+use crate::infra::process::is_running;
+use crate::{log_info, log_success};
+
+pub async fn execute(config: &CtlConfig, directive: Option<&str>) -> Result<()> {
+    if !is_running(&config.pid_file) {
+        return Err(CtlError::DaemonNotRunning);
+    }
+
+    let channel = connect(config).await?;
+    let mut client = DaemonServiceClient::new(channel);
+
+    match directive {
+        Some(directive) => {
+            let response = client
+                .set_log_level(tonic::Request::new(SetLogLevelRequest {
+                    directive: directive.to_string(),
+                }))
+                .await?
+                .into_inner();
+            log_success!(
+                "Log level set to {} (was {})",
+                response.directive,
+                response.previous
+            );
+        }
+        None => {
+            let response = client
+                .get_log_level(tonic::Request::new(GetLogLevelRequest {}))
+                .await?
+                .into_inner();
+            log_info!("{}", response.directive);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,277 @@
+use std::collections::VecDeque;
+use std::io::SeekFrom;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use console::style;
+use daemon::generated::{
+    StreamLogsRequest, StreamLogsResponse, daemon_service_client::DaemonServiceClient,
+};
+use serde::Deserialize;
+use tokio::fs::File;
+use tokio::io::{AsyncBufReadExt, AsyncSeekExt, BufReader};
+use tonic::Code;
+use tracing::{Level, debug};
+
+use crate::config::{CtlConfig, LOG_FILE_POLL_INTERVAL};
+use crate::error::{CtlError, Result};
+use crate::infra::grpc::connect;
+use crate::log_warn;
+
+pub async fn execute(
+    config: &CtlConfig,
+    follow: bool,
+    level: Option<Level>,
+    since: Option<Duration>,
+    lines: usize,
+) -> Result<()> {
+    let since = since.map(|ago| SystemTime::now() - ago);
+
+    match stream(config, follow, level, since, lines).await {
+        Err(CtlError::ConnectionFailed(e)) => {
+            debug!(error = %e, "Log stream unavailable, falling back to the log file");
+            log_warn!(
+                "Daemon is not reachable, reading {}",
+                config.log_file.display()
+            );
+            tail_file(&config.log_file, follow, level, since, lines).await
+        }
+        Err(CtlError::GrpcError(status)) if status.code() == Code::Unavailable => {
+            // The daemon went away mid-stream (e.g. `ffit stop` while following).
+            debug!(error = %status, "Log stream closed by the daemon");
+            Ok(())
+        }
+        result => result,
+    }
+}
+
+async fn stream(
+    config: &CtlConfig,
+    follow: bool,
+    level: Option<Level>,
+    since: Option<SystemTime>,
+    lines: usize,
+) -> Result<()> {
+    let channel = connect(config).await?;
+    let mut client = DaemonServiceClient::new(channel);
+
+    let request = StreamLogsRequest {
+        level: level.map(|level| level.to_string()).unwrap_or_default(),
+        target: String::new(),
+        replay: u32::try_from(lines).unwrap_or(u32::MAX),
+        follow,
+        since_unix_millis: since.map(unix_millis),
+    };
+    let mut records = client
+        .stream_logs(tonic::Request::new(request))
+        .await?
+        .into_inner();
+
+    while let Some(record) = records.message().await? {
+        print_record(&record);
+    }
+    Ok(())
+}
+
+fn unix_millis(time: SystemTime) -> i64 {
+    time.duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or_default()
+}
+
+fn print_record(record: &StreamLogsResponse) {
+    let timestamp =
+        SystemTime::UNIX_EPOCH + Duration::from_millis(record.timestamp_unix_millis.max(0) as u64);
+    let level = match record.level.parse::<Level>() {
+        Ok(Level::ERROR) => style(format!("{:>5}", record.level)).red(),
+        Ok(Level::WARN) => style(format!("{:>5}", record.level)).yellow(),
+        Ok(Level::INFO) => style(format!("{:>5}", record.level)).green(),
+        Ok(Level::DEBUG) => style(format!("{:>5}", record.level)).blue(),
+        _ => style(format!("{:>5}", record.level)).magenta(),
+    };
+
+    let mut line = format!(
+        "{} {} {} {}",
+        style(humantime::format_rfc3339_millis(timestamp)).dim(),
+        level,
+        style(format!("{}:", record.target)).dim(),
+        record.message
+    );
+    for field in &record.fields {
+        line.push_str(&format!(" {}={}", style(&field.name).italic(), field.value));
+    }
+    println!("{}", line);
+}
+
+/// Prints the last `lines` matching lines of the daemon's log file, then keeps
+/// polling for appended lines when `follow` is set.
+async fn tail_file(
+    path: &Path,
+    follow: bool,
+    level: Option<Level>,
+    since: Option<SystemTime>,
+    lines: usize,
+) -> Result<()> {
+    let file = File::open(path)
+        .await
+        .map_err(|e| CtlError::LogReadFailed(format!("{}: {}", path.display(), e)))?;
+    let mut reader = BufReader::new(file);
+
+    let mut tail = VecDeque::with_capacity(lines);
+    let mut buf = String::new();
+    while read_line(&mut reader, &mut buf).await? > 0 {
+        if lines > 0 && file_line_matches(&buf, level, since) {
+            if tail.len() == lines {
+                tail.pop_front();
+            }
+            tail.push_back(strip_ansi(buf.trim_end()));
+        }
+        buf.clear();
+    }
+    for line in tail {
+        println!("{}", line);
+    }
+
+    if !follow {
+        return Ok(());
+    }
+
+    loop {
+        buf.clear();
+        if read_line(&mut reader, &mut buf).await? == 0 {
+            // Start over if the file was truncated or replaced underneath us.
+            let position = reader.stream_position().await.unwrap_or_default();
+            let length = tokio::fs::metadata(path).await.map(|m| m.len()).ok();
+            if length.is_some_and(|length| length < position) {
+                reader
+                    .seek(SeekFrom::Start(0))
+                    .await
+                    .map_err(|e| CtlError::LogReadFailed(format!("{}: {}", path.display(), e)))?;
+            }
+            tokio::time::sleep(LOG_FILE_POLL_INTERVAL).await;
+            continue;
+        }
+        if file_line_matches(&buf, level, since) {
+            println!("{}", strip_ansi(buf.trim_end()));
+        }
+    }
+}
+
+async fn read_line(reader: &mut BufReader<File>, buf: &mut String) -> Result<usize> {
+    reader
+        .read_line(buf)
+        .await
+        .map_err(|e| CtlError::LogReadFailed(e.to_string()))
+}
+
+/// Checks a line written by any of the daemon's formatters against `level` and `since`.
+/// Lines that don't look like records (e.g. panics on stderr) always match.
+fn file_line_matches(line: &str, level: Option<Level>, since: Option<SystemTime>) -> bool {
+    let line = strip_ansi(line);
+    let Some((timestamp, record_level)) = record_header(&line) else {
+        return true;
+    };
+    if since.is_some_and(|since| timestamp < since) {
+        return false;
+    }
+
+    match (level, record_level) {
+        (Some(min), Some(record)) => record <= min,
+        _ => true,
+    }
+}
+
+/// The timestamp and level of a record in any `log_format`:
+/// `<rfc3339> <LEVEL> <target>: ...` for text, `{"timestamp":...,"level":...}`
+/// for json and `ts=<rfc3339> level=<level> ...` for logfmt.
+fn record_header(line: &str) -> Option<(SystemTime, Option<Level>)> {
+    #[derive(Deserialize)]
+    struct JsonHeader {
+        timestamp: String,
+        level: String,
+    }
+
+    let line = line.trim_start();
+    if line.starts_with('{') {
+        let header: JsonHeader = serde_json::from_str(line).ok()?;
+        let timestamp = humantime::parse_rfc3339(&header.timestamp).ok()?;
+        return Some((timestamp, header.level.parse().ok()));
+    }
+
+    let mut words = line.split_whitespace();
+    let first = words.next()?;
+    let (timestamp, level) = match first.strip_prefix("ts=") {
+        Some(timestamp) => (
+            timestamp,
+            words.next().and_then(|w| w.strip_prefix("level=")),
+        ),
+        None => (first, words.next()),
+    };
+    let timestamp = humantime::parse_rfc3339(timestamp).ok()?;
+    Some((timestamp, level.and_then(|level| level.parse().ok())))
+}
+
+/// Removes the color escape sequences the daemon writes when its output is a terminal.
+fn strip_ansi(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            // CSI sequences end with a byte in '@'..='~'.
+            for c in chars.by_ref() {
+                if ('@'..='~').contains(&c) && c != '[' {
+                    break;
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINE: &str = "2026-01-02T03:04:05.123456Z  \u{1b}[32m INFO\u{1b}[0m \u{1b}[2mdaemon::server\u{1b}[0m: started";
+
+    #[test]
+    fn test_strip_ansi() {
+        assert_eq!(
+            strip_ansi(LINE),
+            "2026-01-02T03:04:05.123456Z   INFO daemon::server: started"
+        );
+    }
+
+    #[test]
+    fn test_file_line_matches_level_and_since() {
+        assert!(file_line_matches(LINE, None, None));
+        assert!(file_line_matches(LINE, Some(Level::DEBUG), None));
+        assert!(!file_line_matches(LINE, Some(Level::WARN), None));
+
+        let before = humantime::parse_rfc3339("2026-01-01T00:00:00Z").unwrap();
+        let after = humantime::parse_rfc3339("2026-02-01T00:00:00Z").unwrap();
+        assert!(file_line_matches(LINE, None, Some(before)));
+        assert!(!file_line_matches(LINE, None, Some(after)));
+
+        assert!(file_line_matches(
+            "thread 'main' panicked",
+            Some(Level::ERROR),
+            Some(after)
+        ));
+    }
+
+    #[test]
+    fn test_file_line_matches_json_and_logfmt() {
+        let json = r#"{"timestamp":"2026-01-02T03:04:05.123456Z","level":"DEBUG","fields":{"message":"started"},"target":"daemon::server"}"#;
+        let logfmt = "ts=2026-01-02T03:04:05.123456Z level=debug target=daemon::server msg=started";
+        let after = humantime::parse_rfc3339("2026-02-01T00:00:00Z").unwrap();
+
+        for line in [json, logfmt] {
+            assert!(file_line_matches(line, Some(Level::TRACE), None), "{line}");
+            assert!(!file_line_matches(line, Some(Level::INFO), None), "{line}");
+            assert!(!file_line_matches(line, None, Some(after)), "{line}");
+        }
+    }
+}
@@ -1,9 +1,25 @@
+mod audit;
+mod job;
+mod log_level;
+mod logs;
 mod ping;
+mod reload;
+mod service;
 mod start;
 mod status;
 mod stop;
+mod token;
+mod upgrade;
 
+pub use audit::execute as audit;
+pub use job::execute as job;
+pub use log_level::execute as log_level;
+pub use logs::execute as logs;
 pub use ping::execute as ping;
+pub use reload::execute as reload;
+pub use service::execute as service;
 pub use start::execute as start;
 pub use status::execute as status;
 pub use stop::execute as stop;
+pub use token::execute as token;
+pub use upgrade::execute as upgrade;
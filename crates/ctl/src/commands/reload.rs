@@ -0,0 +1,33 @@
+use daemon::generated::{ReloadConfigRequest, daemon_service_client::DaemonServiceClient};
+
+use crate::config::CtlConfig;
+use crate::error::{CtlError, Result};
+use crate::infra::grpc::connect;
+use crate::infra::process::is_running;
+use crate::{log_dim, log_success, log_warn};
+
+pub async fn execute(config: &CtlConfig) -> Result<()> {
+    if !is_running(&config.pid_file) {
+        return Err(CtlError::DaemonNotRunning);
+    }
+
+    let channel = connect(config).await?;
+    let mut client = DaemonServiceClient::new(channel);
+    let changes = client
+        .reload_config(tonic::Request::new(ReloadConfigRequest {}))
+        .await?
+        .into_inner();
+
+    if changes.applied.is_empty() && changes.requires_restart.is_empty() {
+        log_dim!("Configuration unchanged");
+        return Ok(());
+    }
+
+    for key in &changes.applied {
+        log_success!("Applied: {}", key);
+    }
+    for key in &changes.requires_restart {
+        log_warn!("Restart required: {}", key);
+    }
+    Ok(())
+}
@@ -0,0 +1,160 @@
+use std::ffi::OsStr;
+use std::fs;
+use std::path::PathBuf;
+
+use tokio::process::Command;
+use tracing::debug;
+
+use crate::cli::ServiceCommand;
+use crate::config::{AppPaths, DAEMON_BINARY};
+use crate::error::{CtlError, Result};
+use crate::infra::process::find_daemon_binary;
+use crate::infra::service::{Scope, ServiceManager, ServiceSpec};
+use crate::{log_dim, log_info, log_success, log_warn};
+
+pub async fn execute(command: &ServiceCommand, system: bool) -> Result<()> {
+    let manager = ServiceManager::current().ok_or(CtlError::UnsupportedPlatform)?;
+    let scope = if system { Scope::System } else { Scope::User };
+    let dir = manager.unit_dir(scope).ok_or_else(|| {
+        CtlError::ServiceError("no home directory found for the service file".to_string())
+    })?;
+    let path = dir.join(manager.file_name());
+
+    match command {
+        ServiceCommand::Install => {
+            let spec = ServiceSpec::new(manager, scope, daemon_binary()?, &AppPaths::new());
+            let path = manager
+                .write(&dir, &spec)
+                .map_err(|e| CtlError::ServiceError(format!("{}: {}", path.display(), e)))?;
+            log_success!("Wrote {}", path.display());
+
+            match manager {
+                ServiceManager::Systemd => {
+                    systemctl(scope, ["daemon-reload"]).await?;
+                    systemctl(scope, ["enable", "--now", &manager.service_name()]).await?;
+                }
+                ServiceManager::Launchd => {
+                    launchctl(["load".as_ref(), "-w".as_ref(), path.as_os_str()]).await?;
+                }
+            }
+            log_success!("Enabled and started {}", manager.service_name());
+            if scope == Scope::System && manager == ServiceManager::Systemd {
+                log_dim!(
+                    "The service uses /run/ffit and /etc/ffit; set `socket` in /etc/ffit/config.toml so ffit finds it"
+                );
+            }
+        }
+        ServiceCommand::Uninstall => {
+            if !path.exists() {
+                return Err(CtlError::ServiceError(format!(
+                    "{} is not installed",
+                    path.display()
+                )));
+            }
+            match manager {
+                ServiceManager::Systemd => {
+                    systemctl(scope, ["disable", "--now", &manager.service_name()]).await?;
+                }
+                ServiceManager::Launchd => {
+                    launchctl(["unload".as_ref(), "-w".as_ref(), path.as_os_str()]).await?;
+                }
+            }
+            fs::remove_file(&path)
+                .map_err(|e| CtlError::ServiceError(format!("{}: {}", path.display(), e)))?;
+            if manager == ServiceManager::Systemd {
+                systemctl(scope, ["daemon-reload"]).await?;
+            }
+            log_success!("Removed {}", path.display());
+        }
+        ServiceCommand::Status => {
+            if !path.exists() {
+                log_dim!("Not installed ({} missing)", path.display());
+                return Ok(());
+            }
+            log_info!("Installed: {}", path.display());
+            let state = match manager {
+                ServiceManager::Systemd => {
+                    let mut command = Command::new("systemctl");
+                    if scope == Scope::User {
+                        command.arg("--user");
+                    }
+                    command.args(["is-active", &manager.service_name()]);
+                    query(command).await
+                }
+                ServiceManager::Launchd => {
+                    let mut command = Command::new("launchctl");
+                    command.args(["list", &manager.service_name()]);
+                    query(command).await.map(|_| "loaded".to_string())
+                }
+            };
+            match state {
+                Some(state) if state == "active" || state == "loaded" => {
+                    log_success!("{}: {}", manager.service_name(), state)
+                }
+                Some(state) => log_warn!("{}: {}", manager.service_name(), state),
+                None => log_warn!("{}: not loaded", manager.service_name()),
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The daemon binary as an absolute path, since init systems do not search PATH.
+fn daemon_binary() -> Result<PathBuf> {
+    let binary = PathBuf::from(find_daemon_binary());
+    if binary.is_absolute() {
+        return Ok(binary);
+    }
+    std::env::var_os("PATH")
+        .and_then(|path| {
+            std::env::split_paths(&path)
+                .map(|dir| dir.join(&binary))
+                .find(|candidate| candidate.is_absolute() && candidate.is_file())
+        })
+        .ok_or_else(|| {
+            CtlError::ServiceError(format!(
+                "cannot find {} next to ffit or in PATH",
+                DAEMON_BINARY
+            ))
+        })
+}
+
+async fn systemctl<const N: usize>(scope: Scope, args: [&str; N]) -> Result<()> {
+    let mut command = Command::new("systemctl");
+    if scope == Scope::User {
+        command.arg("--user");
+    }
+    command.args(args);
+    run(command).await
+}
+
+async fn launchctl<const N: usize>(args: [&OsStr; N]) -> Result<()> {
+    let mut command = Command::new("launchctl");
+    command.args(args);
+    run(command).await
+}
+
+async fn run(mut command: Command) -> Result<()> {
+    debug!(command = ?command.as_std(), "Running service manager");
+    let output = command
+        .output()
+        .await
+        .map_err(|e| CtlError::ServiceError(format!("{:?}: {}", command.as_std(), e)))?;
+    if !output.status.success() {
+        return Err(CtlError::ServiceError(format!(
+            "{:?} failed: {}",
+            command.as_std(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(())
+}
+
+/// Stdout of a query, or `None` when it fails to run or prints nothing.
+async fn query(mut command: Command) -> Option<String> {
+    let output = command.output().await.ok()?;
+    if !output.status.success() && output.stdout.is_empty() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string()).filter(|s| !s.is_empty())
+}
@@ -40,12 +40,9 @@ pub async fn execute(config: &CtlConfig) -> Result<()> {
             continue;
         }
 
-        // Verify daemon is actually responding via gRPC
-        debug!(
-            attempt = i + 1,
-            "Attempting gRPC connection to verify daemon"
-        );
-        if grpc::connect(config).await.is_ok() {
+        // Verify daemon reports SERVING over the health service
+        debug!(attempt = i + 1, "Checking daemon health");
+        if grpc::is_serving(config).await {
             log_success!("Started daemon (PID: {})", pid);
             return Ok(());
         }
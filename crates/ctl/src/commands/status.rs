@@ -1,16 +1,62 @@
+use std::time::{Duration, SystemTime};
+
+use daemon::generated::{
+    GetStatusRequest, GetStatusResponse, daemon_service_client::DaemonServiceClient,
+};
+use tracing::debug;
+
 use crate::config::CtlConfig;
 use crate::error::Result;
+use crate::infra::grpc::connect;
 use crate::infra::process::{process_exists, read_pid};
-use crate::{log_dim, log_info};
+use crate::{log_dim, log_info, log_warn};
 
-pub async fn execute(config: &CtlConfig) -> Result<()> {
-    match read_pid(&config.pid_file) {
-        Ok(pid) if process_exists(pid) => {
-            log_info!("Running (PID: {})", pid);
-        }
-        _ => {
-            log_dim!("Not running");
+pub async fn execute(config: &CtlConfig, verbose: bool) -> Result<()> {
+    match get_status(config).await {
+        Ok(status) => print_status(&status, verbose),
+        Err(e) => {
+            debug!(error = %e, "Status RPC failed, falling back to PID check");
+            match read_pid(&config.pid_file) {
+                Ok(pid) if process_exists(pid) => {
+                    log_info!("Running (PID: {})", pid);
+                    log_warn!("Daemon is not answering status requests: {}", e);
+                }
+                _ => {
+                    log_dim!("Not running");
+                }
+            }
         }
     }
     Ok(())
 }
+
+async fn get_status(config: &CtlConfig) -> Result<GetStatusResponse> {
+    let channel = connect(config).await?;
+    let mut client = DaemonServiceClient::new(channel);
+    let response = client
+        .get_status(tonic::Request::new(GetStatusRequest {}))
+        .await?;
+    Ok(response.into_inner())
+}
+
+fn print_status(status: &GetStatusResponse, verbose: bool) {
+    let started_at =
+        SystemTime::UNIX_EPOCH + Duration::from_secs(status.start_time_unix_secs.max(0) as u64);
+    let uptime = Duration::from_secs(status.uptime_secs);
+
+    log_info!("Running (PID: {})", status.pid);
+    log_dim!("Version: {} ({})", status.version, status.git_hash);
+    log_dim!(
+        "Started: {} (up {})",
+        humantime::format_rfc3339_seconds(started_at),
+        humantime::format_duration(uptime)
+    );
+    for listener in &status.listeners {
+        log_dim!("Listening: {}", listener);
+    }
+
+    if verbose {
+        println!();
+        println!("{}", status.config.trim_end());
+    }
+}
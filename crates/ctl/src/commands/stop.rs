@@ -1,3 +1,6 @@
+use std::time::Duration;
+
+use daemon::generated::{ShutdownRequest, daemon_service_client::DaemonServiceClient};
 use tokio::time::sleep;
 use tracing::{debug, warn};
 
@@ -5,21 +8,31 @@ use crate::config::{
     CtlConfig, GRACEFUL_SHUTDOWN_ATTEMPTS, KILL_WAIT_ATTEMPTS, SHUTDOWN_POLL_INTERVAL,
 };
 use crate::error::{CtlError, Result};
+use crate::infra::grpc::connect;
 use crate::infra::process::{
     Signal, is_running, process_exists, read_pid, remove_pid_file, send_signal,
 };
 use crate::{log_success, log_warn};
 
-pub async fn execute(config: &CtlConfig) -> Result<()> {
+pub async fn execute(config: &CtlConfig, reason: Option<&str>, timeout: Option<u32>) -> Result<()> {
     if !is_running(&config.pid_file) {
         return Err(CtlError::DaemonNotRunning);
     }
 
     let pid = read_pid(&config.pid_file)?;
-    debug!(pid, "Sending SIGTERM for graceful shutdown");
-    send_signal(pid, Signal::Term)?;
+    match request_shutdown(config, reason, timeout).await {
+        Ok(()) => debug!(pid, "Daemon accepted shutdown request"),
+        Err(e) if is_unreachable(&e) => {
+            debug!(pid, error = %e, "Shutdown RPC unreachable, sending SIGTERM");
+            send_signal(pid, Signal::Term)?;
+        }
+        Err(e) => return Err(e),
+    }
 
-    for _ in 0..GRACEFUL_SHUTDOWN_ATTEMPTS {
+    let drain = timeout
+        .map(|secs| Duration::from_secs(secs.into()))
+        .unwrap_or_else(|| config.shutdown_timeout());
+    for _ in 0..graceful_attempts(drain) {
         sleep(SHUTDOWN_POLL_INTERVAL).await;
         if !process_exists(pid) {
             remove_pid_file(&config.pid_file);
@@ -46,3 +59,33 @@ pub async fn execute(config: &CtlConfig) -> Result<()> {
         pid
     )))
 }
+
+async fn request_shutdown(
+    config: &CtlConfig,
+    reason: Option<&str>,
+    timeout: Option<u32>,
+) -> Result<()> {
+    let channel = connect(config).await?;
+    let mut client = DaemonServiceClient::new(channel);
+    client
+        .shutdown(tonic::Request::new(ShutdownRequest {
+            reason: reason.unwrap_or_default().to_string(),
+            drain_timeout_secs: timeout,
+        }))
+        .await?;
+    Ok(())
+}
+
+fn is_unreachable(err: &CtlError) -> bool {
+    match err {
+        CtlError::ConnectionFailed(_) => true,
+        CtlError::GrpcError(status) => status.code() == tonic::Code::Unavailable,
+        _ => false,
+    }
+}
+
+/// Polls long enough to cover the daemon's drain timeout, whether requested or configured.
+fn graceful_attempts(drain: Duration) -> u32 {
+    let drain_attempts = (drain.as_millis() / SHUTDOWN_POLL_INTERVAL.as_millis()) as u32;
+    drain_attempts + GRACEFUL_SHUTDOWN_ATTEMPTS
+}
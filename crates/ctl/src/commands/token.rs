@@ -0,0 +1,37 @@
+use daemon::infra::auth::TokenFile;
+
+use crate::cli::TokenCommand;
+use crate::config::CtlConfig;
+use crate::error::{CtlError, Result};
+use crate::{log_dim, log_success};
+
+/// Edits the token file directly; a running daemon picks up changes on the next request.
+pub async fn execute(config: &CtlConfig, command: &TokenCommand) -> Result<()> {
+    let path = config.token_file.as_ref().ok_or_else(|| {
+        CtlError::TokenError("no config directory found; set token_file".to_string())
+    })?;
+    let file = TokenFile::new(path);
+
+    match command {
+        TokenCommand::Create { name } => {
+            let token = file
+                .create(name)
+                .map_err(|e| CtlError::TokenError(e.to_string()))?;
+            log_success!("Created token '{}' in {}", name, path.display());
+            println!("{}", token);
+            log_dim!(
+                "Copy it now, it is not shown again. Clients send it via `token` or FFIT_TOKEN."
+            );
+        }
+        TokenCommand::Revoke { name } => {
+            let revoked = file
+                .revoke(name)
+                .map_err(|e| CtlError::TokenError(e.to_string()))?;
+            if !revoked {
+                return Err(CtlError::TokenError(format!("no token named '{}'", name)));
+            }
+            log_success!("Revoked token '{}'", name);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use daemon::generated::{
+    GetStatusRequest, UpgradeRequest, daemon_service_client::DaemonServiceClient,
+};
+use tracing::debug;
+
+use crate::config::CtlConfig;
+use crate::error::{CtlError, Result};
+use crate::infra::grpc::connect;
+use crate::infra::process::is_running;
+use crate::{log_dim, log_success};
+
+/// Asks the daemon to start the installed binary on its listeners; the old
+/// process drains in the background while the new one already serves.
+pub async fn execute(config: &CtlConfig, timeout: Option<u32>) -> Result<()> {
+    if !is_running(&config.pid_file) {
+        return Err(CtlError::DaemonNotRunning);
+    }
+
+    let channel = connect(config).await?;
+    let mut client = DaemonServiceClient::new(channel);
+    let upgraded = client
+        .upgrade(tonic::Request::new(UpgradeRequest {
+            drain_timeout_secs: timeout,
+        }))
+        .await?
+        .into_inner();
+
+    // A fresh connection reaches the new daemon: the old one stopped accepting.
+    let version = match connect(config).await {
+        Ok(channel) => DaemonServiceClient::new(channel)
+            .get_status(tonic::Request::new(GetStatusRequest {}))
+            .await
+            .map(|status| status.into_inner())
+            .ok()
+            .filter(|status| status.pid == upgraded.pid)
+            .map(|status| status.version),
+        Err(e) => {
+            debug!(error = %e, "Cannot reach upgraded daemon");
+            None
+        }
+    };
+
+    match version {
+        Some(version) => log_success!(
+            "Upgraded daemon to {} (PID: {} -> {})",
+            version,
+            upgraded.previous_pid,
+            upgraded.pid
+        ),
+        None => log_success!(
+            "Upgraded daemon (PID: {} -> {})",
+            upgraded.previous_pid,
+            upgraded.pid
+        ),
+    }
+    log_dim!(
+        "PID {} finishes in-flight requests, then exits",
+        upgraded.previous_pid
+    );
+    Ok(())
+}
@@ -8,3 +8,13 @@ pub const DAEMON_START_POLL_INTERVAL: Duration = Duration::from_millis(100);
 pub const GRACEFUL_SHUTDOWN_ATTEMPTS: u32 = 30;
 pub const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(100);
 pub const KILL_WAIT_ATTEMPTS: u32 = 10;
+pub const LOG_FILE_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// `ffit job wait` exit codes; 1 is any other error and 2 a usage error.
+pub const JOB_FAILED_EXIT_CODE: u8 = 3;
+pub const JOB_CANCELLED_EXIT_CODE: u8 = 4;
+pub const JOB_UNFINISHED_EXIT_CODE: u8 = 5;
+pub const JOB_INTERRUPTED_EXIT_CODE: u8 = 6;
+
+/// Columns the bar of `ffit job watch` takes.
+pub const JOB_PROGRESS_BAR_WIDTH: usize = 30;
@@ -7,7 +7,8 @@ use serde::{Deserialize, Serialize};
 
 use super::constants::CONNECT_TIMEOUT;
 use daemon::config::{
-    AppPaths, ENV_PREFIX, default_pid_file, default_socket_path, default_tcp_addr,
+    AppPaths, ENV_PREFIX, default_audit_file, default_log_file, default_pid_file,
+    default_shutdown_timeout_secs, default_socket_path, default_tcp_addr, default_token_file,
 };
 
 /// CLI configuration.
@@ -24,14 +25,51 @@ pub struct CtlConfig {
     #[serde(default = "default_socket_path")]
     pub socket: PathBuf,
 
+    /// Read by `ffit logs` when the daemon is unreachable.
+    #[serde(default = "default_log_file")]
+    pub log_file: PathBuf,
+
+    /// Audit log written by the daemon, read by `ffit audit`.
+    #[serde(default = "default_audit_file")]
+    pub audit_file: PathBuf,
+
     #[serde(default = "default_tcp_addr")]
     pub tcp_addr: String,
 
     #[serde(default)]
     pub tcp: bool,
 
+    /// Bearer token sent with every request; required over TCP once the daemon has tokens.
+    #[serde(default)]
+    pub token: Option<String>,
+
+    /// Token file managed by `ffit token`, shared with the daemon.
+    #[serde(default = "default_token_file")]
+    pub token_file: Option<PathBuf>,
+
+    /// PEM CA bundle that signed the daemon's certificate; enables TLS for TCP.
+    /// `https://` addresses without it are verified against the system roots.
+    #[serde(default)]
+    pub tls_ca: Option<PathBuf>,
+
+    /// PEM client certificate presented when the daemon sets `tls_client_ca`.
+    #[serde(default)]
+    pub tls_client_cert: Option<PathBuf>,
+
+    /// PEM private key for `tls_client_cert`.
+    #[serde(default)]
+    pub tls_client_key: Option<PathBuf>,
+
+    /// Name to verify the daemon's certificate against instead of the `tcp_addr` host.
+    #[serde(default)]
+    pub tls_server_name: Option<String>,
+
     #[serde(default = "default_connect_timeout_secs")]
     pub connect_timeout_secs: u64,
+
+    /// The daemon's drain timeout, so `ffit stop` waits for it before sending SIGKILL.
+    #[serde(default = "default_shutdown_timeout_secs")]
+    pub shutdown_timeout_secs: u64,
 }
 
 fn default_connect_timeout_secs() -> u64 {
@@ -43,9 +81,18 @@ impl Default for CtlConfig {
         Self {
             pid_file: default_pid_file(),
             socket: default_socket_path(),
+            log_file: default_log_file(),
+            audit_file: default_audit_file(),
             tcp_addr: default_tcp_addr(),
             tcp: false,
+            token: None,
+            token_file: default_token_file(),
+            tls_ca: None,
+            tls_client_cert: None,
+            tls_client_key: None,
+            tls_server_name: None,
             connect_timeout_secs: CONNECT_TIMEOUT.as_secs(),
+            shutdown_timeout_secs: default_shutdown_timeout_secs(),
         }
     }
 }
@@ -82,10 +129,22 @@ impl CtlConfig {
         }
     }
 
+    /// TLS is used for TCP when a CA or client certificate is configured or the address is `https://`.
+    pub fn uses_tls(&self) -> bool {
+        self.tls_ca.is_some()
+            || self.tls_client_cert.is_some()
+            || self.tcp_addr.starts_with("https://")
+    }
+
     pub fn connect_timeout(&self) -> Duration {
         Duration::from_secs(self.connect_timeout_secs)
     }
 
+    /// How long the daemon drains on shutdown when the request sets no timeout.
+    pub fn shutdown_timeout(&self) -> Duration {
+        Duration::from_secs(self.shutdown_timeout_secs)
+    }
+
     pub fn user_config_dir() -> Option<PathBuf> {
         AppPaths::new().config_dir()
     }
@@ -102,7 +161,7 @@ impl CtlConfig {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use daemon::config::DEFAULT_TCP_ADDR;
+    use daemon::config::{DEFAULT_SHUTDOWN_TIMEOUT_SECS, DEFAULT_TCP_ADDR};
 
     #[test]
     fn test_default_config() {
@@ -111,6 +170,7 @@ mod tests {
         assert_eq!(config.socket, default_socket_path());
         assert_eq!(config.pid_file, default_pid_file());
         assert!(!config.tcp);
+        assert_eq!(config.shutdown_timeout_secs, DEFAULT_SHUTDOWN_TIMEOUT_SECS);
     }
 
     #[test]
@@ -5,10 +5,12 @@ mod ctl;
 pub use constants::UDS_DUMMY_URI;
 pub use constants::{
     CONNECT_TIMEOUT, DAEMON_START_POLL_INTERVAL, DAEMON_START_RETRIES, GRACEFUL_SHUTDOWN_ATTEMPTS,
-    KILL_WAIT_ATTEMPTS, SHUTDOWN_POLL_INTERVAL,
+    JOB_CANCELLED_EXIT_CODE, JOB_FAILED_EXIT_CODE, JOB_INTERRUPTED_EXIT_CODE,
+    JOB_PROGRESS_BAR_WIDTH, JOB_UNFINISHED_EXIT_CODE, KILL_WAIT_ATTEMPTS, LOG_FILE_POLL_INTERVAL,
+    SHUTDOWN_POLL_INTERVAL,
 };
 pub use ctl::CtlConfig;
 pub use daemon::config::{
-    APP_NAME, AppPaths, DAEMON_BINARY, DEFAULT_TCP_ADDR, ENV_PREFIX, default_pid_file,
-    default_socket_path, default_tcp_addr,
+    APP_NAME, AppPaths, DAEMON_BINARY, DEFAULT_TCP_ADDR, ENV_PREFIX, default_log_file,
+    default_pid_file, default_socket_path, default_tcp_addr, default_token_file,
 };
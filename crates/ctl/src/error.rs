@@ -1,5 +1,12 @@
+use std::process::ExitCode;
+
 use thiserror::Error;
 
+use crate::config::{
+    JOB_CANCELLED_EXIT_CODE, JOB_FAILED_EXIT_CODE, JOB_INTERRUPTED_EXIT_CODE,
+    JOB_UNFINISHED_EXIT_CODE,
+};
+
 pub type Result<T> = std::result::Result<T, CtlError>;
 
 #[derive(Debug, Error)]
@@ -25,12 +32,55 @@ pub enum CtlError {
     #[error("failed to send signal to process: {0}")]
     SignalFailed(String),
 
+    #[error("TLS configuration error: {0}")]
+    TlsConfig(String),
+
+    #[error("token error: {0}")]
+    TokenError(String),
+
     #[error("connection failed: {0}")]
     ConnectionFailed(String),
 
+    #[error("failed to read log file: {0}")]
+    LogReadFailed(String),
+
+    #[error("failed to read audit log: {0}")]
+    AuditReadFailed(String),
+
+    #[error("service error: {0}")]
+    ServiceError(String),
+
+    #[error("invalid job spec: {0}")]
+    JobSpecError(String),
+
+    #[error("job {0} failed: {1}")]
+    JobFailed(u64, String),
+
+    #[error("job {0} was cancelled: {1}")]
+    JobCancelled(u64, String),
+
+    #[error("job {0} was interrupted: {1}")]
+    JobInterrupted(u64, String),
+
+    #[error("job {0} is still {1}")]
+    JobUnfinished(u64, String),
+
     #[error("gRPC error: {0}")]
     GrpcError(#[from] tonic::Status),
 
     #[error("signal handling not supported on this platform")]
     UnsupportedPlatform,
 }
+
+impl CtlError {
+    /// Job outcomes get their own codes so scripts can tell them apart.
+    pub fn exit_code(&self) -> ExitCode {
+        match self {
+            Self::JobFailed(..) => ExitCode::from(JOB_FAILED_EXIT_CODE),
+            Self::JobCancelled(..) => ExitCode::from(JOB_CANCELLED_EXIT_CODE),
+            Self::JobUnfinished(..) => ExitCode::from(JOB_UNFINISHED_EXIT_CODE),
+            Self::JobInterrupted(..) => ExitCode::from(JOB_INTERRUPTED_EXIT_CODE),
+            _ => ExitCode::FAILURE,
+        }
+    }
+}
@@ -1,18 +1,74 @@
 use std::time::Duration;
 
-use tonic::transport::{Channel, Endpoint};
+use tonic::metadata::{AsciiMetadataValue, MetadataValue};
+use tonic::service::Interceptor;
+use tonic::service::interceptor::InterceptedService;
+use tonic::transport::{Certificate, Channel, ClientTlsConfig, Endpoint, Identity};
+use tonic::{Request, Status};
+use tonic_health::pb::HealthCheckRequest;
+use tonic_health::pb::health_check_response::ServingStatus;
+use tonic_health::pb::health_client::HealthClient;
 use tracing::{debug, warn};
 
 use crate::config::CtlConfig;
 use crate::error::{CtlError, Result};
+use crate::infra::trace_context::TraceContext;
+
+/// Channel to the daemon that attaches [`RequestMetadata`] to every call.
+pub type DaemonChannel = InterceptedService<Channel, RequestMetadata>;
+
+/// Metadata sent with every RPC: the W3C `traceparent` of this invocation
+/// and, when configured, the bearer token.
+#[derive(Clone)]
+pub struct RequestMetadata {
+    traceparent: AsciiMetadataValue,
+    authorization: Option<AsciiMetadataValue>,
+}
+
+impl RequestMetadata {
+    pub fn new(trace: &TraceContext, token: Option<&str>) -> Result<Self> {
+        let authorization = token
+            .map(|token| MetadataValue::try_from(format!("Bearer {}", token.trim())))
+            .transpose()
+            .map_err(|_| {
+                CtlError::TokenError("token contains characters not allowed in a header".into())
+            })?;
+        Ok(Self {
+            // A formatted traceparent is always plain ASCII.
+            traceparent: MetadataValue::try_from(trace.traceparent())
+                .expect("traceparent is valid metadata"),
+            authorization,
+        })
+    }
+}
+
+impl Interceptor for RequestMetadata {
+    fn call(&mut self, mut request: Request<()>) -> std::result::Result<Request<()>, Status> {
+        let metadata = request.metadata_mut();
+        metadata.insert("traceparent", self.traceparent.clone());
+        if let Some(authorization) = &self.authorization {
+            metadata.insert("authorization", authorization.clone());
+        }
+        Ok(request)
+    }
+}
+
+/// Connects to the daemon; every call carries this invocation's trace context and token.
+pub async fn connect(config: &CtlConfig) -> Result<DaemonChannel> {
+    let trace = TraceContext::current();
+    debug!(trace_id = %trace.trace_id(), "Propagating trace context");
+    let metadata = RequestMetadata::new(&trace, config.token.as_deref())?;
+    let channel = connect_channel(config).await?;
+    Ok(InterceptedService::new(channel, metadata))
+}
 
 /// Prefer UDS over TCP unless --tcp flag is set.
-pub async fn connect(config: &CtlConfig) -> Result<Channel> {
+async fn connect_channel(config: &CtlConfig) -> Result<Channel> {
     let timeout = config.connect_timeout();
 
     if config.tcp {
         debug!(address = %config.tcp_addr, "Connecting via TCP (forced)");
-        return connect_tcp(&config.tcp_addr, timeout).await;
+        return connect_tcp(config, timeout).await;
     }
 
     #[cfg(unix)]
@@ -34,7 +90,28 @@ pub async fn connect(config: &CtlConfig) -> Result<Channel> {
     }
 
     debug!(address = %config.tcp_addr, "Connecting via TCP");
-    connect_tcp(&config.tcp_addr, timeout).await
+    connect_tcp(config, timeout).await
+}
+
+/// Ask `grpc.health.v1.Health` for the overall server status.
+pub async fn is_serving(config: &CtlConfig) -> bool {
+    let Ok(channel) = connect(config).await else {
+        return false;
+    };
+
+    let mut client = HealthClient::new(channel);
+    match client
+        .check(HealthCheckRequest {
+            service: String::new(),
+        })
+        .await
+    {
+        Ok(response) => response.into_inner().status() == ServingStatus::Serving,
+        Err(e) => {
+            debug!(error = %e, "Health check failed");
+            false
+        }
+    }
 }
 
 #[cfg(unix)]
@@ -62,12 +139,20 @@ async fn connect_uds(path: &std::path::Path, timeout: Duration) -> Result<Channe
     Ok(channel)
 }
 
-async fn connect_tcp(addr: &str, timeout: Duration) -> Result<Channel> {
-    let uri = to_http_uri(addr);
+async fn connect_tcp(config: &CtlConfig, timeout: Duration) -> Result<Channel> {
+    let tls = client_tls_config(config)?;
+    let uri = to_http_uri(&config.tcp_addr, tls.is_some());
 
-    let channel = Channel::from_shared(uri)
+    let mut endpoint = Channel::from_shared(uri)
         .map_err(|e| CtlError::ConnectionFailed(format!("invalid address: {}", e)))?
-        .connect_timeout(timeout)
+        .connect_timeout(timeout);
+    if let Some(tls) = tls {
+        endpoint = endpoint
+            .tls_config(tls)
+            .map_err(|e| CtlError::TlsConfig(e.to_string()))?;
+    }
+
+    let channel = endpoint
         .connect()
         .await
         .map_err(|e| CtlError::ConnectionFailed(format!("TCP: {}", e)))?;
@@ -75,9 +160,42 @@ async fn connect_tcp(addr: &str, timeout: Duration) -> Result<Channel> {
     Ok(channel)
 }
 
-fn to_http_uri(addr: &str) -> String {
+fn client_tls_config(config: &CtlConfig) -> Result<Option<ClientTlsConfig>> {
+    if !config.uses_tls() {
+        return Ok(None);
+    }
+
+    let mut tls = match &config.tls_ca {
+        Some(ca) => ClientTlsConfig::new().ca_certificate(Certificate::from_pem(read_pem(ca)?)),
+        None => ClientTlsConfig::new().with_native_roots(),
+    };
+    match (&config.tls_client_cert, &config.tls_client_key) {
+        (Some(cert), Some(key)) => {
+            tls = tls.identity(Identity::from_pem(read_pem(cert)?, read_pem(key)?));
+        }
+        (None, None) => {}
+        _ => {
+            return Err(CtlError::TlsConfig(
+                "tls_client_cert and tls_client_key must be set together".to_string(),
+            ));
+        }
+    }
+    if let Some(name) = &config.tls_server_name {
+        tls = tls.domain_name(name);
+    }
+
+    Ok(Some(tls))
+}
+
+fn read_pem(path: &std::path::Path) -> Result<Vec<u8>> {
+    std::fs::read(path).map_err(|e| CtlError::TlsConfig(format!("{}: {}", path.display(), e)))
+}
+
+fn to_http_uri(addr: &str, tls: bool) -> String {
     if addr.starts_with("http://") || addr.starts_with("https://") {
         addr.to_string()
+    } else if tls {
+        format!("https://{}", addr)
     } else {
         format!("http://{}", addr)
     }
@@ -89,11 +207,36 @@ mod tests {
 
     #[test]
     fn test_to_http_uri() {
-        assert_eq!(to_http_uri("[::1]:50051"), "http://[::1]:50051");
+        assert_eq!(to_http_uri("[::1]:50051", false), "http://[::1]:50051");
+        assert_eq!(to_http_uri("[::1]:50051", true), "https://[::1]:50051");
         assert_eq!(
-            to_http_uri("http://localhost:8080"),
+            to_http_uri("http://localhost:8080", false),
             "http://localhost:8080"
         );
-        assert_eq!(to_http_uri("https://example.com"), "https://example.com");
+        assert_eq!(
+            to_http_uri("https://example.com", false),
+            "https://example.com"
+        );
+    }
+
+    #[test]
+    fn test_client_tls_config() {
+        let plain = CtlConfig::default();
+        assert!(client_tls_config(&plain).unwrap().is_none());
+
+        let https = CtlConfig {
+            tcp_addr: "https://ffit.example.com:50051".to_string(),
+            ..CtlConfig::default()
+        };
+        assert!(client_tls_config(&https).unwrap().is_some());
+
+        let missing_key = CtlConfig {
+            tls_client_cert: Some("/nonexistent/client.pem".into()),
+            ..CtlConfig::default()
+        };
+        assert!(matches!(
+            client_tls_config(&missing_key),
+            Err(CtlError::TlsConfig(_))
+        ));
     }
 }
@@ -1,2 +1,4 @@
 pub mod grpc;
 pub mod process;
+pub mod service;
+pub mod trace_context;
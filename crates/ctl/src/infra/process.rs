@@ -16,8 +16,11 @@ pub fn read_pid(pid_file: &Path) -> Result<i32> {
         .map_err(|_| CtlError::InvalidPid(contents.trim().to_string()))
 }
 
+/// Removes a stale PID file; the daemon removes its own when it stops cleanly.
 pub fn remove_pid_file(pid_file: &Path) {
-    if let Err(e) = fs::remove_file(pid_file) {
+    if let Err(e) = fs::remove_file(pid_file)
+        && e.kind() != std::io::ErrorKind::NotFound
+    {
         warn!(path = %pid_file.display(), error = %e, "Failed to remove PID file");
     }
 }
@@ -91,11 +94,13 @@ pub fn send_signal(pid: i32, signal: Signal) -> Result<()> {
 
 #[cfg(unix)]
 pub fn process_exists(pid: i32) -> bool {
+    use nix::errno::Errno;
     use nix::sys::signal::kill;
     use nix::unistd::Pid;
 
-    // Signal 0 checks if process exists without sending a signal
-    kill(Pid::from_raw(pid), None).is_ok()
+    // Signal 0 checks if process exists without sending a signal. EPERM means it
+    // exists but belongs to another user, e.g. a daemon whose socket we may use.
+    !matches!(kill(Pid::from_raw(pid), None), Err(Errno::ESRCH))
 }
 
 #[cfg(windows)]
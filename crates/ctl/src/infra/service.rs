@@ -0,0 +1,300 @@
+//! Service definitions that let the init system run the daemon: a systemd unit
+//! on Linux and a launchd property list on macOS, both rendered from one [`ServiceSpec`].
+
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use directories::BaseDirs;
+
+use crate::config::{APP_NAME, AppPaths, DAEMON_BINARY};
+
+/// Init system that supervises the daemon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceManager {
+    Systemd,
+    Launchd,
+}
+
+/// Whether the service runs for the current user or system-wide as root.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scope {
+    User,
+    System,
+}
+
+/// Everything a service definition needs, independent of the init system.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceSpec {
+    pub scope: Scope,
+    pub binary: PathBuf,
+    pub args: Vec<String>,
+    /// Variables that point the daemon at the same `AppPaths` as the `ffit` that installed it.
+    pub environment: Vec<(String, PathBuf)>,
+    /// Where launchd writes the daemon's stdout and stderr; systemd uses the journal.
+    pub log_dir: PathBuf,
+}
+
+impl ServiceSpec {
+    /// A user service resolves the config, state and runtime directories of `paths`;
+    /// a system service uses /etc, /var/lib and /run.
+    pub fn new(manager: ServiceManager, scope: Scope, binary: PathBuf, paths: &AppPaths) -> Self {
+        let environment = match (manager, scope) {
+            // The user manager does not see variables set in a login shell, so pin
+            // the directories AppPaths derives from (each is `<base>/ffit`).
+            (ServiceManager::Systemd, Scope::User) => [
+                ("XDG_CONFIG_HOME", paths.config_dir()),
+                ("XDG_STATE_HOME", Some(paths.state_dir())),
+                ("XDG_RUNTIME_DIR", Some(paths.runtime_dir())),
+            ]
+            .into_iter()
+            .filter_map(|(name, dir)| {
+                let base = dir?.parent()?.to_path_buf();
+                Some((name.to_string(), base))
+            })
+            .collect(),
+            (ServiceManager::Systemd, Scope::System) => vec![
+                ("XDG_CONFIG_HOME".to_string(), PathBuf::from("/etc")),
+                ("XDG_STATE_HOME".to_string(), PathBuf::from("/var/lib")),
+                ("XDG_RUNTIME_DIR".to_string(), PathBuf::from("/run")),
+            ],
+            // launchd agents inherit HOME, which is all macOS paths depend on.
+            (ServiceManager::Launchd, _) => Vec::new(),
+        };
+        let log_dir = match scope {
+            Scope::User => paths.state_dir(),
+            Scope::System => PathBuf::from("/Library/Logs").join(APP_NAME),
+        };
+
+        Self {
+            scope,
+            binary,
+            args: vec!["--foreground".to_string()],
+            environment,
+            log_dir,
+        }
+    }
+}
+
+impl ServiceManager {
+    /// The init system of this platform, if it is supported.
+    pub fn current() -> Option<Self> {
+        if cfg!(target_os = "macos") {
+            Some(Self::Launchd)
+        } else if cfg!(target_os = "linux") {
+            Some(Self::Systemd)
+        } else {
+            None
+        }
+    }
+
+    /// systemd unit name or launchd label.
+    pub fn service_name(self) -> String {
+        match self {
+            Self::Systemd => format!("{}.service", DAEMON_BINARY),
+            Self::Launchd => DAEMON_BINARY.to_string(),
+        }
+    }
+
+    pub fn file_name(self) -> String {
+        match self {
+            Self::Systemd => self.service_name(),
+            Self::Launchd => format!("{}.plist", DAEMON_BINARY),
+        }
+    }
+
+    /// Directory the init system loads definitions for `scope` from.
+    pub fn unit_dir(self, scope: Scope) -> Option<PathBuf> {
+        match (self, scope) {
+            (Self::Systemd, Scope::User) => {
+                BaseDirs::new().map(|dirs| dirs.config_dir().join("systemd").join("user"))
+            }
+            (Self::Systemd, Scope::System) => Some(PathBuf::from("/etc/systemd/system")),
+            (Self::Launchd, Scope::User) => {
+                BaseDirs::new().map(|dirs| dirs.home_dir().join("Library").join("LaunchAgents"))
+            }
+            (Self::Launchd, Scope::System) => Some(PathBuf::from("/Library/LaunchDaemons")),
+        }
+    }
+
+    pub fn render(self, spec: &ServiceSpec) -> String {
+        match self {
+            Self::Systemd => systemd_unit(spec),
+            Self::Launchd => launchd_plist(spec),
+        }
+    }
+
+    /// Writes the definition for `spec` into `dir`, creating it if needed.
+    pub fn write(self, dir: &Path, spec: &ServiceSpec) -> io::Result<PathBuf> {
+        fs::create_dir_all(dir)?;
+        let path = dir.join(self.file_name());
+        fs::write(&path, self.render(spec))?;
+        Ok(path)
+    }
+}
+
+fn systemd_unit(spec: &ServiceSpec) -> String {
+    let mut exec = systemd_quote(&spec.binary.to_string_lossy());
+    for arg in &spec.args {
+        exec.push(' ');
+        exec.push_str(&systemd_quote(arg));
+    }
+
+    let mut unit = String::new();
+    unit.push_str("# Generated by `ffit service install`; rerun it instead of editing.\n\n");
+    unit.push_str("[Unit]\nDescription=ffit daemon\nAfter=network.target\n\n");
+    unit.push_str("[Service]\nType=notify\n");
+    let _ = writeln!(unit, "ExecStart={}", exec);
+    unit.push_str("ExecReload=/bin/kill -HUP $MAINPID\nRestart=on-failure\nWatchdogSec=30\n");
+    for (name, value) in &spec.environment {
+        let _ = writeln!(
+            unit,
+            "Environment={}",
+            systemd_quote(&format!("{}={}", name, value.display()))
+        );
+    }
+    if spec.scope == Scope::System {
+        let _ = writeln!(unit, "RuntimeDirectory={}", APP_NAME);
+        let _ = writeln!(unit, "StateDirectory={}", APP_NAME);
+    }
+    let target = match spec.scope {
+        Scope::User => "default.target",
+        Scope::System => "multi-user.target",
+    };
+    let _ = write!(unit, "\n[Install]\nWantedBy={}\n", target);
+    unit
+}
+
+/// Quotes a word for a unit file, where `%` starts a specifier.
+fn systemd_quote(word: &str) -> String {
+    let word = word.replace('%', "%%");
+    if word.is_empty() || word.contains(|c: char| c.is_whitespace() || "\"'\\".contains(c)) {
+        format!("\"{}\"", word.replace('\\', "\\\\").replace('"', "\\\""))
+    } else {
+        word
+    }
+}
+
+fn launchd_plist(spec: &ServiceSpec) -> String {
+    let string = |value: &str| format!("<string>{}</string>", xml_escape(value));
+
+    let mut plist = String::new();
+    plist.push_str(concat!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+        "<!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" ",
+        "\"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n",
+        "<!-- Generated by `ffit service install`; rerun it instead of editing. -->\n",
+        "<plist version=\"1.0\">\n<dict>\n",
+    ));
+    let _ = writeln!(
+        plist,
+        "  <key>Label</key>\n  {}",
+        string(&ServiceManager::Launchd.service_name())
+    );
+    plist.push_str("  <key>ProgramArguments</key>\n  <array>\n");
+    let _ = writeln!(plist, "    {}", string(&spec.binary.to_string_lossy()));
+    for arg in &spec.args {
+        let _ = writeln!(plist, "    {}", string(arg));
+    }
+    plist.push_str("  </array>\n");
+    if !spec.environment.is_empty() {
+        plist.push_str("  <key>EnvironmentVariables</key>\n  <dict>\n");
+        for (name, value) in &spec.environment {
+            let _ = writeln!(
+                plist,
+                "    <key>{}</key>\n    {}",
+                xml_escape(name),
+                string(&value.to_string_lossy())
+            );
+        }
+        plist.push_str("  </dict>\n");
+    }
+    plist.push_str("  <key>RunAtLoad</key>\n  <true/>\n");
+    // Restart after crashes, but not after `ffit stop`.
+    plist.push_str("  <key>KeepAlive</key>\n  <dict>\n    <key>SuccessfulExit</key>\n    <false/>\n  </dict>\n");
+    let log = spec.log_dir.join(format!("{}.out", DAEMON_BINARY));
+    let _ = writeln!(
+        plist,
+        "  <key>StandardOutPath</key>\n  {0}\n  <key>StandardErrorPath</key>\n  {0}",
+        string(&log.to_string_lossy())
+    );
+    plist.push_str("</dict>\n</plist>\n");
+    plist
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(manager: ServiceManager, scope: Scope) -> ServiceSpec {
+        ServiceSpec::new(
+            manager,
+            scope,
+            PathBuf::from("/opt/ffit & co/bin/ffit-daemon"),
+            &AppPaths::new(),
+        )
+    }
+
+    #[test]
+    fn test_systemd_user_unit() {
+        let unit = ServiceManager::Systemd.render(&spec(ServiceManager::Systemd, Scope::User));
+
+        assert!(unit.contains("Type=notify\n"));
+        assert!(unit.contains("ExecStart=\"/opt/ffit & co/bin/ffit-daemon\" --foreground\n"));
+        let runtime = AppPaths::new().runtime_dir();
+        assert!(unit.contains(&format!(
+            "Environment=XDG_RUNTIME_DIR={}\n",
+            runtime.parent().unwrap().display()
+        )));
+        assert!(unit.contains("WantedBy=default.target\n"));
+        assert!(!unit.contains("RuntimeDirectory="));
+    }
+
+    #[test]
+    fn test_systemd_system_unit() {
+        let unit = ServiceManager::Systemd.render(&spec(ServiceManager::Systemd, Scope::System));
+
+        assert!(unit.contains("Environment=XDG_RUNTIME_DIR=/run\n"));
+        assert!(unit.contains("RuntimeDirectory=ffit\nStateDirectory=ffit\n"));
+        assert!(unit.contains("WantedBy=multi-user.target\n"));
+
+        assert_eq!(systemd_quote("50%"), "50%%");
+        assert_eq!(systemd_quote(r#"a "b""#), r#""a \"b\"""#);
+    }
+
+    #[test]
+    fn test_launchd_plist() {
+        let plist = ServiceManager::Launchd.render(&spec(ServiceManager::Launchd, Scope::User));
+
+        assert!(plist.contains("<key>Label</key>\n  <string>ffit-daemon</string>"));
+        assert!(plist.contains(
+            "    <string>/opt/ffit &amp; co/bin/ffit-daemon</string>\n    <string>--foreground</string>\n"
+        ));
+        let log = AppPaths::new().state_dir().join("ffit-daemon.out");
+        assert!(plist.contains(&format!("<string>{}</string>", log.display())));
+        assert!(!plist.contains("EnvironmentVariables"));
+        assert!(plist.ends_with("</plist>\n"));
+    }
+
+    #[test]
+    fn test_writes_into_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let units = dir.path().join("units");
+
+        for manager in [ServiceManager::Systemd, ServiceManager::Launchd] {
+            let spec = spec(manager, Scope::User);
+            let path = manager.write(&units, &spec).unwrap();
+            assert_eq!(path, units.join(manager.file_name()));
+            assert_eq!(fs::read_to_string(&path).unwrap(), manager.render(&spec));
+        }
+    }
+}
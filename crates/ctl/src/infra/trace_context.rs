@@ -0,0 +1,114 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::OnceLock;
+use std::time::SystemTime;
+
+/// Environment variable carrying a W3C `traceparent` to continue, e.g. from a CI job.
+pub const TRACEPARENT_ENV: &str = "TRACEPARENT";
+
+/// W3C trace context shared by every RPC of one `ffit` invocation,
+/// so the daemon's spans for a command land in a single trace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceContext {
+    trace_id: u128,
+    parent_id: u64,
+    sampled: bool,
+}
+
+impl TraceContext {
+    /// Continues `TRACEPARENT` when it is valid, otherwise starts a new sampled trace.
+    pub fn current() -> Self {
+        static CURRENT: OnceLock<TraceContext> = OnceLock::new();
+        *CURRENT.get_or_init(|| {
+            std::env::var(TRACEPARENT_ENV)
+                .ok()
+                .and_then(|value| Self::parse(&value))
+                .unwrap_or_else(Self::new_root)
+        })
+    }
+
+    pub fn new_root() -> Self {
+        Self {
+            trace_id: (u128::from(random_u64()) << 64) | u128::from(random_u64()),
+            parent_id: random_u64(),
+            sampled: true,
+        }
+    }
+
+    /// Parses a version 00 `traceparent` header value.
+    pub fn parse(value: &str) -> Option<Self> {
+        let mut parts = value.trim().split('-');
+        let (version, trace_id, parent_id, flags) =
+            (parts.next()?, parts.next()?, parts.next()?, parts.next()?);
+        if version != "00" || parts.next().is_some() {
+            return None;
+        }
+        if trace_id.len() != 32 || parent_id.len() != 16 || flags.len() != 2 {
+            return None;
+        }
+
+        let context = Self {
+            trace_id: u128::from_str_radix(trace_id, 16).ok()?,
+            parent_id: u64::from_str_radix(parent_id, 16).ok()?,
+            sampled: u8::from_str_radix(flags, 16).ok()? & 0x01 == 0x01,
+        };
+        // All-zero ids are invalid per the spec.
+        (context.trace_id != 0 && context.parent_id != 0).then_some(context)
+    }
+
+    pub fn trace_id(&self) -> String {
+        format!("{:032x}", self.trace_id)
+    }
+
+    pub fn traceparent(&self) -> String {
+        format!(
+            "00-{:032x}-{:016x}-{:02x}",
+            self.trace_id,
+            self.parent_id,
+            u8::from(self.sampled)
+        )
+    }
+}
+
+/// Non-zero id from the per-process random hasher keys; ids need to be unique, not secret.
+fn random_u64() -> u64 {
+    let mut hasher = RandomState::new().build_hasher();
+    let nanos = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    hasher.write_u128(nanos);
+    hasher.write_u32(std::process::id());
+    hasher.finish().max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_traceparent_round_trip() {
+        let value = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+        let context = TraceContext::parse(value).unwrap();
+        assert_eq!(context.trace_id(), "4bf92f3577b34da6a3ce929d0e0e4736");
+        assert_eq!(context.traceparent(), value);
+
+        let root = TraceContext::new_root();
+        assert_eq!(TraceContext::parse(&root.traceparent()), Some(root));
+        assert_ne!(root, TraceContext::new_root());
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid() {
+        for value in [
+            "",
+            "01-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            "00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e473-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-extra",
+        ] {
+            assert_eq!(TraceContext::parse(value), None, "{}", value);
+        }
+    }
+}
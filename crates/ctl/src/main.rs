@@ -19,7 +19,7 @@ async fn main() -> ExitCode {
 
     if let Err(e) = run().await {
         log_error!("{}", e);
-        return ExitCode::FAILURE;
+        return e.exit_code();
     }
     ExitCode::SUCCESS
 }
@@ -30,8 +30,23 @@ async fn run() -> ctl::error::Result<()> {
 
     match &args.command {
         Command::Start => commands::start(&config).await,
-        Command::Stop => commands::stop(&config).await,
-        Command::Status => commands::status(&config).await,
+        Command::Stop { reason, timeout } => {
+            commands::stop(&config, reason.as_deref(), *timeout).await
+        }
+        Command::Upgrade { timeout } => commands::upgrade(&config, *timeout).await,
+        Command::Status { verbose } => commands::status(&config, *verbose).await,
+        Command::Reload => commands::reload(&config).await,
+        Command::LogLevel { directive } => commands::log_level(&config, directive.as_deref()).await,
+        Command::Logs {
+            follow,
+            level,
+            since,
+            lines,
+        } => commands::logs(&config, *follow, *level, *since, *lines).await,
+        Command::Audit { since, until } => commands::audit(&config, *since, *until).await,
         Command::Ping { message } => commands::ping(&config, message).await,
+        Command::Job { command } => commands::job(&config, command).await,
+        Command::Token { command } => commands::token(&config, command).await,
+        Command::Service { system, command } => commands::service(command, *system).await,
     }
 }
@@ -1,5 +1,7 @@
 use clap::Parser;
 
+use crate::config::{ConfigOverrides, LogFormat};
+
 #[derive(Parser, Debug)]
 #[command(name = "ffit-daemon")]
 #[command(about = "ffit daemon process")]
@@ -7,4 +9,17 @@ pub struct Args {
     /// Run in foreground (don't daemonize)
     #[arg(short, long)]
     pub foreground: bool,
+
+    /// Log output format: text, json or logfmt (overrides `log_format` in the config)
+    #[arg(long, value_name = "FORMAT")]
+    pub log_format: Option<LogFormat>,
+}
+
+impl Args {
+    pub fn overrides(&self) -> ConfigOverrides {
+        ConfigOverrides {
+            foreground: self.foreground,
+            log_format: self.log_format,
+        }
+    }
 }
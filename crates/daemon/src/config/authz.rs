@@ -0,0 +1,31 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// The `[authz]` section: which RPC methods each caller may invoke.
+///
+/// Enforced once any role is defined; until then every authenticated caller
+/// may call everything. Callers are bound to roles by principal: `uid:<n>` or
+/// `user:<name>` on the Unix socket, `token:<name>` for bearer tokens and
+/// `cert:<subject>` for TLS client certificates.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AuthzConfig {
+    /// Role name to the methods it allows, as `<service>/<method>` patterns
+    /// where `*` matches any run of characters (e.g. `daemon.v1.*/Get*`).
+    #[serde(default)]
+    pub roles: BTreeMap<String, Vec<String>>,
+
+    /// Principal to role name.
+    #[serde(default)]
+    pub bindings: BTreeMap<String, String>,
+
+    /// Role for callers without a binding; unset denies them.
+    #[serde(default)]
+    pub default_role: Option<String>,
+}
+
+impl AuthzConfig {
+    pub fn is_enabled(&self) -> bool {
+        !self.roles.is_empty()
+    }
+}
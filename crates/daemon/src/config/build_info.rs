@@ -0,0 +1,4 @@
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Short commit hash captured by `build.rs`, or "unknown" outside a git checkout.
+pub const GIT_HASH: &str = env!("FFIT_GIT_HASH");
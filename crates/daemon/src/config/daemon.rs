@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 use figment::Figment;
@@ -5,10 +6,36 @@ use figment::providers::{Env, Format, Serialized, Toml};
 use serde::{Deserialize, Serialize};
 
 use super::{
-    AppPaths, ENV_PREFIX, default_lock_file, default_log_file, default_pid_file,
-    default_socket_path, default_tcp_addr, default_workdir,
+    AppPaths, AuthzConfig, ENV_PREFIX, JobQuotaConfig, LogFormat, default_audit_file,
+    default_job_store_file, default_lock_file, default_log_buffer_size, default_log_file,
+    default_log_level, default_log_max_files, default_log_max_size_bytes,
+    default_max_concurrent_jobs, default_max_finished_jobs, default_pid_file,
+    default_shutdown_timeout_secs, default_socket_path, default_tcp_addr, default_token_file,
+    default_trace_sample_ratio, default_workdir,
 };
 
+const REDACTED: &str = "<redacted>";
+
+/// Keys (or whole sections) that take effect on reload without a restart.
+pub const RELOADABLE_KEYS: &[&str] = &[
+    "tcp_addr",
+    "socket",
+    "log_level",
+    "shutdown_timeout_secs",
+    "max_concurrent_jobs",
+    "max_finished_jobs",
+    "job_quotas",
+    "authz",
+];
+
+/// Settings given on the daemon's command line. They win over every config
+/// source, at startup and again on each reload.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ConfigOverrides {
+    pub foreground: bool,
+    pub log_format: Option<LogFormat>,
+}
+
 /// Daemon configuration.
 ///
 /// Priority (lowest to highest):
@@ -26,6 +53,50 @@ pub struct DaemonConfig {
     #[serde(default = "default_socket_path")]
     pub socket: PathBuf,
 
+    /// PEM certificate chain served on the TCP listener; enables TLS together with `tls_key`.
+    #[serde(default)]
+    pub tls_cert: Option<PathBuf>,
+
+    /// PEM private key for `tls_cert`.
+    #[serde(default)]
+    pub tls_key: Option<PathBuf>,
+
+    /// PEM CA bundle; when set, TCP clients must present a certificate it signed.
+    #[serde(default)]
+    pub tls_client_ca: Option<PathBuf>,
+
+    /// Bearer tokens accepted on the TCP listener, in addition to those in `token_file`.
+    #[serde(default)]
+    pub auth_tokens: Vec<String>,
+
+    /// Tokens managed by `ffit token`; once it exists, TCP clients must authenticate.
+    #[serde(default = "default_token_file")]
+    pub token_file: Option<PathBuf>,
+
+    /// Users allowed on the Unix socket besides the daemon's own user and root.
+    #[serde(default)]
+    pub allowed_uids: Vec<u32>,
+
+    /// Groups (names or gids) whose members may use the Unix socket.
+    #[serde(default)]
+    pub allowed_groups: Vec<String>,
+
+    /// Octal mode set on the Unix socket after binding (e.g. "0660"); unset keeps the umask default.
+    #[serde(default)]
+    pub socket_mode: Option<String>,
+
+    /// User (name or uid) the Unix socket is chowned to after binding.
+    #[serde(default)]
+    pub socket_owner: Option<String>,
+
+    /// Group (name or gid) the Unix socket is chowned to after binding.
+    #[serde(default)]
+    pub socket_group: Option<String>,
+
+    /// Address of the HTTP `/metrics` listener; unset disables it.
+    #[serde(default)]
+    pub metrics_addr: Option<String>,
+
     #[serde(default = "default_pid_file")]
     pub pid_file: PathBuf,
 
@@ -35,8 +106,74 @@ pub struct DaemonConfig {
     #[serde(default = "default_log_file")]
     pub log_file: PathBuf,
 
+    /// JSON-lines record of mutating RPCs, read by `ffit audit`.
+    #[serde(default = "default_audit_file")]
+    pub audit_file: PathBuf,
+
+    /// JSON-lines record of jobs and their results, recovered on startup.
+    #[serde(default = "default_job_store_file")]
+    pub job_store_file: PathBuf,
+
     #[serde(default = "default_workdir")]
     pub workdir: PathBuf,
+
+    /// Startup log filter in `RUST_LOG` syntax; `RUST_LOG` itself takes precedence.
+    #[serde(default = "default_log_level")]
+    pub log_level: String,
+
+    #[serde(default)]
+    pub log_format: LogFormat,
+
+    /// Recent log records kept in memory for `ffit logs` replay.
+    #[serde(default = "default_log_buffer_size")]
+    pub log_buffer_size: usize,
+
+    /// Rotate `log_file` once it reaches this size; 0 disables size-based rotation.
+    #[serde(default = "default_log_max_size_bytes")]
+    pub log_max_size_bytes: u64,
+
+    /// Rotate `log_file` this often; 0 disables time-based rotation.
+    #[serde(default)]
+    pub log_rotate_interval_secs: u64,
+
+    /// Rotated files to keep (`ffit.log.1` is the newest).
+    #[serde(default = "default_log_max_files")]
+    pub log_max_files: usize,
+
+    /// Gzip rotated files (`ffit.log.1.gz`).
+    #[serde(default)]
+    pub log_compress: bool,
+
+    /// OTLP/gRPC collector receiving spans (requires the `otel` feature); unset disables export.
+    #[serde(default)]
+    pub otlp_endpoint: Option<String>,
+
+    /// Fraction of new traces exported; traces continued from `ffit` follow the caller's decision.
+    #[serde(default = "default_trace_sample_ratio")]
+    pub trace_sample_ratio: f64,
+
+    /// How long shutdown waits for in-flight requests and running jobs before
+    /// abandoning them; 0 abandons them at once. `ffit stop` waits this long
+    /// plus a margin.
+    #[serde(default = "default_shutdown_timeout_secs")]
+    pub shutdown_timeout_secs: u64,
+
+    /// Jobs run at once across all callers; defaults to the number of CPUs.
+    #[serde(default = "default_max_concurrent_jobs")]
+    pub max_concurrent_jobs: usize,
+
+    /// Finished jobs kept for `ffit job list` and `show`; the ones that
+    /// finished first are dropped first. At least one is kept.
+    #[serde(default = "default_max_finished_jobs")]
+    pub max_finished_jobs: usize,
+
+    /// Per-caller job limits and shares; see [`JobQuotaConfig`].
+    #[serde(default)]
+    pub job_quotas: JobQuotaConfig,
+
+    /// Role-based access to RPC methods; see [`AuthzConfig`].
+    #[serde(default)]
+    pub authz: AuthzConfig,
 }
 
 impl Default for DaemonConfig {
@@ -45,10 +182,37 @@ impl Default for DaemonConfig {
             foreground: false,
             tcp_addr: default_tcp_addr(),
             socket: default_socket_path(),
+            tls_cert: None,
+            tls_key: None,
+            tls_client_ca: None,
+            auth_tokens: Vec::new(),
+            token_file: default_token_file(),
+            allowed_uids: Vec::new(),
+            allowed_groups: Vec::new(),
+            socket_mode: None,
+            socket_owner: None,
+            socket_group: None,
+            metrics_addr: None,
             pid_file: default_pid_file(),
             lock_file: default_lock_file(),
             log_file: default_log_file(),
+            audit_file: default_audit_file(),
+            job_store_file: default_job_store_file(),
             workdir: default_workdir(),
+            log_level: default_log_level(),
+            log_format: LogFormat::default(),
+            log_buffer_size: default_log_buffer_size(),
+            log_max_size_bytes: default_log_max_size_bytes(),
+            log_rotate_interval_secs: 0,
+            log_max_files: default_log_max_files(),
+            log_compress: false,
+            otlp_endpoint: None,
+            trace_sample_ratio: default_trace_sample_ratio(),
+            shutdown_timeout_secs: default_shutdown_timeout_secs(),
+            max_concurrent_jobs: default_max_concurrent_jobs(),
+            max_finished_jobs: default_max_finished_jobs(),
+            job_quotas: JobQuotaConfig::default(),
+            authz: AuthzConfig::default(),
         }
     }
 }
@@ -78,13 +242,62 @@ impl DaemonConfig {
         figment
     }
 
-    pub fn with_foreground(self, foreground: bool) -> Self {
+    /// Applies the command-line settings on top of the loaded ones.
+    pub fn with_overrides(self, overrides: ConfigOverrides) -> Self {
         Self {
-            foreground: foreground || self.foreground,
+            foreground: overrides.foreground || self.foreground,
+            log_format: overrides.log_format.unwrap_or(self.log_format),
             ..self
         }
     }
 
+    /// Copy safe to show to clients, with `auth_tokens` masked.
+    pub fn redacted(&self) -> Self {
+        Self {
+            auth_tokens: vec![REDACTED.to_string(); self.auth_tokens.len()],
+            ..self.clone()
+        }
+    }
+
+    /// Copies the reloadable keys from `new`, keeping everything else.
+    pub fn with_reloadable(self, new: &Self) -> Self {
+        Self {
+            tcp_addr: new.tcp_addr.clone(),
+            socket: new.socket.clone(),
+            log_level: new.log_level.clone(),
+            shutdown_timeout_secs: new.shutdown_timeout_secs,
+            max_concurrent_jobs: new.max_concurrent_jobs,
+            max_finished_jobs: new.max_finished_jobs,
+            job_quotas: new.job_quotas.clone(),
+            authz: new.authz.clone(),
+            ..self
+        }
+    }
+
+    pub fn is_reloadable(key: &str) -> bool {
+        RELOADABLE_KEYS.iter().any(|reloadable| {
+            key == *reloadable
+                || key
+                    .strip_prefix(reloadable)
+                    .is_some_and(|rest| rest.starts_with('.'))
+        })
+    }
+
+    /// Dotted keys whose values differ between `self` and `other`.
+    pub fn changed_keys(&self, other: &Self) -> Vec<String> {
+        let old = flatten(self);
+        let new = flatten(other);
+
+        let mut keys: Vec<String> = old
+            .iter()
+            .filter(|(key, value)| new.get(*key) != Some(value))
+            .map(|(key, _)| key.clone())
+            .collect();
+        keys.extend(new.keys().filter(|key| !old.contains_key(*key)).cloned());
+        keys.sort();
+        keys
+    }
+
     pub fn user_config_dir() -> Option<PathBuf> {
         AppPaths::new().config_dir()
     }
@@ -98,10 +311,37 @@ impl DaemonConfig {
     }
 }
 
+fn flatten(config: &DaemonConfig) -> BTreeMap<String, toml::Value> {
+    fn walk(prefix: &str, value: toml::Value, out: &mut BTreeMap<String, toml::Value>) {
+        match value {
+            toml::Value::Table(table) => {
+                for (key, value) in table {
+                    let key = if prefix.is_empty() {
+                        key
+                    } else {
+                        format!("{}.{}", prefix, key)
+                    };
+                    walk(&key, value, out);
+                }
+            }
+            value => {
+                out.insert(prefix.to_string(), value);
+            }
+        }
+    }
+
+    let mut out = BTreeMap::new();
+    // Serializing plain config structs into a TOML value cannot fail.
+    if let Ok(value) = toml::Value::try_from(config) {
+        walk("", value, &mut out);
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::DEFAULT_TCP_ADDR;
+    use crate::config::{DEFAULT_LOG_LEVEL, DEFAULT_TCP_ADDR};
 
     #[test]
     fn test_default_config() {
@@ -112,6 +352,7 @@ mod tests {
         assert_eq!(config.pid_file, default_pid_file());
         assert_eq!(config.log_file, default_log_file());
         assert_eq!(config.workdir, default_workdir());
+        assert_eq!(config.log_level, DEFAULT_LOG_LEVEL);
     }
 
     #[test]
@@ -119,4 +360,65 @@ mod tests {
         let config = DaemonConfig::load().expect("Failed to load config");
         assert_eq!(config.tcp_addr, DEFAULT_TCP_ADDR);
     }
+
+    #[test]
+    fn test_changed_keys() {
+        let old = DaemonConfig::default();
+        let new = DaemonConfig {
+            tcp_addr: "127.0.0.1:60000".to_string(),
+            workdir: PathBuf::from("/srv"),
+            ..DaemonConfig::default()
+        };
+
+        assert!(old.changed_keys(&old.clone()).is_empty());
+        assert_eq!(old.changed_keys(&new), vec!["tcp_addr", "workdir"]);
+    }
+
+    #[test]
+    fn test_with_reloadable_copies_only_reloadable_keys() {
+        let old = DaemonConfig::default();
+        let new = DaemonConfig {
+            tcp_addr: "127.0.0.1:60000".to_string(),
+            socket: PathBuf::from("/tmp/other.sock"),
+            log_level: "daemon=debug".to_string(),
+            shutdown_timeout_secs: 5,
+            max_concurrent_jobs: 1000,
+            max_finished_jobs: 10,
+            job_quotas: JobQuotaConfig {
+                weight: 2,
+                ..JobQuotaConfig::default()
+            },
+            workdir: PathBuf::from("/srv"),
+            authz: AuthzConfig {
+                default_role: Some("reader".to_string()),
+                ..AuthzConfig::default()
+            },
+            ..DaemonConfig::default()
+        };
+
+        let applied = old.clone().with_reloadable(&new);
+        let changed = old.changed_keys(&applied);
+        assert!(changed.iter().all(|key| DaemonConfig::is_reloadable(key)));
+        assert_eq!(changed.len(), RELOADABLE_KEYS.len());
+        assert_eq!(applied.workdir, old.workdir);
+    }
+
+    #[test]
+    fn test_redacted_masks_tokens() {
+        let config = DaemonConfig {
+            auth_tokens: vec!["s3cret".to_string()],
+            ..DaemonConfig::default()
+        };
+        let redacted = config.redacted();
+        assert_eq!(redacted.auth_tokens, vec![REDACTED]);
+        assert_eq!(config.changed_keys(&redacted), vec!["auth_tokens"]);
+    }
+
+    #[test]
+    fn test_is_reloadable_matches_sections() {
+        assert!(DaemonConfig::is_reloadable("tcp_addr"));
+        assert!(!DaemonConfig::is_reloadable("tcp_addr_extra"));
+        assert!(!DaemonConfig::is_reloadable("pid_file"));
+        assert!(DaemonConfig::is_reloadable("authz.roles.reader"));
+    }
 }
@@ -0,0 +1,65 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// The `[job_quotas]` section: how callers share the job workers.
+///
+/// A caller is its first principal: `uid:<n>` on the Unix socket, otherwise
+/// `token:<name>` or `cert:<subject>`, or `anonymous`. While several callers
+/// have jobs queued, workers go to each in proportion to its weight.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JobQuotaConfig {
+    /// Jobs one caller may run at once; 0 leaves only `max_concurrent_jobs`.
+    #[serde(default)]
+    pub max_running: usize,
+
+    /// Share of the workers a caller gets relative to the others.
+    #[serde(default = "default_weight")]
+    pub weight: u32,
+
+    /// Principal to the limits that replace the defaults above.
+    #[serde(default)]
+    pub callers: BTreeMap<String, CallerQuota>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CallerQuota {
+    #[serde(default)]
+    pub max_running: Option<usize>,
+
+    #[serde(default)]
+    pub weight: Option<u32>,
+}
+
+impl Default for JobQuotaConfig {
+    fn default() -> Self {
+        Self {
+            max_running: 0,
+            weight: default_weight(),
+            callers: BTreeMap::new(),
+        }
+    }
+}
+
+impl JobQuotaConfig {
+    /// Jobs `caller` may run at once; 0 means no limit of its own.
+    pub fn max_running(&self, caller: &str) -> usize {
+        self.callers
+            .get(caller)
+            .and_then(|quota| quota.max_running)
+            .unwrap_or(self.max_running)
+    }
+
+    /// `caller`'s weight, at least 1.
+    pub fn weight(&self, caller: &str) -> u32 {
+        self.callers
+            .get(caller)
+            .and_then(|quota| quota.weight)
+            .unwrap_or(self.weight)
+            .max(1)
+    }
+}
+
+fn default_weight() -> u32 {
+    1
+}
@@ -1,9 +1,22 @@
+mod authz;
+mod build_info;
 mod daemon;
+mod job_quota;
+mod log_format;
 mod paths;
 
-pub use daemon::DaemonConfig;
+pub use authz::AuthzConfig;
+pub use build_info::{GIT_HASH, VERSION};
+pub use daemon::{ConfigOverrides, DaemonConfig, RELOADABLE_KEYS};
+pub use job_quota::{CallerQuota, JobQuotaConfig};
+pub use log_format::LogFormat;
 pub use paths::{
-    APP_NAME, AppPaths, DAEMON_BINARY, DEFAULT_TCP_ADDR, DEFAULT_WORKDIR, ENV_PREFIX,
-    default_lock_file, default_log_file, default_pid_file, default_socket_path, default_tcp_addr,
-    default_workdir,
+    APP_NAME, AppPaths, DAEMON_BINARY, DEFAULT_LOG_BUFFER_SIZE, DEFAULT_LOG_LEVEL,
+    DEFAULT_LOG_MAX_FILES, DEFAULT_LOG_MAX_SIZE_BYTES, DEFAULT_MAX_FINISHED_JOBS,
+    DEFAULT_SHUTDOWN_TIMEOUT_SECS, DEFAULT_TCP_ADDR, DEFAULT_TRACE_SAMPLE_RATIO, DEFAULT_WORKDIR,
+    ENV_PREFIX, default_audit_file, default_job_store_file, default_lock_file,
+    default_log_buffer_size, default_log_file, default_log_level, default_log_max_files,
+    default_log_max_size_bytes, default_max_concurrent_jobs, default_max_finished_jobs,
+    default_pid_file, default_shutdown_timeout_secs, default_socket_path, default_tcp_addr,
+    default_token_file, default_trace_sample_ratio, default_workdir,
 };
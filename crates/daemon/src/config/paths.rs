@@ -7,6 +7,13 @@ pub const DAEMON_BINARY: &str = "ffit-daemon";
 pub const ENV_PREFIX: &str = "FFIT_";
 pub const DEFAULT_TCP_ADDR: &str = "127.0.0.1:50051";
 pub const DEFAULT_WORKDIR: &str = "/";
+pub const DEFAULT_LOG_LEVEL: &str = "daemon=info";
+pub const DEFAULT_LOG_BUFFER_SIZE: usize = 1000;
+pub const DEFAULT_LOG_MAX_SIZE_BYTES: u64 = 10 * 1024 * 1024;
+pub const DEFAULT_LOG_MAX_FILES: usize = 5;
+pub const DEFAULT_TRACE_SAMPLE_RATIO: f64 = 1.0;
+pub const DEFAULT_SHUTDOWN_TIMEOUT_SECS: u64 = 30;
+pub const DEFAULT_MAX_FINISHED_JOBS: usize = 1000;
 
 /// XDG-compliant paths on Linux, appropriate paths on macOS/Windows.
 /// Falls back to /tmp when runtime directory is not available.
@@ -71,6 +78,20 @@ impl AppPaths {
         self.state_dir().join(format!("{}.log", APP_NAME))
     }
 
+    /// Append-only record of mutating RPCs, kept apart from the rotated log file.
+    pub fn audit_file(&self) -> PathBuf {
+        self.state_dir().join("audit.jsonl")
+    }
+
+    /// Jobs and their results, kept across restarts.
+    pub fn job_store_file(&self) -> PathBuf {
+        self.state_dir().join("jobs.jsonl")
+    }
+
+    pub fn token_file(&self) -> Option<PathBuf> {
+        self.config_dir().map(|dir| dir.join("tokens"))
+    }
+
     pub fn user_config_file(&self) -> Option<PathBuf> {
         self.config_dir().map(|dir| dir.join("config.toml"))
     }
@@ -102,6 +123,18 @@ pub fn default_log_file() -> PathBuf {
     AppPaths::new().log_file()
 }
 
+pub fn default_audit_file() -> PathBuf {
+    AppPaths::new().audit_file()
+}
+
+pub fn default_job_store_file() -> PathBuf {
+    AppPaths::new().job_store_file()
+}
+
+pub fn default_token_file() -> Option<PathBuf> {
+    AppPaths::new().token_file()
+}
+
 pub fn default_workdir() -> PathBuf {
     PathBuf::from(DEFAULT_WORKDIR)
 }
@@ -110,6 +143,39 @@ pub fn default_tcp_addr() -> String {
     DEFAULT_TCP_ADDR.to_string()
 }
 
+pub fn default_log_level() -> String {
+    DEFAULT_LOG_LEVEL.to_string()
+}
+
+pub fn default_log_buffer_size() -> usize {
+    DEFAULT_LOG_BUFFER_SIZE
+}
+
+pub fn default_log_max_size_bytes() -> u64 {
+    DEFAULT_LOG_MAX_SIZE_BYTES
+}
+
+pub fn default_log_max_files() -> usize {
+    DEFAULT_LOG_MAX_FILES
+}
+
+pub fn default_trace_sample_ratio() -> f64 {
+    DEFAULT_TRACE_SAMPLE_RATIO
+}
+
+pub fn default_shutdown_timeout_secs() -> u64 {
+    DEFAULT_SHUTDOWN_TIMEOUT_SECS
+}
+
+/// One job per CPU.
+pub fn default_max_concurrent_jobs() -> usize {
+    std::thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+pub fn default_max_finished_jobs() -> usize {
+    DEFAULT_MAX_FINISHED_JOBS
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -133,6 +199,8 @@ mod tests {
 
         let state = paths.state_dir();
         assert!(paths.log_file().starts_with(&state));
+        assert!(paths.audit_file().starts_with(&state));
+        assert!(paths.job_store_file().starts_with(&state));
     }
 
     #[test]
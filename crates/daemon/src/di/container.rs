@@ -1,23 +1,63 @@
 use std::sync::Arc;
 
-use crate::ui::grpc::PingHandler;
-use crate::usecase::PingUseCase;
+use tokio::sync::{mpsc, watch};
+
+use crate::config::{ConfigOverrides, DaemonConfig};
+use crate::error::Result;
+use crate::infra::job_store::JobStore;
+use crate::infra::logging::Logging;
+use crate::ui::grpc::{DaemonHandler, JobHandler, PingHandler};
+use crate::usecase::{
+    JobsUseCase, LogLevelUseCase, LogsUseCase, PingUseCase, ReloadUseCase, ShutdownSignal,
+    ShutdownUseCase, StatusUseCase, UpgradeCommand, UpgradeUseCase,
+};
 
 pub struct Container {
     pub ping_handler: Arc<PingHandler>,
+    pub daemon_handler: Arc<DaemonHandler>,
+    pub job_handler: Arc<JobHandler>,
+    pub jobs_use_case: JobsUseCase,
+    pub reload_use_case: Arc<ReloadUseCase>,
 }
 
 impl Container {
-    pub fn new() -> Self {
+    /// Must be called inside the tokio runtime, which runs the job dispatcher.
+    pub fn new(
+        config: watch::Sender<DaemonConfig>,
+        overrides: ConfigOverrides,
+        listeners: watch::Receiver<Vec<String>>,
+        shutdown: ShutdownSignal,
+        logging: Option<Logging>,
+        upgrades: mpsc::Sender<UpgradeCommand>,
+        job_store: JobStore,
+    ) -> Result<Self> {
         let ping_use_case = PingUseCase;
         let ping_handler = Arc::new(PingHandler::new(ping_use_case));
 
-        Self { ping_handler }
-    }
-}
+        let jobs_use_case = JobsUseCase::new(config.subscribe(), job_store, &shutdown)?;
+        let job_handler = Arc::new(JobHandler::new(jobs_use_case.clone()));
+
+        let status_use_case = StatusUseCase::new(config.subscribe(), listeners);
+        let shutdown_use_case = ShutdownUseCase::new(shutdown.clone());
+        let log_filter = logging.as_ref().map(|logging| logging.filter.clone());
+        let reload_use_case = Arc::new(ReloadUseCase::new(config, overrides, log_filter.clone()));
+        let log_level_use_case = LogLevelUseCase::new(log_filter);
+        let logs_use_case = LogsUseCase::new(logging.map(|logging| logging.broadcast), shutdown);
+        let daemon_handler = Arc::new(DaemonHandler::new(
+            status_use_case,
+            shutdown_use_case,
+            Arc::clone(&reload_use_case),
+            log_level_use_case,
+            logs_use_case,
+            UpgradeUseCase::new(upgrades),
+        ));
 
-impl Default for Container {
-    fn default() -> Self {
-        Self::new()
+        Ok(Self {
+            ping_handler,
+            daemon_handler,
+            job_handler,
+            jobs_use_case,
+            reload_use_case,
+        })
     }
 }
@@ -0,0 +1,76 @@
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use super::rfc3339;
+
+/// One audited RPC, stored as a line of JSON in the audit file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    /// When the call arrived.
+    #[serde(with = "rfc3339")]
+    pub time: SystemTime,
+    /// `<service>/<method>`.
+    pub method: String,
+    /// Principals of the caller (see `Caller`), or `anonymous`.
+    pub caller: String,
+    /// Listener the call came in on, e.g. `tcp://127.0.0.1:50051` or `unix:///run/ffit.sock`.
+    pub transport: String,
+    /// Final gRPC status code name, e.g. `Ok` or `PermissionDenied`.
+    pub code: String,
+    pub duration_ms: u64,
+}
+
+/// Which audit records to show.
+#[derive(Debug, Clone, Default)]
+pub struct AuditQuery {
+    pub since: Option<SystemTime>,
+    pub until: Option<SystemTime>,
+}
+
+impl AuditQuery {
+    pub fn matches(&self, record: &AuditRecord) -> bool {
+        self.since.is_none_or(|since| record.time >= since)
+            && self.until.is_none_or(|until| record.time < until)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn record(secs: u64) -> AuditRecord {
+        AuditRecord {
+            time: SystemTime::UNIX_EPOCH + Duration::from_secs(secs),
+            method: "daemon.v1.DaemonService/Shutdown".to_string(),
+            caller: "uid:1000".to_string(),
+            transport: "unix:///tmp/ffit.sock".to_string(),
+            code: "Ok".to_string(),
+            duration_ms: 3,
+        }
+    }
+
+    #[test]
+    fn test_record_round_trips_as_json() {
+        let line = serde_json::to_string(&record(100)).unwrap();
+        assert!(line.contains(r#""time":"1970-01-01T00:01:40.000Z""#));
+        assert_eq!(
+            serde_json::from_str::<AuditRecord>(&line).unwrap(),
+            record(100)
+        );
+    }
+
+    #[test]
+    fn test_query_filters_time_range() {
+        let query = AuditQuery {
+            since: Some(SystemTime::UNIX_EPOCH + Duration::from_secs(50)),
+            until: Some(SystemTime::UNIX_EPOCH + Duration::from_secs(150)),
+        };
+        assert!(query.matches(&record(50)));
+        assert!(query.matches(&record(100)));
+        assert!(!query.matches(&record(10)));
+        assert!(!query.matches(&record(150)));
+        assert!(AuditQuery::default().matches(&record(0)));
+    }
+}
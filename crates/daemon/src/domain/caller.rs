@@ -0,0 +1,51 @@
+use std::fmt;
+
+const ANONYMOUS: &str = "anonymous";
+
+/// Who sent a request, as established by the listener's authentication.
+///
+/// Attached to every request by the auth interceptor; read it with
+/// `request.extensions().get::<Caller>()`. An empty caller is anonymous:
+/// authentication is off for the listener it came in on.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Caller {
+    /// User on the other end of the Unix socket.
+    pub uid: Option<u32>,
+    /// Name of the bearer token presented over TCP.
+    pub token: Option<String>,
+    /// Subject of the TLS client certificate.
+    pub subject: Option<String>,
+}
+
+impl Caller {
+    /// Identities authorization rules refer to: `uid:<n>`, `token:<name>` and `cert:<subject>`.
+    pub fn principals(&self) -> Vec<String> {
+        let uid = self.uid.map(|uid| format!("uid:{}", uid));
+        let token = self.token.as_ref().map(|name| format!("token:{}", name));
+        let subject = self
+            .subject
+            .as_ref()
+            .map(|subject| format!("cert:{}", subject));
+        [uid, token, subject].into_iter().flatten().collect()
+    }
+
+    /// The principal job quotas are kept under: the first of [`Self::principals`],
+    /// or `anonymous`.
+    pub fn owner(&self) -> String {
+        self.principals()
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| ANONYMOUS.to_string())
+    }
+}
+
+impl fmt::Display for Caller {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let principals = self.principals();
+        if principals.is_empty() {
+            f.write_str(ANONYMOUS)
+        } else {
+            f.write_str(&principals.join(" "))
+        }
+    }
+}
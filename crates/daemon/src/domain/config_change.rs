@@ -0,0 +1,12 @@
+/// Outcome of re-reading the configuration, split by whether each changed key is now live.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigChanges {
+    pub applied: Vec<String>,
+    pub requires_restart: Vec<String>,
+}
+
+impl ConfigChanges {
+    pub fn is_empty(&self) -> bool {
+        self.applied.is_empty() && self.requires_restart.is_empty()
+    }
+}
@@ -0,0 +1,242 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::time::SystemTime;
+
+use compute::Progress;
+use serde::{Deserialize, Serialize};
+
+use super::{Caller, rfc3339};
+
+/// Assigned in submission order and never reused, even across restarts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct JobId(pub u64);
+
+impl fmt::Display for JobId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobState {
+    Queued,
+    Running,
+    Succeeded,
+    Failed,
+    Cancelled,
+    /// Stopped along with the daemon and out of retries.
+    Interrupted,
+}
+
+impl JobState {
+    /// Whether the job has finished and will not change again.
+    pub fn is_terminal(self) -> bool {
+        matches!(
+            self,
+            Self::Succeeded | Self::Failed | Self::Cancelled | Self::Interrupted
+        )
+    }
+}
+
+impl fmt::Display for JobState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Queued => "queued",
+            Self::Running => "running",
+            Self::Succeeded => "succeeded",
+            Self::Failed => "failed",
+            Self::Cancelled => "cancelled",
+            Self::Interrupted => "interrupted",
+        })
+    }
+}
+
+/// What to run: a task kind from the compute crate and its parameters.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JobSpec {
+    pub kind: String,
+    pub params: BTreeMap<String, String>,
+    /// Times the job is requeued if the daemon stops while it runs.
+    #[serde(default)]
+    pub max_retries: u32,
+    /// Higher runs first among the same caller's queued jobs.
+    #[serde(default)]
+    pub priority: i32,
+}
+
+/// A job and its outcome, stored as a line of JSON in the job store.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Job {
+    pub id: JobId,
+    pub spec: JobSpec,
+    pub state: JobState,
+    /// The submitting caller's principals, or `anonymous`.
+    pub submitter: String,
+    /// The caller whose quota the job counts against; see [`Caller::owner`].
+    #[serde(default)]
+    pub owner: String,
+    #[serde(with = "rfc3339")]
+    pub created_at: SystemTime,
+    #[serde(with = "rfc3339::option")]
+    pub started_at: Option<SystemTime>,
+    #[serde(with = "rfc3339::option")]
+    pub finished_at: Option<SystemTime>,
+    /// Times the job has started running.
+    #[serde(default)]
+    pub attempts: u32,
+    /// Output of a succeeded job.
+    pub result: Option<String>,
+    /// Why the job failed, was cancelled or was interrupted.
+    pub error: Option<String>,
+    /// Place among queued jobs, 1 being next to start; set on jobs handed
+    /// out by the scheduler, never stored.
+    #[serde(skip)]
+    pub queue_position: Option<usize>,
+    /// What the task last reported while running; never stored.
+    #[serde(skip)]
+    pub progress: Option<Progress>,
+}
+
+impl Job {
+    pub fn new(id: JobId, spec: JobSpec, caller: &Caller) -> Self {
+        Self {
+            id,
+            spec,
+            state: JobState::Queued,
+            submitter: caller.to_string(),
+            owner: caller.owner(),
+            created_at: SystemTime::now(),
+            started_at: None,
+            finished_at: None,
+            attempts: 0,
+            result: None,
+            error: None,
+            queue_position: None,
+            progress: None,
+        }
+    }
+
+    pub fn start(&mut self) {
+        self.state = JobState::Running;
+        self.started_at = Some(SystemTime::now());
+        self.attempts += 1;
+    }
+
+    /// Settles a job that was running when the daemon stopped: queued again
+    /// while it has retries left, otherwise interrupted.
+    pub fn recover(&mut self) {
+        if self.attempts <= self.spec.max_retries {
+            self.state = JobState::Queued;
+            self.started_at = None;
+        } else {
+            self.error = Some("the daemon stopped while the job was running".to_string());
+            self.end(JobState::Interrupted);
+        }
+    }
+
+    /// Puts back a job stopped so a new daemon can take over; the attempt it
+    /// was on does not count against its retries.
+    pub fn requeue(&mut self) {
+        self.state = JobState::Queued;
+        self.started_at = None;
+        self.attempts = self.attempts.saturating_sub(1);
+    }
+
+    pub fn succeed(&mut self, result: String) {
+        self.result = Some(result);
+        self.end(JobState::Succeeded);
+    }
+
+    pub fn fail(&mut self, error: impl Into<String>) {
+        self.error = Some(error.into());
+        self.end(JobState::Failed);
+    }
+
+    pub fn cancel(&mut self, reason: impl Into<String>) {
+        self.error = Some(reason.into());
+        self.end(JobState::Cancelled);
+    }
+
+    fn end(&mut self, state: JobState) {
+        self.state = state;
+        self.finished_at = Some(SystemTime::now());
+    }
+}
+
+/// Which jobs `List` returns.
+#[derive(Debug, Clone, Default)]
+pub struct JobQuery {
+    pub state: Option<JobState>,
+    /// Only the most recent matches; `None` returns all.
+    pub limit: Option<usize>,
+}
+
+impl JobQuery {
+    pub fn matches(&self, job: &Job) -> bool {
+        self.state.is_none_or(|state| job.state == state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn running(max_retries: u32) -> Job {
+        let spec = JobSpec {
+            kind: "sleep".to_string(),
+            params: BTreeMap::from([("millis".to_string(), "10".to_string())]),
+            max_retries,
+            priority: 0,
+        };
+        let caller = Caller {
+            uid: Some(1000),
+            ..Caller::default()
+        };
+        let mut job = Job::new(JobId(1), spec, &caller);
+        job.start();
+        job
+    }
+
+    #[test]
+    fn test_recover_requeues_until_retries_run_out() {
+        let mut job = running(1);
+        job.recover();
+        assert_eq!(job.state, JobState::Queued);
+        assert_eq!(job.started_at, None);
+
+        job.start();
+        assert_eq!(job.attempts, 2);
+        job.recover();
+        assert_eq!(job.state, JobState::Interrupted);
+        assert!(job.finished_at.is_some() && job.error.is_some());
+    }
+
+    #[test]
+    fn test_requeue_gives_back_the_attempt() {
+        let mut job = running(0);
+        job.requeue();
+        assert_eq!(job.state, JobState::Queued);
+        assert_eq!(job.attempts, 0);
+        job.start();
+        job.recover();
+        assert_eq!(job.state, JobState::Interrupted);
+    }
+
+    #[test]
+    fn test_job_round_trips_as_json() {
+        let mut job = running(0);
+        job.succeed("ok".to_string());
+        let line = serde_json::to_string(&job).unwrap();
+        assert!(line.contains(r#""state":"succeeded""#));
+        let decoded: Job = serde_json::from_str(&line).unwrap();
+        // Times are stored with millisecond precision.
+        assert_eq!(decoded.id, job.id);
+        assert_eq!(decoded.spec, job.spec);
+        assert_eq!(decoded.owner, "uid:1000");
+        assert_eq!(decoded.attempts, 1);
+        assert_eq!(decoded.result.as_deref(), Some("ok"));
+        assert!(decoded.started_at.is_some() && decoded.finished_at.is_some());
+    }
+}
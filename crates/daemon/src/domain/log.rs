@@ -0,0 +1,69 @@
+use std::time::SystemTime;
+
+use tracing::Level;
+
+/// A single event captured from the daemon's tracing subscriber.
+#[derive(Debug, Clone)]
+pub struct LogRecord {
+    pub timestamp: SystemTime,
+    pub level: Level,
+    pub target: String,
+    pub message: String,
+    pub fields: Vec<(String, String)>,
+}
+
+/// Which records a log subscriber wants to see.
+#[derive(Debug, Clone, Default)]
+pub struct LogQuery {
+    pub min_level: Option<Level>,
+    pub target: Option<String>,
+    pub since: Option<SystemTime>,
+}
+
+impl LogQuery {
+    pub fn matches(&self, record: &LogRecord) -> bool {
+        // More verbose levels compare greater, so ERROR < WARN < ... < TRACE.
+        self.min_level.is_none_or(|level| record.level <= level)
+            && self
+                .target
+                .as_deref()
+                .is_none_or(|target| record.target.starts_with(target))
+            && self.since.is_none_or(|since| record.timestamp >= since)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn record(level: Level, target: &str) -> LogRecord {
+        LogRecord {
+            timestamp: SystemTime::UNIX_EPOCH + Duration::from_secs(100),
+            level,
+            target: target.to_string(),
+            message: "message".to_string(),
+            fields: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_query_filters_level_target_and_time() {
+        let query = LogQuery {
+            min_level: Some(Level::INFO),
+            target: Some("daemon::server".to_string()),
+            since: Some(SystemTime::UNIX_EPOCH + Duration::from_secs(50)),
+        };
+
+        assert!(query.matches(&record(Level::WARN, "daemon::server::grpc")));
+        assert!(!query.matches(&record(Level::DEBUG, "daemon::server::grpc")));
+        assert!(!query.matches(&record(Level::INFO, "daemon::usecase")));
+
+        let later = LogQuery {
+            since: Some(SystemTime::UNIX_EPOCH + Duration::from_secs(200)),
+            ..LogQuery::default()
+        };
+        assert!(!later.matches(&record(Level::ERROR, "daemon")));
+        assert!(LogQuery::default().matches(&record(Level::TRACE, "h2")));
+    }
+}
@@ -1,3 +1,22 @@
+mod audit;
+mod caller;
+mod config_change;
+mod job;
+mod log;
+mod peer;
 mod ping;
+mod rfc3339;
+mod shutdown;
+mod status;
+mod upgrade;
 
+pub use audit::{AuditQuery, AuditRecord};
+pub use caller::Caller;
+pub use config_change::ConfigChanges;
+pub use job::{Job, JobId, JobQuery, JobSpec, JobState};
+pub use log::{LogQuery, LogRecord};
+pub use peer::PeerCredentials;
 pub use ping::PingMessage;
+pub use shutdown::{ShutdownPhase, ShutdownRequest};
+pub use status::DaemonStatus;
+pub use upgrade::{UpgradeRequest, Upgraded};
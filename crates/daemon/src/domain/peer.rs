@@ -0,0 +1,12 @@
+/// The local process on the other end of a Unix socket connection, as reported
+/// by the kernel (`SO_PEERCRED`, or `getpeereid` on BSDs) when it connected.
+///
+/// Attached to every request received on the socket; read it with
+/// `request.extensions().get::<PeerCredentials>()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PeerCredentials {
+    pub uid: u32,
+    pub gid: u32,
+    /// Not reported on every platform.
+    pub pid: Option<i32>,
+}
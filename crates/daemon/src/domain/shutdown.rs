@@ -0,0 +1,68 @@
+use std::fmt;
+use std::time::Duration;
+
+const UNSPECIFIED_REASON: &str = "unspecified";
+
+/// Why the daemon is stopping and how long in-flight requests may drain.
+#[derive(Debug, Clone)]
+pub struct ShutdownRequest {
+    reason: String,
+    drain_timeout: Option<Duration>,
+}
+
+impl ShutdownRequest {
+    pub fn new(reason: impl Into<String>, drain_timeout: Option<Duration>) -> Self {
+        let reason = reason.into();
+        let reason = if reason.trim().is_empty() {
+            UNSPECIFIED_REASON.to_string()
+        } else {
+            reason
+        };
+        Self {
+            reason,
+            drain_timeout,
+        }
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    pub fn drain_timeout(&self) -> Option<Duration> {
+        self.drain_timeout
+    }
+}
+
+/// Where the daemon is in shutting down. Phases only move forward.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ShutdownPhase {
+    Running,
+    /// New requests are refused while those in flight finish.
+    Draining,
+    /// The drain timeout elapsed and the remaining requests are being cancelled.
+    Cancelling,
+    /// Removing the sockets and the PID file; nothing is waited for anymore.
+    CleaningUp,
+}
+
+impl fmt::Display for ShutdownPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Running => "running",
+            Self::Draining => "draining",
+            Self::Cancelling => "cancelling",
+            Self::CleaningUp => "cleaning up",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_reason_is_unspecified() {
+        let request = ShutdownRequest::new("  ", None);
+        assert_eq!(request.reason(), "unspecified");
+    }
+}
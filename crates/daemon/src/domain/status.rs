@@ -0,0 +1,23 @@
+use std::time::{Duration, SystemTime};
+
+use crate::config::DaemonConfig;
+
+#[derive(Debug, Clone)]
+pub struct DaemonStatus {
+    pub pid: u32,
+    pub version: &'static str,
+    pub git_hash: &'static str,
+    pub started_at: SystemTime,
+    pub uptime: Duration,
+    pub listeners: Vec<String>,
+    pub config: DaemonConfig,
+}
+
+impl DaemonStatus {
+    pub fn start_time_unix_secs(&self) -> i64 {
+        self.started_at
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or_default()
+    }
+}
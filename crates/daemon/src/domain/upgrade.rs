@@ -0,0 +1,15 @@
+use std::time::Duration;
+
+/// Asks the daemon to start its binary again on the same listeners and then exit.
+#[derive(Debug, Clone, Default)]
+pub struct UpgradeRequest {
+    /// How long the old process may spend draining once the new one serves.
+    pub drain_timeout: Option<Duration>,
+}
+
+/// A completed handoff: `pid` serves now, `previous_pid` is draining.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Upgraded {
+    pub previous_pid: u32,
+    pub pid: u32,
+}
@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::domain::{JobId, JobState};
+
 pub type Result<T> = std::result::Result<T, DaemonError>;
 
 #[derive(Debug, Error)]
@@ -25,9 +27,56 @@ pub enum DaemonError {
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
 
+    #[error("TLS configuration error: {0}")]
+    TlsError(String),
+
+    #[error(
+        "refusing to listen on {0} without TLS: set tls_cert and tls_key, or use a loopback address"
+    )]
+    InsecureListener(std::net::SocketAddr),
+
+    #[error("invalid Unix socket access settings: {0}")]
+    SocketAccessError(String),
+
+    #[error("token error: {0}")]
+    TokenError(String),
+
+    #[error("invalid authorization policy: {0}")]
+    AuthzError(String),
+
     #[error("invalid address: {0}")]
     InvalidAddress(#[from] std::net::AddrParseError),
 
     #[error("message cannot be empty")]
     EmptyMessage,
+
+    #[error("invalid log filter: {0}")]
+    InvalidLogFilter(String),
+
+    #[error("log filter cannot be changed: {0}")]
+    LogFilterError(String),
+
+    #[error("log streaming unavailable: {0}")]
+    LogStreamUnavailable(String),
+
+    #[error("upgrade failed: {0}")]
+    UpgradeError(String),
+
+    #[error("invalid job: {0}")]
+    InvalidJob(String),
+
+    #[error("job {0} not found")]
+    JobNotFound(JobId),
+
+    #[error("job {0} already {1}")]
+    JobFinished(JobId, JobState),
+
+    #[error("daemon is shutting down and accepts no new jobs")]
+    JobsClosed,
+
+    #[error("metrics error: {0}")]
+    MetricsError(#[from] prometheus::Error),
+
+    #[error("failed to set up trace export: {0}")]
+    TelemetryError(String),
 }
@@ -0,0 +1,260 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Instant, SystemTime};
+
+use http::{Request, Response};
+use http_body::{Body, Frame, SizeHint};
+use tonic::Code;
+use tower::{Layer, Service};
+
+use super::{AuditLog, is_read_only};
+use crate::domain::AuditRecord;
+use crate::infra::auth::CallerSlot;
+use crate::infra::{grpc_status, rpc_method};
+
+/// Writes an [`AuditRecord`] for every RPC that is not read-only, and for
+/// any RPC refused by authentication or authorization.
+///
+/// Runs outside the auth interceptor, so it sees rejected requests too, and
+/// learns the [`Caller`](crate::domain::Caller) through a [`CallerSlot`].
+#[derive(Clone)]
+pub struct AuditLayer {
+    log: AuditLog,
+    transport: String,
+}
+
+impl AuditLayer {
+    /// `transport` is the listener's address, e.g. `unix:///run/ffit.sock`.
+    pub fn new(log: AuditLog, transport: impl Into<String>) -> Self {
+        Self {
+            log,
+            transport: transport.into(),
+        }
+    }
+}
+
+impl<S> Layer<S> for AuditLayer {
+    type Service = AuditService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        AuditService {
+            inner,
+            log: self.log.clone(),
+            transport: self.transport.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct AuditService<S> {
+    inner: S,
+    log: AuditLog,
+    transport: String,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for AuditService<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = Response<AuditBody<ResBody>>;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request<ReqBody>) -> Self::Future {
+        let mut call = AuditedCall::start(self, &request);
+        request.extensions_mut().insert(call.caller.clone());
+        let future = self.inner.call(request);
+
+        Box::pin(async move {
+            // If the inner service fails, `call` is dropped here and recorded as cancelled.
+            let response = future.await?;
+            // Trailers-only responses (most errors) carry the status in the headers.
+            call.code = call.code.or(grpc_status(response.headers()));
+
+            let (parts, body) = response.into_parts();
+            Ok(Response::from_parts(
+                parts,
+                AuditBody {
+                    inner: body,
+                    call: Some(call),
+                },
+            ))
+        })
+    }
+}
+
+/// Response body that writes the audit record once the body is done or dropped,
+/// so the recorded status and duration cover the whole call.
+pub struct AuditBody<B> {
+    inner: B,
+    call: Option<AuditedCall>,
+}
+
+impl<B> Body for AuditBody<B>
+where
+    B: Body + Unpin,
+{
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let poll = Pin::new(&mut self.inner).poll_frame(cx);
+        match &poll {
+            Poll::Ready(Some(Ok(frame))) => {
+                if let (Some(trailers), Some(call)) = (frame.trailers_ref(), self.call.as_mut()) {
+                    call.code = call.code.or(grpc_status(trailers));
+                }
+            }
+            Poll::Ready(None) | Poll::Ready(Some(Err(_))) => {
+                self.call.take();
+            }
+            Poll::Pending => {}
+        }
+        poll
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+/// An RPC being audited; written to the log when dropped, unless it turned
+/// out to be a permitted read-only call.
+struct AuditedCall {
+    log: AuditLog,
+    method: String,
+    read_only: bool,
+    caller: CallerSlot,
+    transport: String,
+    code: Option<Code>,
+    time: SystemTime,
+    start: Instant,
+}
+
+impl AuditedCall {
+    fn start<S, B>(service: &AuditService<S>, request: &Request<B>) -> Self {
+        let (name, method) = rpc_method(request.uri().path());
+
+        Self {
+            log: service.log.clone(),
+            method: format!("{}/{}", name, method),
+            read_only: is_read_only(name, method),
+            caller: CallerSlot::default(),
+            transport: service.transport.clone(),
+            code: None,
+            time: SystemTime::now(),
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Drop for AuditedCall {
+    fn drop(&mut self) {
+        // No status means the client went away before the call completed.
+        let code = self.code.unwrap_or(Code::Cancelled);
+        let refused = matches!(code, Code::Unauthenticated | Code::PermissionDenied);
+        if self.read_only && !refused {
+            return;
+        }
+
+        self.log.record(&AuditRecord {
+            time: self.time,
+            method: std::mem::take(&mut self.method),
+            caller: self.caller.get().unwrap_or_default().to_string(),
+            transport: std::mem::take(&mut self.transport),
+            code: format!("{:?}", code),
+            duration_ms: self.start.elapsed().as_millis() as u64,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::Infallible;
+    use std::future::Ready;
+
+    use crate::domain::{AuditQuery, Caller};
+
+    /// Answers every request with a trailers-only status taken from the path.
+    #[derive(Clone)]
+    struct Respond;
+
+    impl Service<Request<()>> for Respond {
+        type Response = Response<String>;
+        type Error = Infallible;
+        type Future = Ready<Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, request: Request<()>) -> Self::Future {
+            // Stands in for the auth interceptor and authorization.
+            let code = match request.uri().path().rsplit('/').next() {
+                Some("Shutdown") => "7",
+                Some("ListJobs") => "16",
+                _ => "0",
+            };
+            if let Some(slot) = request.extensions().get::<CallerSlot>() {
+                slot.set(Caller {
+                    uid: Some(1000),
+                    ..Caller::default()
+                });
+            }
+            let response = Response::builder()
+                .header("grpc-status", code)
+                .body(String::new())
+                .unwrap();
+            std::future::ready(Ok(response))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_records_mutating_calls_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit.jsonl");
+        let log = AuditLog::open(&path).unwrap();
+        let mut service = AuditLayer::new(log.clone(), "unix:///tmp/ffit.sock").layer(Respond);
+
+        for path in [
+            "/daemon.v1.DaemonService/GetStatus",
+            "/daemon.v1.DaemonService/ReloadConfig",
+            "/daemon.v1.DaemonService/Shutdown",
+            "/daemon.v1.JobService/ListJobs",
+        ] {
+            let request = Request::builder().uri(path).body(()).unwrap();
+            drop(service.call(request).await.unwrap());
+        }
+        log.flush().await;
+
+        let records = AuditLog::read(&path, &AuditQuery::default()).unwrap();
+        let summary: Vec<_> = records
+            .iter()
+            .map(|record| (record.method.as_str(), record.code.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("daemon.v1.DaemonService/ReloadConfig", "Ok"),
+                ("daemon.v1.DaemonService/Shutdown", "PermissionDenied"),
+                ("daemon.v1.JobService/ListJobs", "Unauthenticated"),
+            ]
+        );
+        assert_eq!(records[0].caller, "uid:1000");
+        assert_eq!(records[0].transport, "unix:///tmp/ffit.sock");
+    }
+}
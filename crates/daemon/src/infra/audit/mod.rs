@@ -0,0 +1,216 @@
+mod layer;
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread;
+
+use tokio::sync::oneshot;
+use tracing::{error, warn};
+
+use crate::domain::{AuditQuery, AuditRecord};
+
+pub use layer::{AuditBody, AuditLayer, AuditService};
+
+/// Services whose every method only reads state.
+const READ_ONLY_SERVICES: &[&str] = &[
+    "grpc.health.v1.Health",
+    "grpc.reflection.v1.ServerReflection",
+    "daemon.v1.PingService",
+];
+
+/// Method name prefixes that only read state, whatever the service.
+const READ_ONLY_PREFIXES: &[&str] = &["Get", "List", "Stream", "Watch", "Wait"];
+
+/// Whether an RPC leaves the daemon unchanged and is therefore not audited.
+pub fn is_read_only(service: &str, method: &str) -> bool {
+    READ_ONLY_SERVICES.contains(&service)
+        || READ_ONLY_PREFIXES
+            .iter()
+            .any(|prefix| method.starts_with(prefix))
+}
+
+/// Append-only JSON-lines audit file, one [`AuditRecord`] per line.
+///
+/// Records are handed to a writer thread, so RPCs never wait on the disk;
+/// the channel is unbounded so none are dropped while it catches up.
+/// Each record is written with a single `write` on an `O_APPEND` file,
+/// so lines never interleave with other writers.
+#[derive(Clone)]
+pub struct AuditLog {
+    path: PathBuf,
+    writer: mpsc::Sender<Queued>,
+}
+
+enum Queued {
+    Record(Vec<u8>),
+    /// Answered once every record sent before it is written.
+    Flush(oneshot::Sender<()>),
+}
+
+impl AuditLog {
+    /// Opens `path` for appending, creating it (mode 0600) and its directory if needed.
+    pub fn open(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut options = OpenOptions::new();
+        options.create(true).append(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let file = options.open(&path)?;
+
+        let (writer, queue) = mpsc::channel();
+        let thread_path = path.clone();
+        thread::Builder::new()
+            .name("audit-writer".to_string())
+            .spawn(move || write_records(file, &thread_path, queue))?;
+
+        Ok(Self { path, writer })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Queues `record` for writing; failures are logged rather than failing the RPC.
+    pub fn record(&self, record: &AuditRecord) {
+        let mut line = match serde_json::to_vec(record) {
+            Ok(line) => line,
+            Err(e) => {
+                error!(error = %e, "Failed to encode audit record");
+                return;
+            }
+        };
+        line.push(b'\n');
+
+        if self.writer.send(Queued::Record(line)).is_err() {
+            error!(path = %self.path.display(), "Audit writer stopped, dropping record");
+        }
+    }
+
+    /// Waits until every record queued so far is written.
+    pub async fn flush(&self) {
+        let (done, written) = oneshot::channel();
+        if self.writer.send(Queued::Flush(done)).is_ok() {
+            let _ = written.await;
+        }
+    }
+
+    /// Reads the records in `path` that match `query`, oldest first.
+    /// A missing file has no records; malformed lines are skipped.
+    pub fn read(path: &Path, query: &AuditQuery) -> io::Result<Vec<AuditRecord>> {
+        let file = match File::open(path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+
+        let mut records = Vec::new();
+        for (index, line) in BufReader::new(file).lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str::<AuditRecord>(&line) {
+                Ok(record) if query.matches(&record) => records.push(record),
+                Ok(_) => {}
+                Err(e) => warn!(line = index + 1, error = %e, "Skipping malformed audit record"),
+            }
+        }
+        Ok(records)
+    }
+}
+
+/// Runs on the writer thread until every [`AuditLog`] clone is dropped.
+fn write_records(mut file: File, path: &Path, queue: mpsc::Receiver<Queued>) {
+    for queued in queue {
+        match queued {
+            Queued::Record(line) => {
+                if let Err(e) = file.write_all(&line) {
+                    error!(path = %path.display(), error = %e, "Failed to write audit record");
+                }
+            }
+            Queued::Flush(done) => {
+                let _ = done.send(());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, SystemTime};
+
+    fn record(secs: u64, method: &str) -> AuditRecord {
+        AuditRecord {
+            time: SystemTime::UNIX_EPOCH + Duration::from_secs(secs),
+            method: method.to_string(),
+            caller: "anonymous".to_string(),
+            transport: "tcp://127.0.0.1:50051".to_string(),
+            code: "Ok".to_string(),
+            duration_ms: 1,
+        }
+    }
+
+    #[test]
+    fn test_is_read_only() {
+        assert!(is_read_only("daemon.v1.DaemonService", "GetStatus"));
+        assert!(is_read_only("daemon.v1.DaemonService", "StreamLogs"));
+        assert!(is_read_only("daemon.v1.PingService", "Ping"));
+        assert!(!is_read_only("daemon.v1.DaemonService", "Shutdown"));
+        assert!(!is_read_only("daemon.v1.DaemonService", "SetLogLevel"));
+    }
+
+    #[tokio::test]
+    async fn test_appends_and_reads_by_time() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit.jsonl");
+
+        let log = AuditLog::open(&path).unwrap();
+        log.record(&record(100, "daemon.v1.DaemonService/ReloadConfig"));
+        log.flush().await;
+        // Reopening appends instead of truncating.
+        let log = AuditLog::open(&path).unwrap();
+        log.record(&record(200, "daemon.v1.DaemonService/Shutdown"));
+        log.flush().await;
+        fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(b"not json\n")
+            .unwrap();
+
+        let all = AuditLog::read(&path, &AuditQuery::default()).unwrap();
+        assert_eq!(all.len(), 2);
+        let recent = AuditQuery {
+            since: Some(SystemTime::UNIX_EPOCH + Duration::from_secs(150)),
+            until: None,
+        };
+        let records = AuditLog::read(&path, &recent).unwrap();
+        assert_eq!(
+            records,
+            vec![record(200, "daemon.v1.DaemonService/Shutdown")]
+        );
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        assert!(
+            AuditLog::read(&dir.path().join("missing"), &AuditQuery::default())
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,413 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, RwLock};
+use std::task::{Context, Poll};
+
+use http::{Request, Response};
+use tonic::Status;
+use tower::{Layer, Service};
+use tracing::warn;
+
+use crate::config::AuthzConfig;
+use crate::domain::Caller;
+use crate::error::{DaemonError, Result};
+use crate::infra::rpc_method;
+
+/// Methods every caller may invoke: `ffit start` and probes wait on the health service.
+const ALWAYS_ALLOWED: &[&str] = &["grpc.health.v1.Health/*"];
+
+/// Decides which RPC methods a [`Caller`] may invoke, following the `[authz]` section.
+///
+/// Clones share their rules, so [`Authorizer::update`] applies to every listener
+/// and open connection at once.
+#[derive(Clone, Default)]
+pub struct Authorizer {
+    rules: Arc<RwLock<Option<Rules>>>,
+}
+
+/// A validated `[authz]` section; `None` in [`Authorizer`] when no roles are defined.
+struct Rules {
+    roles: HashMap<String, Vec<String>>,
+    bindings: HashMap<String, String>,
+    default_role: Option<String>,
+}
+
+impl Authorizer {
+    pub fn from_config(config: &AuthzConfig) -> Result<Self> {
+        Ok(Self {
+            rules: Arc::new(RwLock::new(Rules::from_config(config)?)),
+        })
+    }
+
+    /// Checks `config` without applying it.
+    pub fn validate(config: &AuthzConfig) -> Result<()> {
+        Rules::from_config(config).map(|_| ())
+    }
+
+    /// Replaces the rules; on error the current ones stay in force.
+    pub fn update(&self, config: &AuthzConfig) -> Result<()> {
+        let rules = Rules::from_config(config)?;
+        *self.rules.write().expect("authz rules lock poisoned") = rules;
+        Ok(())
+    }
+
+    /// Whether `caller` may invoke `method` (`<service>/<method>`).
+    pub fn allows(&self, caller: &Caller, method: &str) -> bool {
+        match &*self.rules.read().expect("authz rules lock poisoned") {
+            Some(rules) => rules.allows(caller, method),
+            None => true,
+        }
+    }
+}
+
+impl Rules {
+    fn from_config(config: &AuthzConfig) -> Result<Option<Self>> {
+        if !config.is_enabled() {
+            return Ok(None);
+        }
+
+        let known_role = |role: &String, context: &str| {
+            if config.roles.contains_key(role) {
+                Ok(())
+            } else {
+                Err(DaemonError::AuthzError(format!(
+                    "{} refers to undefined role '{}'",
+                    context, role
+                )))
+            }
+        };
+
+        for (role, patterns) in &config.roles {
+            if patterns.iter().any(|pattern| pattern.is_empty()) {
+                return Err(DaemonError::AuthzError(format!(
+                    "role '{}' has an empty method pattern",
+                    role
+                )));
+            }
+        }
+        if let Some(role) = &config.default_role {
+            known_role(role, "default_role")?;
+        }
+
+        let mut bindings = HashMap::new();
+        for (principal, role) in &config.bindings {
+            known_role(role, &format!("binding '{}'", principal))?;
+            bindings.insert(canonical_principal(principal)?, role.clone());
+        }
+
+        Ok(Some(Self {
+            roles: config
+                .roles
+                .iter()
+                .map(|(role, patterns)| (role.clone(), patterns.clone()))
+                .collect(),
+            bindings,
+            default_role: config.default_role.clone(),
+        }))
+    }
+
+    /// Roles granted by the caller's bindings, or the default role when it has none.
+    fn roles(&self, caller: &Caller) -> Vec<&str> {
+        let bound: Vec<&str> = caller
+            .principals()
+            .iter()
+            .filter_map(|principal| self.bindings.get(principal))
+            .map(String::as_str)
+            .collect();
+        if bound.is_empty() {
+            self.default_role.iter().map(String::as_str).collect()
+        } else {
+            bound
+        }
+    }
+
+    fn allows(&self, caller: &Caller, method: &str) -> bool {
+        let granted = self
+            .roles(caller)
+            .into_iter()
+            .filter_map(|role| self.roles.get(role))
+            .flatten()
+            .map(String::as_str);
+        ALWAYS_ALLOWED
+            .iter()
+            .copied()
+            .chain(granted)
+            .any(|pattern| glob_match(pattern, method))
+    }
+}
+
+/// Checks the principal's kind and resolves `user:<name>` to the `uid:<n>` callers carry.
+fn canonical_principal(principal: &str) -> Result<String> {
+    let invalid = || {
+        DaemonError::AuthzError(format!(
+            "invalid principal '{}', expected uid:, user:, token: or cert:",
+            principal
+        ))
+    };
+    let (kind, value) = principal.split_once(':').ok_or_else(invalid)?;
+    match kind {
+        "uid" => value
+            .parse::<u32>()
+            .map(|_| principal.to_string())
+            .map_err(|_| invalid()),
+        #[cfg(unix)]
+        "user" => super::resolve_user(value)
+            .map(|uid| format!("uid:{}", uid))
+            .map_err(|_| {
+                DaemonError::AuthzError(format!("unknown user in binding '{}'", principal))
+            }),
+        "token" | "cert" if !value.is_empty() => Ok(principal.to_string()),
+        _ => Err(invalid()),
+    }
+}
+
+/// Matches `text` against `pattern`, where `*` stands for any run of characters.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let (pattern, text) = (pattern.as_bytes(), text.as_bytes());
+    let (mut p, mut t) = (0, 0);
+    // Position after the last `*` and the text position it is currently matched up to.
+    let mut backtrack = None;
+
+    while t < text.len() {
+        if p < pattern.len() && pattern[p] == b'*' {
+            p += 1;
+            backtrack = Some((p, t));
+        } else if p < pattern.len() && pattern[p] == text[t] {
+            p += 1;
+            t += 1;
+        } else if let Some((star_p, star_t)) = backtrack {
+            p = star_p;
+            t = star_t + 1;
+            backtrack = Some((star_p, t));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&c| c == b'*')
+}
+
+/// Rejects RPCs the caller's roles do not allow with `PERMISSION_DENIED`.
+///
+/// Runs inside the auth interceptor, which attaches the [`Caller`].
+#[derive(Clone)]
+pub struct AuthzLayer {
+    authorizer: Authorizer,
+}
+
+impl AuthzLayer {
+    pub fn new(authorizer: Authorizer) -> Self {
+        Self { authorizer }
+    }
+}
+
+impl<S> Layer<S> for AuthzLayer {
+    type Service = AuthzService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        AuthzService {
+            inner,
+            authorizer: self.authorizer.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct AuthzService<S> {
+    inner: S,
+    authorizer: Authorizer,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for AuthzService<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+    S::Future: Send + 'static,
+    S::Error: Send + 'static,
+    ResBody: Default + Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future =
+        Pin<Box<dyn Future<Output = std::result::Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<std::result::Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<ReqBody>) -> Self::Future {
+        let (service, method) = rpc_method(request.uri().path());
+        let method = format!("{}/{}", service, method);
+        let anonymous = Caller::default();
+        let caller = request.extensions().get::<Caller>().unwrap_or(&anonymous);
+
+        if !self.authorizer.allows(caller, &method) {
+            warn!(
+                target: "daemon::audit",
+                event = "authz_denied",
+                caller = %caller,
+                method = %method,
+                "Denied RPC"
+            );
+            let status = Status::permission_denied(format!("{} may not call {}", caller, method));
+            let (parts, ()) = status.into_http::<()>().into_parts();
+            let response = Response::from_parts(parts, ResBody::default());
+            return Box::pin(std::future::ready(Ok(response)));
+        }
+
+        Box::pin(self.inner.call(request))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use std::convert::Infallible;
+    use std::future::Ready;
+
+    fn config(bindings: &[(&str, &str)], default_role: Option<&str>) -> AuthzConfig {
+        AuthzConfig {
+            roles: BTreeMap::from([
+                (
+                    "reader".to_string(),
+                    vec![
+                        "daemon.v1.*/Get*".to_string(),
+                        "daemon.v1.PingService/*".to_string(),
+                    ],
+                ),
+                ("admin".to_string(), vec!["*".to_string()]),
+            ]),
+            bindings: bindings
+                .iter()
+                .map(|(principal, role)| (principal.to_string(), role.to_string()))
+                .collect(),
+            default_role: default_role.map(str::to_string),
+        }
+    }
+
+    fn token(name: &str) -> Caller {
+        Caller {
+            token: Some(name.to_string()),
+            ..Caller::default()
+        }
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("*", "daemon.v1.DaemonService/Shutdown"));
+        assert!(glob_match(
+            "daemon.v1.*/Get*",
+            "daemon.v1.DaemonService/GetStatus"
+        ));
+        assert!(!glob_match(
+            "daemon.v1.*/Get*",
+            "daemon.v1.DaemonService/Shutdown"
+        ));
+        assert!(glob_match("*Service/*Level", "a.LogService/SetLogLevel"));
+        assert!(!glob_match(
+            "daemon.v1.PingService/Ping",
+            "daemon.v1.PingService/Pin"
+        ));
+        assert!(glob_match("a**b", "ab"));
+    }
+
+    #[test]
+    fn test_roles_from_bindings_and_default() {
+        let authorizer = Authorizer::from_config(&config(
+            &[("token:ci", "reader"), ("uid:0", "admin")],
+            Some("reader"),
+        ))
+        .unwrap();
+
+        assert!(authorizer.allows(&token("ci"), "daemon.v1.DaemonService/GetStatus"));
+        assert!(!authorizer.allows(&token("ci"), "daemon.v1.DaemonService/Shutdown"));
+        let root = Caller {
+            uid: Some(0),
+            ..Caller::default()
+        };
+        assert!(authorizer.allows(&root, "daemon.v1.DaemonService/Shutdown"));
+        // Unbound callers fall back to the default role.
+        assert!(authorizer.allows(&Caller::default(), "daemon.v1.PingService/Ping"));
+        assert!(!authorizer.allows(&token("other"), "daemon.v1.DaemonService/Shutdown"));
+
+        let strict = Authorizer::from_config(&config(&[("token:ci", "admin")], None)).unwrap();
+        assert!(!strict.allows(&Caller::default(), "daemon.v1.PingService/Ping"));
+        assert!(strict.allows(&Caller::default(), "grpc.health.v1.Health/Check"));
+        assert!(strict.allows(&token("ci"), "daemon.v1.PingService/Ping"));
+    }
+
+    #[test]
+    fn test_disabled_without_roles() {
+        let authorizer = Authorizer::from_config(&AuthzConfig::default()).unwrap();
+        assert!(authorizer.allows(&Caller::default(), "daemon.v1.DaemonService/Shutdown"));
+
+        authorizer.update(&config(&[], None)).unwrap();
+        assert!(!authorizer.allows(&Caller::default(), "daemon.v1.DaemonService/Shutdown"));
+    }
+
+    #[test]
+    fn test_rejects_invalid_config() {
+        assert!(Authorizer::validate(&config(&[("token:ci", "missing")], None)).is_err());
+        assert!(Authorizer::validate(&config(&[], Some("missing"))).is_err());
+        assert!(Authorizer::validate(&config(&[("group:wheel", "admin")], None)).is_err());
+        assert!(Authorizer::validate(&config(&[("uid:alice", "admin")], None)).is_err());
+        assert!(Authorizer::validate(&config(&[("token:", "admin")], None)).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_user_bindings_resolve_to_uid() {
+        let authorizer = Authorizer::from_config(&config(&[("user:root", "admin")], None)).unwrap();
+        let root = Caller {
+            uid: Some(0),
+            ..Caller::default()
+        };
+        assert!(authorizer.allows(&root, "daemon.v1.DaemonService/Shutdown"));
+    }
+
+    /// Answers every request with an empty success.
+    #[derive(Clone)]
+    struct Ok200;
+
+    impl Service<Request<()>> for Ok200 {
+        type Response = Response<String>;
+        type Error = Infallible;
+        type Future = Ready<std::result::Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _request: Request<()>) -> Self::Future {
+            std::future::ready(Ok(Response::new("handled".to_string())))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_layer_denies_with_permission_denied() {
+        let authorizer = Authorizer::from_config(&config(&[("token:ci", "reader")], None)).unwrap();
+        let mut service = AuthzLayer::new(authorizer).layer(Ok200);
+
+        let call = |path: &str| {
+            let mut request = Request::builder().uri(path).body(()).unwrap();
+            request.extensions_mut().insert(token("ci"));
+            request
+        };
+
+        let response = service
+            .call(call("/daemon.v1.DaemonService/GetStatus"))
+            .await
+            .unwrap();
+        assert_eq!(response.body(), "handled");
+
+        let response = service
+            .call(call("/daemon.v1.DaemonService/Shutdown"))
+            .await
+            .unwrap();
+        assert_eq!(response.headers()["grpc-status"], "7");
+        assert!(response.body().is_empty());
+    }
+}
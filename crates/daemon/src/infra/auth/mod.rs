@@ -0,0 +1,359 @@
+mod authz;
+#[cfg(unix)]
+mod peer;
+mod tokens;
+
+use std::fs;
+use std::io::ErrorKind;
+use std::sync::{Arc, Mutex, RwLock, RwLockReadGuard};
+use std::time::SystemTime;
+
+use tonic::service::Interceptor;
+use tonic::{Request, Status};
+use tracing::{error, warn};
+
+use crate::config::DaemonConfig;
+use crate::domain::Caller;
+#[cfg(unix)]
+use crate::domain::PeerCredentials;
+
+pub use authz::{Authorizer, AuthzLayer, AuthzService};
+#[cfg(unix)]
+pub use peer::PeerPolicy;
+#[cfg(unix)]
+pub(crate) use peer::{resolve_group, resolve_user};
+pub use tokens::{TokenEntry, TokenFile};
+
+/// Name `auth_tokens` authenticate as, e.g. in `token:auth_tokens` authz bindings.
+pub const CONFIG_TOKEN_NAME: &str = "auth_tokens";
+
+/// Checks bearer tokens against `auth_tokens` and the token file.
+///
+/// The file is re-read whenever it changes, so `ffit token create/revoke`
+/// apply without a restart. Authentication is off while neither source exists;
+/// once the file exists it stays on, even with every token revoked.
+#[derive(Clone)]
+pub struct Authenticator {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    tokens: Vec<String>,
+    file: Option<TokenFile>,
+    cache: RwLock<FileTokens>,
+}
+
+/// Tokens read from the file, tagged with the modification time they were read at.
+#[derive(Default)]
+struct FileTokens {
+    version: Option<(SystemTime, u64)>,
+    tokens: Vec<TokenEntry>,
+}
+
+impl Authenticator {
+    pub fn new(tokens: Vec<String>, file: Option<TokenFile>) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                tokens,
+                file,
+                cache: RwLock::new(FileTokens::default()),
+            }),
+        }
+    }
+
+    pub fn from_config(config: &DaemonConfig) -> Self {
+        Self::new(
+            config.auth_tokens.clone(),
+            config.token_file.as_ref().map(TokenFile::new),
+        )
+    }
+
+    /// Accepts `token` if it matches a configured token, or anything when authentication is off.
+    /// Returns the matched token's name: its name in the file, or [`CONFIG_TOKEN_NAME`].
+    pub fn check(&self, token: Option<&str>) -> Result<Option<String>, Status> {
+        match self.refresh() {
+            Some(file) => self.verify(token, &file.tokens).map(Some),
+            None if self.inner.tokens.is_empty() => Ok(None),
+            None => self.verify(token, &[]).map(Some),
+        }
+    }
+
+    fn verify(&self, token: Option<&str>, file_tokens: &[TokenEntry]) -> Result<String, Status> {
+        let Some(token) = token else {
+            return Err(Status::unauthenticated("missing bearer token"));
+        };
+        let known = self
+            .inner
+            .tokens
+            .iter()
+            .map(|known| (CONFIG_TOKEN_NAME, known))
+            .chain(
+                file_tokens
+                    .iter()
+                    .map(|entry| (entry.name.as_str(), &entry.token)),
+            );
+        // Compare every token so timing does not reveal which one nearly matched.
+        let matched = known.fold(None, |matched, (name, known)| {
+            let equal = constant_time_eq(known.as_bytes(), token.as_bytes());
+            matched.or(equal.then_some(name))
+        });
+        match matched {
+            Some(name) => Ok(name.to_string()),
+            None => Err(Status::unauthenticated("invalid bearer token")),
+        }
+    }
+
+    /// Returns the file's tokens, or `None` when there is no token file.
+    fn refresh(&self) -> Option<RwLockReadGuard<'_, FileTokens>> {
+        let file = self.inner.file.as_ref()?;
+        let version = match fs::metadata(file.path()) {
+            Ok(metadata) => metadata.modified().ok().map(|time| (time, metadata.len())),
+            Err(e) if e.kind() == ErrorKind::NotFound => return None,
+            Err(e) => {
+                error!(path = %file.path().display(), error = %e, "Failed to stat token file");
+                None
+            }
+        };
+
+        {
+            let cache = self.inner.cache.read().expect("token cache lock poisoned");
+            if version.is_some() && cache.version == version {
+                return Some(cache);
+            }
+        }
+
+        let mut cache = self.inner.cache.write().expect("token cache lock poisoned");
+        cache.version = version;
+        cache.tokens = match file.load() {
+            Ok(entries) => entries,
+            Err(e) => {
+                // Fail closed: an unreadable file still requires a token.
+                error!(path = %file.path().display(), error = %e, "Failed to load token file");
+                Vec::new()
+            }
+        };
+        drop(cache);
+        Some(self.inner.cache.read().expect("token cache lock poisoned"))
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+/// Tells a layer outside the [`AuthInterceptor`] who a request came from, also
+/// when authentication rejects it: then only the identity known without a
+/// valid token. Insert it into the request's extensions before the interceptor runs.
+#[derive(Clone, Default)]
+pub struct CallerSlot(Arc<Mutex<Option<Caller>>>);
+
+impl CallerSlot {
+    pub fn get(&self) -> Option<Caller> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    pub(crate) fn set(&self, caller: Caller) {
+        *self.0.lock().unwrap_or_else(|e| e.into_inner()) = Some(caller);
+    }
+}
+
+/// Authenticates requests the way their listener requires: a bearer token
+/// on TCP, an allowed peer on the Unix socket. The default lets everything through.
+///
+/// Accepted requests carry a [`Caller`] for the authorization layer, and any
+/// [`CallerSlot`] is filled in whether the request is accepted or not.
+#[derive(Clone, Default)]
+pub struct AuthInterceptor {
+    check: Check,
+}
+
+#[derive(Clone, Default)]
+enum Check {
+    #[default]
+    None,
+    Token(Authenticator),
+    #[cfg(unix)]
+    Peer(PeerPolicy),
+}
+
+impl AuthInterceptor {
+    /// Requires an `authorization: Bearer <token>` header that `authenticator` accepts.
+    pub fn token(authenticator: Authenticator) -> Self {
+        Self {
+            check: Check::Token(authenticator),
+        }
+    }
+
+    /// Requires [`PeerCredentials`] that `policy` allows.
+    #[cfg(unix)]
+    pub fn peer(policy: PeerPolicy) -> Self {
+        Self {
+            check: Check::Peer(policy),
+        }
+    }
+}
+
+impl Interceptor for AuthInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        #[cfg(unix)]
+        let uid = request
+            .extensions()
+            .get::<PeerCredentials>()
+            .map(|peer| peer.uid);
+        #[cfg(not(unix))]
+        let uid = None;
+        let mut caller = Caller {
+            uid,
+            token: None,
+            subject: certificate_subject(&request),
+        };
+        let slot = request.extensions().get::<CallerSlot>().cloned();
+
+        let checked = match &self.check {
+            Check::None => Ok(None),
+            Check::Token(authenticator) => check_token(authenticator, &request),
+            #[cfg(unix)]
+            Check::Peer(policy) => check_peer(policy, &request).map(|()| None),
+        };
+        let checked = checked.map(|token| caller.token = token);
+        if let Some(slot) = slot {
+            slot.set(caller.clone());
+        }
+        checked?;
+
+        request.extensions_mut().insert(caller);
+        Ok(request)
+    }
+}
+
+/// Returns the name of the accepted token, if authentication is on.
+fn check_token(
+    authenticator: &Authenticator,
+    request: &Request<()>,
+) -> Result<Option<String>, Status> {
+    let token = request
+        .metadata()
+        .get("authorization")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    authenticator.check(token).inspect_err(|status| {
+        warn!(remote = ?request.remote_addr(), reason = status.message(), "Rejected unauthenticated request");
+    })
+}
+
+#[cfg(unix)]
+fn check_peer(policy: &PeerPolicy, request: &Request<()>) -> Result<(), Status> {
+    let Some(peer) = request.extensions().get::<PeerCredentials>() else {
+        return Err(Status::permission_denied("peer credentials unavailable"));
+    };
+    if !policy.allows(peer) {
+        warn!(uid = peer.uid, gid = peer.gid, pid = ?peer.pid, "Rejected Unix socket peer");
+        return Err(Status::permission_denied(format!(
+            "uid {} is not allowed to use this socket",
+            peer.uid
+        )));
+    }
+    Ok(())
+}
+
+/// Subject of the client certificate verified during the TLS handshake, e.g. `CN=ci`.
+fn certificate_subject(request: &Request<()>) -> Option<String> {
+    let certs = request.peer_certs()?;
+    let (_, cert) = x509_parser::parse_x509_certificate(certs.first()?).ok()?;
+    Some(cert.subject().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tonic::Code;
+
+    fn request(token: Option<&str>) -> Request<()> {
+        let mut request = Request::new(());
+        if let Some(token) = token {
+            request.metadata_mut().insert(
+                "authorization",
+                format!("Bearer {}", token).parse().unwrap(),
+            );
+        }
+        request
+    }
+
+    fn code(interceptor: &mut AuthInterceptor, token: Option<&str>) -> Option<Code> {
+        interceptor
+            .call(request(token))
+            .err()
+            .map(|status| status.code())
+    }
+
+    #[test]
+    fn test_interceptor_checks_configured_tokens() {
+        let mut open = AuthInterceptor::token(Authenticator::new(Vec::new(), None));
+        assert_eq!(code(&mut open, None), None);
+
+        let mut interceptor =
+            AuthInterceptor::token(Authenticator::new(vec!["s3cret".to_string()], None));
+        assert_eq!(code(&mut interceptor, Some("s3cret")), None);
+        assert_eq!(
+            code(&mut interceptor, Some("wrong")),
+            Some(Code::Unauthenticated)
+        );
+        assert_eq!(code(&mut interceptor, None), Some(Code::Unauthenticated));
+
+        let mut unix = AuthInterceptor::default();
+        assert_eq!(code(&mut unix, None), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_interceptor_checks_peer() {
+        let mut interceptor =
+            AuthInterceptor::peer(PeerPolicy::from_config(&DaemonConfig::default()).unwrap());
+        assert_eq!(code(&mut interceptor, None), Some(Code::PermissionDenied));
+
+        let mut call = |uid| {
+            let mut request = request(None);
+            request.extensions_mut().insert(PeerCredentials {
+                uid,
+                gid: uid,
+                pid: None,
+            });
+            interceptor.call(request).err().map(|status| status.code())
+        };
+        assert_eq!(call(nix::unistd::getuid().as_raw()), None);
+        assert_eq!(call(4_000_000_001), Some(Code::PermissionDenied));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_interceptor_fills_caller_slot_when_rejecting() {
+        let mut interceptor =
+            AuthInterceptor::token(Authenticator::new(vec!["s3cret".to_string()], None));
+        let slot = CallerSlot::default();
+        let mut request = request(Some("wrong"));
+        request.extensions_mut().insert(slot.clone());
+        request.extensions_mut().insert(PeerCredentials {
+            uid: 1000,
+            gid: 1000,
+            pid: None,
+        });
+
+        assert!(interceptor.call(request).is_err());
+        assert_eq!(slot.get().unwrap().to_string(), "uid:1000");
+    }
+
+    #[test]
+    fn test_token_file_changes_apply_immediately() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = TokenFile::new(dir.path().join("tokens"));
+        let authenticator = Authenticator::new(Vec::new(), Some(file.clone()));
+        assert!(authenticator.check(None).is_ok());
+
+        let token = file.create("ci").unwrap();
+        assert!(authenticator.check(Some(&token)).is_ok());
+        assert!(authenticator.check(None).is_err());
+
+        file.revoke("ci").unwrap();
+        assert!(authenticator.check(Some(&token)).is_err());
+    }
+}
@@ -0,0 +1,134 @@
+use nix::unistd::{Gid, Group, Uid, User, getuid};
+
+use crate::config::DaemonConfig;
+use crate::domain::PeerCredentials;
+use crate::error::{DaemonError, Result};
+
+/// Who may use the Unix socket: the daemon's own user, root, `allowed_uids`
+/// and members of `allowed_groups`.
+#[derive(Debug, Clone)]
+pub struct PeerPolicy {
+    uids: Vec<u32>,
+    gids: Vec<u32>,
+}
+
+impl PeerPolicy {
+    pub fn from_config(config: &DaemonConfig) -> Result<Self> {
+        let mut uids = vec![getuid().as_raw(), 0];
+        uids.extend(&config.allowed_uids);
+        let gids = config
+            .allowed_groups
+            .iter()
+            .map(|group| resolve_group(group).map(Gid::as_raw))
+            .collect::<Result<_>>()?;
+        Ok(Self { uids, gids })
+    }
+
+    pub fn allows(&self, peer: &PeerCredentials) -> bool {
+        if self.uids.contains(&peer.uid) || self.gids.contains(&peer.gid) {
+            return true;
+        }
+        !self.gids.is_empty()
+            && supplementary_groups(peer.uid, peer.gid)
+                .iter()
+                .any(|gid| self.gids.contains(gid))
+    }
+}
+
+/// Groups `uid` belongs to according to the group database.
+#[cfg(not(target_vendor = "apple"))]
+fn supplementary_groups(uid: u32, gid: u32) -> Vec<u32> {
+    let Ok(Some(user)) = User::from_uid(Uid::from_raw(uid)) else {
+        return Vec::new();
+    };
+    let Ok(name) = std::ffi::CString::new(user.name) else {
+        return Vec::new();
+    };
+    nix::unistd::getgrouplist(&name, Gid::from_raw(gid))
+        .map(|groups| groups.into_iter().map(Gid::as_raw).collect())
+        .unwrap_or_default()
+}
+
+/// `getgrouplist` is unavailable on Apple platforms, so only the primary group is checked.
+#[cfg(target_vendor = "apple")]
+fn supplementary_groups(_uid: u32, _gid: u32) -> Vec<u32> {
+    Vec::new()
+}
+
+/// Accepts a user name or a numeric uid.
+pub(crate) fn resolve_user(user: &str) -> Result<Uid> {
+    if let Ok(uid) = user.parse() {
+        return Ok(Uid::from_raw(uid));
+    }
+    match User::from_name(user) {
+        Ok(Some(user)) => Ok(user.uid),
+        _ => Err(DaemonError::SocketAccessError(format!(
+            "unknown user '{}'",
+            user
+        ))),
+    }
+}
+
+/// Accepts a group name or a numeric gid.
+pub(crate) fn resolve_group(group: &str) -> Result<Gid> {
+    if let Ok(gid) = group.parse() {
+        return Ok(Gid::from_raw(gid));
+    }
+    match Group::from_name(group) {
+        Ok(Some(group)) => Ok(group.gid),
+        _ => Err(DaemonError::SocketAccessError(format!(
+            "unknown group '{}'",
+            group
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nix::unistd::getgid;
+
+    fn peer(uid: u32, gid: u32) -> PeerCredentials {
+        PeerCredentials {
+            uid,
+            gid,
+            pid: None,
+        }
+    }
+
+    #[test]
+    fn test_policy_allows_own_user_and_configured_ids() {
+        // Ids far outside the range the test host hands out.
+        let (stranger, stranger_group) = (4_000_000_001, 4_000_000_002);
+
+        let policy = PeerPolicy::from_config(&DaemonConfig::default()).unwrap();
+        assert!(policy.allows(&peer(getuid().as_raw(), getgid().as_raw())));
+        assert!(policy.allows(&peer(0, 0)));
+        assert!(!policy.allows(&peer(stranger, stranger_group)));
+
+        let policy = PeerPolicy::from_config(&DaemonConfig {
+            allowed_uids: vec![stranger],
+            ..DaemonConfig::default()
+        })
+        .unwrap();
+        assert!(policy.allows(&peer(stranger, stranger_group)));
+
+        let policy = PeerPolicy::from_config(&DaemonConfig {
+            allowed_groups: vec![stranger_group.to_string()],
+            ..DaemonConfig::default()
+        })
+        .unwrap();
+        assert!(policy.allows(&peer(stranger, stranger_group)));
+        assert!(!policy.allows(&peer(stranger, stranger)));
+    }
+
+    #[test]
+    fn test_resolve_names() {
+        assert_eq!(resolve_user("0").unwrap(), Uid::from_raw(0));
+        assert_eq!(resolve_group("0").unwrap(), Gid::from_raw(0));
+        assert!(matches!(
+            resolve_group("no-such-group-ffit"),
+            Err(DaemonError::SocketAccessError(_))
+        ));
+    }
+}
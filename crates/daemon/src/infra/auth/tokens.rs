@@ -0,0 +1,214 @@
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use tracing::warn;
+
+use super::CONFIG_TOKEN_NAME;
+use crate::error::{DaemonError, Result};
+
+/// Random bytes in a generated token, hex-encoded on disk.
+const TOKEN_BYTES: usize = 32;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenEntry {
+    pub name: String,
+    pub token: String,
+}
+
+/// Named bearer tokens stored one per line as `<name> <token>`.
+///
+/// The file is written with mode 0600 and rejected when group or others can access it.
+#[derive(Debug, Clone)]
+pub struct TokenFile {
+    path: PathBuf,
+}
+
+impl TokenFile {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Reads all tokens; a missing file holds none.
+    pub fn load(&self) -> Result<Vec<TokenEntry>> {
+        let contents = match fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+        self.check_permissions()?;
+
+        Ok(contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| line.split_once(char::is_whitespace))
+            .filter(|(name, _)| {
+                // Would authenticate as the `auth_tokens` from the config.
+                let reserved = *name == CONFIG_TOKEN_NAME;
+                if reserved {
+                    warn!(path = %self.path.display(), "Ignoring token with reserved name '{}'", name);
+                }
+                !reserved
+            })
+            .map(|(name, token)| TokenEntry {
+                name: name.to_string(),
+                token: token.trim().to_string(),
+            })
+            .collect())
+    }
+
+    /// Adds a token called `name` and returns its secret, which is not shown again.
+    pub fn create(&self, name: &str) -> Result<String> {
+        if name.is_empty() || name.starts_with('#') || name.contains(char::is_whitespace) {
+            return Err(DaemonError::TokenError(format!(
+                "invalid token name '{}': use a single word",
+                name
+            )));
+        }
+
+        if name == CONFIG_TOKEN_NAME {
+            return Err(DaemonError::TokenError(format!(
+                "token name '{}' is reserved for the tokens in the config",
+                name
+            )));
+        }
+
+        let mut entries = self.load()?;
+        if entries.iter().any(|entry| entry.name == name) {
+            return Err(DaemonError::TokenError(format!(
+                "a token named '{}' already exists",
+                name
+            )));
+        }
+
+        let token = generate_token()?;
+        entries.push(TokenEntry {
+            name: name.to_string(),
+            token: token.clone(),
+        });
+        self.save(&entries)?;
+        Ok(token)
+    }
+
+    /// Removes the token called `name`; returns false if there was none.
+    pub fn revoke(&self, name: &str) -> Result<bool> {
+        let mut entries = self.load()?;
+        let before = entries.len();
+        entries.retain(|entry| entry.name != name);
+        if entries.len() == before {
+            return Ok(false);
+        }
+        self.save(&entries)?;
+        Ok(true)
+    }
+
+    /// Replaces the file atomically so the daemon never reads a partial write.
+    fn save(&self, entries: &[TokenEntry]) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let tmp = self.path.with_extension("tmp");
+        let _ = fs::remove_file(&tmp);
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+
+        let mut file = options.open(&tmp)?;
+        writeln!(file, "# ffit bearer tokens: <name> <token>")?;
+        for entry in entries {
+            writeln!(file, "{} {}", entry.name, entry.token)?;
+        }
+        file.sync_all()?;
+        fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    fn check_permissions(&self) -> Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let mode = fs::metadata(&self.path)?.permissions().mode();
+        if mode & 0o077 != 0 {
+            return Err(DaemonError::TokenError(format!(
+                "{} is accessible by other users (mode {:o}); run `chmod 600` on it",
+                self.path.display(),
+                mode & 0o777
+            )));
+        }
+        Ok(())
+    }
+
+    #[cfg(not(unix))]
+    fn check_permissions(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+fn generate_token() -> Result<String> {
+    let mut bytes = [0u8; TOKEN_BYTES];
+    getrandom::fill(&mut bytes)
+        .map_err(|e| DaemonError::TokenError(format!("failed to generate token: {}", e)))?;
+    Ok(bytes.iter().map(|byte| format!("{:02x}", byte)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A token file that does not exist yet, in a directory removed on drop.
+    fn scratch_file() -> (tempfile::TempDir, TokenFile) {
+        let dir = tempfile::tempdir().unwrap();
+        let file = TokenFile::new(dir.path().join("tokens"));
+        (dir, file)
+    }
+
+    #[test]
+    fn test_create_and_revoke() {
+        let (_dir, file) = scratch_file();
+        assert!(file.load().unwrap().is_empty());
+
+        let ci = file.create("ci").unwrap();
+        let laptop = file.create("laptop").unwrap();
+        assert_eq!(ci.len(), TOKEN_BYTES * 2);
+        assert_ne!(ci, laptop);
+        assert!(matches!(file.create("ci"), Err(DaemonError::TokenError(_))));
+        assert!(matches!(
+            file.create(CONFIG_TOKEN_NAME),
+            Err(DaemonError::TokenError(_))
+        ));
+
+        assert!(file.revoke("ci").unwrap());
+        assert!(!file.revoke("ci").unwrap());
+        assert_eq!(
+            file.load().unwrap(),
+            vec![TokenEntry {
+                name: "laptop".to_string(),
+                token: laptop,
+            }]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_load_rejects_shared_file() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let (_dir, file) = scratch_file();
+        file.create("ci").unwrap();
+        let mode = fs::metadata(file.path()).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+
+        fs::set_permissions(file.path(), fs::Permissions::from_mode(0o644)).unwrap();
+        assert!(matches!(file.load(), Err(DaemonError::TokenError(_))));
+    }
+}
@@ -0,0 +1,236 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use http::{HeaderMap, Request, Response};
+use http_body::{Body, Frame, SizeHint};
+use tonic::Status;
+use tower::{Layer, Service};
+
+use super::{InFlight, RequestGuard};
+use crate::domain::Caller;
+use crate::infra::rpc_method;
+
+/// Tracks every request in an [`InFlight`] registry, refuses new ones once it
+/// drains, and ends the running ones when it is cancelled.
+///
+/// Runs inside the auth interceptor, which attaches the [`Caller`].
+#[derive(Clone)]
+pub struct InFlightLayer {
+    in_flight: InFlight,
+}
+
+impl InFlightLayer {
+    pub fn new(in_flight: InFlight) -> Self {
+        Self { in_flight }
+    }
+}
+
+impl<S> Layer<S> for InFlightLayer {
+    type Service = InFlightService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        InFlightService {
+            inner,
+            in_flight: self.in_flight.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct InFlightService<S> {
+    inner: S,
+    in_flight: InFlight,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for InFlightService<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+    S::Future: Send + 'static,
+    S::Error: Send + 'static,
+    ResBody: Send + 'static,
+{
+    type Response = Response<InFlightBody<ResBody>>;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<ReqBody>) -> Self::Future {
+        let (service, method) = rpc_method(request.uri().path());
+        let method = format!("{}/{}", service, method);
+        let caller = request
+            .extensions()
+            .get::<Caller>()
+            .cloned()
+            .unwrap_or_default()
+            .to_string();
+
+        let Some(guard) = self.in_flight.start(method, caller) else {
+            let status = Status::unavailable("daemon is shutting down");
+            return Box::pin(std::future::ready(Ok(status_response(&status))));
+        };
+        let in_flight = self.in_flight.clone();
+        let future = self.inner.call(request);
+
+        Box::pin(async move {
+            let response = tokio::select! {
+                response = future => response?,
+                // Dropping the handler's future here is what cancels its work.
+                () = in_flight.cancelled() => return Ok(status_response(&abandoned())),
+            };
+
+            let (parts, body) = response.into_parts();
+            Ok(Response::from_parts(
+                parts,
+                InFlightBody {
+                    inner: Some(body),
+                    guard: Some(guard),
+                    cancelled: Some(Box::pin(in_flight.cancelled())),
+                },
+            ))
+        })
+    }
+}
+
+/// Response body that keeps its request tracked until the body is done or dropped,
+/// so streaming RPCs count until their last message, and ends it with `CANCELLED`
+/// trailers when the registry is cancelled.
+pub struct InFlightBody<B> {
+    /// `None` for responses this layer answered itself, and once cancelled.
+    inner: Option<B>,
+    guard: Option<RequestGuard>,
+    cancelled: Option<Pin<Box<dyn Future<Output = ()> + Send>>>,
+}
+
+impl<B> Body for InFlightBody<B>
+where
+    B: Body + Unpin,
+{
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        if let Some(cancelled) = self.cancelled.as_mut()
+            && cancelled.as_mut().poll(cx).is_ready()
+        {
+            // Drops the handler's stream along with whatever it was still producing.
+            self.inner = None;
+            self.cancelled = None;
+            self.guard.take();
+            return Poll::Ready(Some(Ok(Frame::trailers(status_headers(&abandoned())))));
+        }
+
+        let Some(inner) = self.inner.as_mut() else {
+            return Poll::Ready(None);
+        };
+        let poll = Pin::new(inner).poll_frame(cx);
+        if matches!(poll, Poll::Ready(None) | Poll::Ready(Some(Err(_)))) {
+            self.guard.take();
+            self.cancelled = None;
+        }
+        poll
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.as_ref().is_none_or(B::is_end_stream)
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner
+            .as_ref()
+            .map_or_else(|| SizeHint::with_exact(0), B::size_hint)
+    }
+}
+
+fn abandoned() -> Status {
+    Status::cancelled("daemon shut down before the request finished")
+}
+
+/// A trailers-only response carrying `status`.
+fn status_response<B>(status: &Status) -> Response<InFlightBody<B>> {
+    let (parts, ()) = status.clone().into_http::<()>().into_parts();
+    Response::from_parts(
+        parts,
+        InFlightBody {
+            inner: None,
+            guard: None,
+            cancelled: None,
+        },
+    )
+}
+
+fn status_headers(status: &Status) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    // Only fails for details that cannot be encoded, which these statuses have none of.
+    let _ = status.add_header(&mut headers);
+    headers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::Infallible;
+    use std::future::Pending;
+
+    /// Never answers, like a handler still working at shutdown.
+    #[derive(Clone)]
+    struct Stuck;
+
+    impl Service<Request<()>> for Stuck {
+        type Response = Response<String>;
+        type Error = Infallible;
+        type Future = Pending<Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _request: Request<()>) -> Self::Future {
+            std::future::pending()
+        }
+    }
+
+    fn request() -> Request<()> {
+        Request::builder()
+            .uri("/daemon.v1.DaemonService/StreamLogs")
+            .body(())
+            .unwrap()
+    }
+
+    fn code(response: &Response<InFlightBody<String>>) -> Option<tonic::Code> {
+        crate::infra::grpc_status(response.headers())
+    }
+
+    #[tokio::test]
+    async fn test_cancel_ends_running_request() {
+        let in_flight = InFlight::new();
+        let mut service = InFlightLayer::new(in_flight.clone()).layer(Stuck);
+
+        let call = tokio::spawn(service.call(request()));
+        let requests = in_flight.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "daemon.v1.DaemonService/StreamLogs");
+
+        in_flight.cancel();
+        let response = call.await.unwrap().unwrap();
+        assert_eq!(code(&response), Some(tonic::Code::Cancelled));
+        assert!(in_flight.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_draining_refuses_request() {
+        let in_flight = InFlight::new();
+        let mut service = InFlightLayer::new(in_flight.clone()).layer(Stuck);
+
+        in_flight.start_draining();
+        let response = service.call(request()).await.unwrap();
+        assert_eq!(code(&response), Some(tonic::Code::Unavailable));
+        assert!(in_flight.is_empty());
+    }
+}
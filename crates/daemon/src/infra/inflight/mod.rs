@@ -0,0 +1,183 @@
+mod layer;
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use tokio::sync::watch;
+
+pub use layer::{InFlightBody, InFlightLayer, InFlightService};
+
+/// Requests the daemon is serving, so shutdown can wait for them, refuse new
+/// ones, and report and cancel whatever is still running when it gives up.
+#[derive(Clone)]
+pub struct InFlight {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    next_id: AtomicU64,
+    state: Mutex<State>,
+    cancel: watch::Sender<bool>,
+}
+
+#[derive(Default)]
+struct State {
+    draining: bool,
+    requests: BTreeMap<u64, Tracked>,
+}
+
+struct Tracked {
+    method: String,
+    caller: String,
+    started: Instant,
+}
+
+/// A request still being served, as reported when shutdown abandons it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InFlightRequest {
+    /// `service/method`, e.g. `daemon.v1.DaemonService/StreamLogs`.
+    pub method: String,
+    pub caller: String,
+    pub elapsed: Duration,
+}
+
+impl InFlight {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                next_id: AtomicU64::new(0),
+                state: Mutex::new(State::default()),
+                cancel: watch::Sender::new(false),
+            }),
+        }
+    }
+
+    /// Tracks a request until the returned guard is dropped.
+    /// Returns `None` once draining, when no new requests are accepted.
+    fn start(&self, method: String, caller: String) -> Option<RequestGuard> {
+        let mut state = self.state();
+        if state.draining {
+            return None;
+        }
+        let id = self.inner.next_id.fetch_add(1, Ordering::Relaxed);
+        state.requests.insert(
+            id,
+            Tracked {
+                method,
+                caller,
+                started: Instant::now(),
+            },
+        );
+        Some(RequestGuard {
+            in_flight: self.clone(),
+            id,
+        })
+    }
+
+    /// Refuses new requests from now on; those already running continue.
+    pub fn start_draining(&self) {
+        self.state().draining = true;
+    }
+
+    pub fn is_draining(&self) -> bool {
+        self.state().draining
+    }
+
+    pub fn len(&self) -> usize {
+        self.state().requests.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Requests still running, oldest first.
+    pub fn requests(&self) -> Vec<InFlightRequest> {
+        self.state()
+            .requests
+            .values()
+            .map(|tracked| InFlightRequest {
+                method: tracked.method.clone(),
+                caller: tracked.caller.clone(),
+                elapsed: tracked.started.elapsed(),
+            })
+            .collect()
+    }
+
+    /// Ends every running request with `CANCELLED`, dropping its handler.
+    pub fn cancel(&self) {
+        self.inner.cancel.send_replace(true);
+    }
+
+    /// Completes once [`cancel`](Self::cancel) has been called.
+    fn cancelled(&self) -> impl Future<Output = ()> + Send + 'static {
+        let mut cancel = self.inner.cancel.subscribe();
+        async move {
+            // The sender lives as long as any clone of this registry.
+            let _ = cancel.wait_for(|cancelled| *cancelled).await;
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.inner.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for InFlight {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// One tracked request; untracked when dropped.
+struct RequestGuard {
+    in_flight: InFlight,
+    id: u64,
+}
+
+impl Drop for RequestGuard {
+    fn drop(&mut self) {
+        self.in_flight.state().requests.remove(&self.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tracks_requests_until_dropped() {
+        let in_flight = InFlight::new();
+        let first = in_flight.start("a/First".to_string(), "uid:1".to_string());
+        let second = in_flight.start("a/Second".to_string(), "anonymous".to_string());
+        assert_eq!(in_flight.len(), 2);
+
+        drop(first);
+        let requests = in_flight.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "a/Second");
+        assert_eq!(requests[0].caller, "anonymous");
+
+        drop(second);
+        assert!(in_flight.is_empty());
+    }
+
+    #[test]
+    fn test_draining_refuses_new_requests() {
+        let in_flight = InFlight::new();
+        let running = in_flight.start("a/Running".to_string(), String::new());
+
+        in_flight.start_draining();
+        assert!(in_flight.is_draining());
+        assert!(
+            in_flight
+                .start("a/Late".to_string(), String::new())
+                .is_none()
+        );
+        assert_eq!(in_flight.len(), 1);
+        drop(running);
+    }
+}
@@ -0,0 +1,331 @@
+use std::collections::BTreeMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, BufWriter, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::oneshot;
+use tracing::{error, warn};
+
+use crate::domain::{Job, JobId};
+
+/// Records below which the store is never compacted.
+const COMPACT_MIN_RECORDS: usize = 1000;
+
+/// Append-only JSON-lines job store: every change to a job appends its full
+/// record, and the last record of each job wins when the file is read back.
+///
+/// Superseded records are dropped by [`JobStore::compact`], which rewrites
+/// the file and renames it into place, starting with a meta record of the
+/// highest id ever stored so ids of pruned jobs are not issued again. Only one daemon may write the store:
+/// before an upgrade the old one stops its jobs and flushes, and writes
+/// nothing more once the new one has recovered them.
+pub struct JobStore {
+    path: PathBuf,
+    file: File,
+    /// Lines in the file, for deciding when to compact.
+    records: usize,
+    /// Highest job id ever stored, including jobs since pruned.
+    last_id: u64,
+}
+
+/// First line of a compacted store.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Meta {
+    last_id: u64,
+}
+
+impl JobStore {
+    /// Opens `path` for appending, creating it (mode 0600) and its directory if needed.
+    pub fn open(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = open_append(&path)?;
+        Ok(Self {
+            path,
+            file,
+            records: 0,
+            last_id: 0,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Highest job id ever stored, known after [`JobStore::load`].
+    pub fn last_id(&self) -> JobId {
+        JobId(self.last_id)
+    }
+
+    /// Reads the latest record of every job, oldest job first.
+    /// Malformed lines, such as one cut short by a crash, are skipped.
+    pub fn load(&mut self) -> io::Result<Vec<Job>> {
+        let file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+
+        let mut jobs = BTreeMap::<JobId, Job>::new();
+        self.records = 0;
+        for (index, line) in BufReader::new(file).lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            self.records += 1;
+            match serde_json::from_str::<Job>(&line) {
+                Ok(job) => {
+                    self.last_id = self.last_id.max(job.id.0);
+                    jobs.insert(job.id, job);
+                }
+                Err(e) => match serde_json::from_str::<Meta>(&line) {
+                    Ok(meta) => self.last_id = self.last_id.max(meta.last_id),
+                    Err(_) => {
+                        warn!(line = index + 1, error = %e, "Skipping malformed job record")
+                    }
+                },
+            }
+        }
+        Ok(jobs.into_values().collect())
+    }
+
+    /// Appends the current record of `job`.
+    pub fn append(&mut self, job: &Job) -> io::Result<()> {
+        let mut line = serde_json::to_vec(job)?;
+        line.push(b'\n');
+        self.file.write_all(&line)?;
+        self.records += 1;
+        self.last_id = self.last_id.max(job.id.0);
+        Ok(())
+    }
+
+    /// Whether superseded records make up most of the file.
+    pub fn should_compact(&self, jobs: usize) -> bool {
+        self.records >= COMPACT_MIN_RECORDS && self.records > jobs * 2
+    }
+
+    /// Replaces the file with the highest id stored so far and one record
+    /// per job in `jobs`, which must have been loaded or appended before.
+    pub fn compact<'a>(&mut self, jobs: impl IntoIterator<Item = &'a Job>) -> io::Result<()> {
+        let mut name = self.path.file_name().unwrap_or_default().to_os_string();
+        name.push(".tmp");
+        let tmp = self.path.with_file_name(name);
+
+        let mut writer = BufWriter::new(create_private(&tmp)?);
+        let meta = Meta {
+            last_id: self.last_id,
+        };
+        let mut records = 1;
+        serde_json::to_writer(&mut writer, &meta)?;
+        writer.write_all(b"\n")?;
+        for job in jobs {
+            serde_json::to_writer(&mut writer, job)?;
+            writer.write_all(b"\n")?;
+            records += 1;
+        }
+        writer
+            .into_inner()
+            .map_err(io::IntoInnerError::into_error)?
+            .sync_all()?;
+        fs::rename(&tmp, &self.path)?;
+
+        self.file = open_append(&self.path)?;
+        self.records = records;
+        Ok(())
+    }
+}
+
+impl JobStore {
+    /// Moves the store to a writer thread, which from then on appends every
+    /// record and compacts down to the live jobs, starting from `jobs`.
+    pub fn into_writer<'a>(
+        self,
+        jobs: impl IntoIterator<Item = &'a Job>,
+    ) -> io::Result<JobStoreWriter> {
+        let live = jobs.into_iter().map(|job| (job.id, job.clone())).collect();
+        let path = self.path.clone();
+        let (writer, queue) = mpsc::channel();
+        thread::Builder::new()
+            .name("job-store".to_string())
+            .spawn(move || self.write_records(live, queue))?;
+        Ok(JobStoreWriter { path, writer })
+    }
+
+    /// Runs on the writer thread until the [`JobStoreWriter`] is dropped.
+    fn write_records(mut self, mut live: BTreeMap<JobId, Job>, queue: mpsc::Receiver<Queued>) {
+        for queued in queue {
+            match queued {
+                Queued::Record(job) => {
+                    if let Err(e) = self.append(&job) {
+                        error!(job = %job.id, path = %self.path.display(), error = %e, "Failed to store job");
+                    }
+                    live.insert(job.id, *job);
+                }
+                Queued::Remove(id) => {
+                    live.remove(&id);
+                }
+                Queued::Flush(done) => {
+                    let _ = done.send(());
+                    continue;
+                }
+            }
+            if self.should_compact(live.len())
+                && let Err(e) = self.compact(live.values())
+            {
+                error!(path = %self.path.display(), error = %e, "Failed to compact job store");
+            }
+        }
+    }
+}
+
+/// Queues changes for the [`JobStore`] on its writer thread, so jobs never
+/// wait on the disk, nor hold the jobs table while the store is compacted.
+/// The channel is unbounded so no change is dropped while it catches up.
+#[derive(Clone)]
+pub struct JobStoreWriter {
+    path: PathBuf,
+    writer: mpsc::Sender<Queued>,
+}
+
+enum Queued {
+    Record(Box<Job>),
+    /// The job was pruned; its records go with the next compaction.
+    Remove(JobId),
+    /// Answered once everything sent before it is written.
+    Flush(oneshot::Sender<()>),
+}
+
+impl JobStoreWriter {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Queues the current record of `job`; failures are logged by the writer.
+    pub fn append(&self, job: &Job) {
+        self.send(Queued::Record(Box::new(job.clone())));
+    }
+
+    /// Leaves the job out of the store from the next compaction on.
+    pub fn remove(&self, id: JobId) {
+        self.send(Queued::Remove(id));
+    }
+
+    /// Waits until every change queued so far is written.
+    pub async fn flush(&self) {
+        let (done, written) = oneshot::channel();
+        if self.writer.send(Queued::Flush(done)).is_ok() {
+            let _ = written.await;
+        }
+    }
+
+    fn send(&self, queued: Queued) {
+        if self.writer.send(queued).is_err() {
+            error!(path = %self.path.display(), "Job store writer stopped, dropping change");
+        }
+    }
+}
+
+fn open_append(path: &Path) -> io::Result<File> {
+    let mut options = OpenOptions::new();
+    options.create(true).append(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options.open(path)
+}
+
+fn create_private(path: &Path) -> io::Result<File> {
+    let mut options = OpenOptions::new();
+    options.create(true).write(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options.open(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Caller, JobSpec, JobState};
+
+    fn job(id: u64) -> Job {
+        let spec = JobSpec {
+            kind: "sum".to_string(),
+            params: BTreeMap::from([("values".to_string(), "1,2".to_string())]),
+            max_retries: 0,
+            priority: 0,
+        };
+        Job::new(JobId(id), spec, &Caller::default())
+    }
+
+    #[tokio::test]
+    async fn test_last_record_wins_and_compaction_keeps_it() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("jobs.jsonl");
+
+        let mut store = JobStore::open(&path).unwrap();
+        assert!(store.load().unwrap().is_empty());
+        let mut first = job(1);
+        store.append(&first).unwrap();
+        store.append(&job(2)).unwrap();
+        first.start();
+        store.append(&first).unwrap();
+        // A record torn by a crash is skipped.
+        fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(b"{\"id\":3,")
+            .unwrap();
+
+        let mut store = JobStore::open(&path).unwrap();
+        let jobs = store.load().unwrap();
+        assert_eq!(jobs.len(), 2);
+        assert_eq!(jobs[0].state, JobState::Running);
+        assert_eq!(jobs[1].id, JobId(2));
+        assert!(!store.should_compact(jobs.len()));
+
+        store.compact(&jobs).unwrap();
+        store.append(&job(3)).unwrap();
+        let contents = fs::read_to_string(&path).unwrap();
+        assert_eq!(contents.lines().count(), 4);
+        assert_eq!(JobStore::open(&path).unwrap().load().unwrap()[0], jobs[0]);
+
+        let store = JobStore::open(&path).unwrap();
+        let writer = store.into_writer(&jobs).unwrap();
+        first.succeed("3".to_string());
+        writer.append(&first);
+        writer.remove(JobId(2));
+        writer.flush().await;
+        // A removed job stays in the file until the next compaction.
+        let jobs = JobStore::open(&path).unwrap().load().unwrap();
+        assert_eq!(jobs.len(), 3);
+        assert_eq!(jobs[0].state, JobState::Succeeded);
+
+        // Compaction keeps the highest id, even when that job is gone.
+        let mut store = JobStore::open(&path).unwrap();
+        store.load().unwrap();
+        store.compact(&jobs[..2]).unwrap();
+        let mut store = JobStore::open(&path).unwrap();
+        assert_eq!(store.load().unwrap().len(), 2);
+        assert_eq!(store.last_id(), JobId(3));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+}
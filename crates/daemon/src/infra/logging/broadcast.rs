@@ -0,0 +1,136 @@
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use tokio::sync::broadcast;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::Layer;
+use tracing_subscriber::layer::Context;
+
+use crate::domain::LogRecord;
+
+/// Live subscribers that fall this far behind start losing records.
+const CHANNEL_CAPACITY: usize = 1024;
+
+/// Tracing layer that keeps the most recent records and fans new ones out to subscribers.
+#[derive(Clone)]
+pub struct LogBroadcast {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    capacity: usize,
+    buffer: Mutex<VecDeque<LogRecord>>,
+    sender: broadcast::Sender<LogRecord>,
+}
+
+impl LogBroadcast {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self {
+            inner: Arc::new(Inner {
+                capacity,
+                buffer: Mutex::new(VecDeque::with_capacity(capacity)),
+                sender,
+            }),
+        }
+    }
+
+    /// Returns the buffered records together with a receiver for everything logged after them.
+    pub fn subscribe(&self) -> (Vec<LogRecord>, broadcast::Receiver<LogRecord>) {
+        // Holding the buffer lock keeps the snapshot and the receiver gap-free.
+        let buffer = self.inner.buffer.lock().unwrap_or_else(|e| e.into_inner());
+        let receiver = self.inner.sender.subscribe();
+        (buffer.iter().cloned().collect(), receiver)
+    }
+
+    fn push(&self, record: LogRecord) {
+        let mut buffer = self.inner.buffer.lock().unwrap_or_else(|e| e.into_inner());
+        if self.inner.capacity == 0 {
+            let _ = self.inner.sender.send(record);
+            return;
+        }
+        if buffer.len() == self.inner.capacity {
+            buffer.pop_front();
+        }
+        buffer.push_back(record.clone());
+        let _ = self.inner.sender.send(record);
+    }
+}
+
+impl<S: Subscriber> Layer<S> for LogBroadcast {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        let mut visitor = RecordVisitor::default();
+        event.record(&mut visitor);
+
+        self.push(LogRecord {
+            timestamp: SystemTime::now(),
+            level: *metadata.level(),
+            target: metadata.target().to_string(),
+            message: visitor.message,
+            fields: visitor.fields,
+        });
+    }
+}
+
+#[derive(Default)]
+struct RecordVisitor {
+    message: String,
+    fields: Vec<(String, String)>,
+}
+
+impl Visit for RecordVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else {
+            self.fields
+                .push((field.name().to_string(), value.to_string()));
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == "message" {
+            self.message = format!("{:?}", value);
+        } else {
+            self.fields
+                .push((field.name().to_string(), format!("{:?}", value)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing::Level;
+    use tracing_subscriber::Registry;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[test]
+    fn test_buffers_and_broadcasts_events() {
+        let broadcast = LogBroadcast::new(2);
+        let subscriber = Registry::default().with(broadcast.clone());
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!("first");
+            let (_, mut receiver) = broadcast.subscribe();
+            tracing::warn!(address = "tcp://127.0.0.1:1", "second");
+            tracing::error!("third");
+
+            let live = receiver.try_recv().expect("second is broadcast");
+            assert_eq!(live.message, "second");
+            assert_eq!(live.level, Level::WARN);
+            assert_eq!(
+                live.fields,
+                vec![("address".to_string(), "tcp://127.0.0.1:1".to_string())]
+            );
+        });
+
+        let (replay, _) = broadcast.subscribe();
+        let messages: Vec<_> = replay.iter().map(|r| r.message.as_str()).collect();
+        assert_eq!(messages, vec!["second", "third"]);
+    }
+}
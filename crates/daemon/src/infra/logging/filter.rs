@@ -0,0 +1,69 @@
+use tracing_subscriber::reload::Handle;
+use tracing_subscriber::{EnvFilter, Registry};
+
+use crate::error::{DaemonError, Result};
+
+/// Handle to the subscriber's filter, which can be swapped while the daemon runs.
+#[derive(Clone)]
+pub struct LogFilter {
+    handle: Handle<EnvFilter, Registry>,
+}
+
+impl LogFilter {
+    pub fn new(handle: Handle<EnvFilter, Registry>) -> Self {
+        Self { handle }
+    }
+
+    pub fn parse(directives: &str) -> Result<EnvFilter> {
+        EnvFilter::try_new(directives)
+            .map_err(|e| DaemonError::InvalidLogFilter(format!("{}: {}", directives, e)))
+    }
+
+    pub fn current(&self) -> Result<String> {
+        self.handle
+            .with_current(|filter| filter.to_string())
+            .map_err(|e| DaemonError::LogFilterError(e.to_string()))
+    }
+
+    /// Replaces the filter and returns the previous directives.
+    pub fn set(&self, directives: &str) -> Result<String> {
+        let filter = Self::parse(directives)?;
+        let previous = self.current()?;
+        self.handle
+            .reload(filter)
+            .map_err(|e| DaemonError::LogFilterError(e.to_string()))?;
+        Ok(previous)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::reload;
+
+    #[test]
+    fn test_set_replaces_filter() {
+        let (layer, handle) = reload::Layer::new(EnvFilter::new("daemon=info"));
+        let _subscriber = Registry::default().with(layer);
+        let filter = LogFilter::new(handle);
+
+        assert_eq!(filter.current().unwrap(), "daemon=info");
+        let previous = filter.set("daemon=debug").unwrap();
+        assert_eq!(previous, "daemon=info");
+        assert_eq!(filter.current().unwrap(), "daemon=debug");
+    }
+
+    #[test]
+    fn test_set_rejects_invalid_directive() {
+        let (layer, handle) = reload::Layer::new(EnvFilter::new("daemon=info"));
+        let _subscriber = Registry::default().with(layer);
+        let filter = LogFilter::new(handle);
+
+        assert!(matches!(
+            filter.set("daemon=loud"),
+            Err(DaemonError::InvalidLogFilter(_))
+        ));
+        assert_eq!(filter.current().unwrap(), "daemon=info");
+    }
+}
@@ -0,0 +1,184 @@
+use std::fmt::{self, Debug};
+
+use tracing::field::{Field, Visit};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::field::RecordFields;
+use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::time::{FormatTime, SystemTime};
+use tracing_subscriber::fmt::{FmtContext, FormatEvent, FormatFields, FormattedFields};
+use tracing_subscriber::registry::LookupSpan;
+
+/// Formats each event as one logfmt line:
+/// `ts=... level=info target=daemon::ui::grpc::ping span=ping message=hello msg="Received ping"`.
+///
+/// Span fields are rendered by [`LogfmtFields`], so pair it with `.fmt_fields(LogfmtFields)`.
+pub struct Logfmt;
+
+impl<S, N> FormatEvent<S, N> for Logfmt
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        let metadata = event.metadata();
+
+        writer.write_str("ts=")?;
+        SystemTime.format_time(&mut writer)?;
+        write!(
+            writer,
+            " level={} target={}",
+            metadata.level().as_str().to_ascii_lowercase(),
+            quote(metadata.target())
+        )?;
+
+        if let Some(scope) = ctx.event_scope() {
+            for span in scope.from_root() {
+                write!(writer, " span={}", quote(span.name()))?;
+                if let Some(fields) = span.extensions().get::<FormattedFields<N>>() {
+                    writer.write_str(fields)?;
+                }
+            }
+        }
+
+        let mut visitor = LogfmtVisitor::new(writer.by_ref(), true);
+        event.record(&mut visitor);
+        visitor.result?;
+        writeln!(writer)
+    }
+}
+
+/// Renders span fields as ` key=value` pairs, each with a leading space.
+pub struct LogfmtFields;
+
+impl<'writer> FormatFields<'writer> for LogfmtFields {
+    fn format_fields<R: RecordFields>(&self, writer: Writer<'writer>, fields: R) -> fmt::Result {
+        let mut visitor = LogfmtVisitor::new(writer, false);
+        fields.record(&mut visitor);
+        visitor.result
+    }
+
+    fn add_fields(
+        &self,
+        current: &'writer mut FormattedFields<Self>,
+        fields: &tracing::span::Record<'_>,
+    ) -> fmt::Result {
+        // Pairs already start with a space, so no separator is needed.
+        self.format_fields(current.as_writer(), fields)
+    }
+}
+
+struct LogfmtVisitor<'writer> {
+    writer: Writer<'writer>,
+    /// Write the event's `message` as `msg`; span fields keep their names.
+    is_event: bool,
+    result: fmt::Result,
+}
+
+impl<'writer> LogfmtVisitor<'writer> {
+    fn new(writer: Writer<'writer>, is_event: bool) -> Self {
+        Self {
+            writer,
+            is_event,
+            result: Ok(()),
+        }
+    }
+
+    fn write_pair(&mut self, field: &Field, value: &str) {
+        if self.result.is_err() {
+            return;
+        }
+        let key = match field.name() {
+            "message" if self.is_event => "msg",
+            name => name,
+        };
+        self.result = write!(self.writer, " {}={}", key, quote(value));
+    }
+}
+
+impl Visit for LogfmtVisitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.write_pair(field, value);
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.write_pair(field, &format!("{:?}", value));
+    }
+}
+
+/// Quotes values that would otherwise break `key=value` parsing.
+fn quote(value: &str) -> String {
+    let needs_quotes = value.is_empty()
+        || value
+            .chars()
+            .any(|c| c.is_whitespace() || c.is_control() || c == '=' || c == '"');
+    if needs_quotes {
+        format!("{:?}", value)
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+    use std::sync::{Arc, Mutex};
+    use tracing::{info, info_span};
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_logfmt_includes_span_fields() {
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .event_format(Logfmt)
+            .fmt_fields(LogfmtFields)
+            .with_writer(move || writer.clone())
+            .finish();
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = info_span!("ping", message = "hello world");
+            let _entered = span.enter();
+            info!(count = 2, "Received ping");
+        });
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert!(output.starts_with("ts="), "{}", output);
+        assert!(output.contains(" level=info target=daemon::infra::logging::logfmt::tests"));
+        assert!(
+            output.contains(r#" span=ping message="hello world""#),
+            "{}",
+            output
+        );
+        assert!(
+            output.ends_with(" msg=\"Received ping\" count=2\n"),
+            "{}",
+            output
+        );
+    }
+
+    #[test]
+    fn test_quote() {
+        assert_eq!(quote("plain"), "plain");
+        assert_eq!(quote(""), r#""""#);
+        assert_eq!(quote("a=b"), r#""a=b""#);
+        assert_eq!(quote("say \"hi\"\n"), r#""say \"hi\"\n""#);
+    }
+}
@@ -0,0 +1,79 @@
+mod broadcast;
+mod filter;
+mod logfmt;
+mod rotation;
+
+use tracing::warn;
+use tracing_subscriber::Layer;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Registry, fmt, reload};
+
+use crate::config::{DaemonConfig, LogFormat};
+use crate::error::Result;
+use crate::infra::telemetry::{self, Telemetry};
+
+pub use broadcast::LogBroadcast;
+pub use filter::LogFilter;
+pub use logfmt::{Logfmt, LogfmtFields};
+pub use rotation::{LogRotator, RotationPolicy};
+
+/// Handles to the installed subscriber that the server needs at runtime.
+#[derive(Clone)]
+pub struct Logging {
+    pub filter: LogFilter,
+    pub broadcast: LogBroadcast,
+    pub telemetry: Telemetry,
+}
+
+/// Checks the startup filter without installing anything.
+pub fn validate(config: &DaemonConfig) -> Result<()> {
+    initial_filter(config).map(|_| ())
+}
+
+/// Installs the global subscriber. `RUST_LOG` takes precedence over `config.log_level`.
+/// Must be called inside the tokio runtime, which span export relies on.
+pub fn init(config: &DaemonConfig) -> Result<Logging> {
+    let filter = initial_filter(config)?;
+    let (export, telemetry) = telemetry::layer(config)?;
+    let (filter_layer, handle) = reload::Layer::new(filter);
+    let broadcast = LogBroadcast::new(config.log_buffer_size);
+    let output = match config.log_format {
+        LogFormat::Text => fmt::layer().boxed(),
+        LogFormat::Json => fmt::layer()
+            .json()
+            .with_current_span(true)
+            .with_span_list(true)
+            .boxed(),
+        LogFormat::Logfmt => fmt::layer()
+            .event_format(Logfmt)
+            .fmt_fields(LogfmtFields)
+            .boxed(),
+    };
+
+    Registry::default()
+        .with(filter_layer)
+        .with(export)
+        .with(output)
+        .with(broadcast.clone())
+        .init();
+
+    if config.otlp_endpoint.is_some() && !telemetry::ENABLED {
+        warn!(
+            "otlp_endpoint is set but this build lacks the `otel` feature; spans are not exported"
+        );
+    }
+
+    Ok(Logging {
+        filter: LogFilter::new(handle),
+        broadcast,
+        telemetry,
+    })
+}
+
+fn initial_filter(config: &DaemonConfig) -> Result<EnvFilter> {
+    match EnvFilter::try_from_default_env() {
+        Ok(filter) => Ok(filter),
+        Err(_) => LogFilter::parse(&config.log_level),
+    }
+}
@@ -0,0 +1,209 @@
+use std::ffi::OsString;
+use std::fs::{self, File, OpenOptions};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use flate2::Compression;
+use flate2::write::GzEncoder;
+
+use crate::config::DaemonConfig;
+
+/// When and how `log_file` is rotated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RotationPolicy {
+    pub max_size: Option<u64>,
+    pub interval: Option<Duration>,
+    pub max_files: usize,
+    pub compress: bool,
+}
+
+impl RotationPolicy {
+    pub fn from_config(config: &DaemonConfig) -> Self {
+        Self {
+            max_size: Some(config.log_max_size_bytes).filter(|&size| size > 0),
+            interval: Some(Duration::from_secs(config.log_rotate_interval_secs))
+                .filter(|interval| !interval.is_zero()),
+            max_files: config.log_max_files,
+            compress: config.log_compress,
+        }
+    }
+}
+
+/// Renames `ffit.log` to `ffit.log.1` (shifting older files up and dropping
+/// those beyond `max_files`) and reopens a fresh `ffit.log`.
+pub struct LogRotator {
+    path: PathBuf,
+    policy: RotationPolicy,
+    opened_at: SystemTime,
+}
+
+impl LogRotator {
+    pub fn new(path: impl Into<PathBuf>, policy: RotationPolicy) -> Self {
+        let path = path.into();
+        // Time-based rotation counts from when the current file was started.
+        let opened_at = fs::metadata(&path)
+            .and_then(|metadata| metadata.created().or_else(|_| metadata.modified()))
+            .unwrap_or_else(|_| SystemTime::now());
+        Self {
+            path,
+            policy,
+            opened_at,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn policy(&self) -> &RotationPolicy {
+        &self.policy
+    }
+
+    pub fn should_rotate(&self, now: SystemTime) -> bool {
+        let too_big = self.policy.max_size.is_some_and(|max| {
+            fs::metadata(&self.path).is_ok_and(|metadata| metadata.len() >= max)
+        });
+        let too_old = self.policy.interval.is_some_and(|interval| {
+            now.duration_since(self.opened_at)
+                .is_ok_and(|age| age >= interval)
+        });
+        too_big || too_old
+    }
+
+    /// Rotates and returns the newly created log file for the caller to write to.
+    pub fn rotate(&mut self) -> io::Result<File> {
+        for index in (1..=self.policy.max_files).rev() {
+            for compressed in [false, true] {
+                let from = self.rotated_path(index, compressed);
+                if !from.exists() {
+                    continue;
+                }
+                if index == self.policy.max_files {
+                    fs::remove_file(&from)?;
+                } else {
+                    fs::rename(&from, self.rotated_path(index + 1, compressed))?;
+                }
+            }
+        }
+
+        let newest = self.rotated_path(1, false);
+        if self.path.exists() {
+            fs::rename(&self.path, &newest)?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        self.opened_at = SystemTime::now();
+
+        if newest.exists() {
+            if self.policy.max_files == 0 {
+                fs::remove_file(&newest)?;
+            } else if self.policy.compress {
+                compress(&newest, &self.rotated_path(1, true))?;
+            }
+        }
+
+        Ok(file)
+    }
+
+    fn rotated_path(&self, index: usize, compressed: bool) -> PathBuf {
+        let mut name = OsString::from(self.path.as_os_str());
+        name.push(format!(".{}", index));
+        if compressed {
+            name.push(".gz");
+        }
+        PathBuf::from(name)
+    }
+}
+
+fn compress(from: &Path, to: &Path) -> io::Result<()> {
+    let mut input = File::open(from)?;
+    let mut encoder = GzEncoder::new(File::create(to)?, Compression::default());
+    io::copy(&mut input, &mut encoder)?;
+    encoder.finish()?;
+    fs::remove_file(from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::read::GzDecoder;
+    use std::io::{Read, Write};
+
+    fn policy(max_files: usize, compress: bool) -> RotationPolicy {
+        RotationPolicy {
+            max_size: Some(16),
+            interval: None,
+            max_files,
+            compress,
+        }
+    }
+
+    #[test]
+    fn test_rotate_shifts_and_drops_old_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ffit.log");
+        let mut rotator = LogRotator::new(&path, policy(2, false));
+
+        for generation in ["first", "second", "third"] {
+            let mut file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .unwrap();
+            writeln!(file, "{} generation", generation).unwrap();
+            assert!(rotator.should_rotate(SystemTime::now()));
+            rotator.rotate().unwrap();
+        }
+
+        assert_eq!(fs::metadata(&path).unwrap().len(), 0);
+        assert!(!rotator.should_rotate(SystemTime::now()));
+        assert_eq!(
+            fs::read_to_string(dir.path().join("ffit.log.1")).unwrap(),
+            "third generation\n"
+        );
+        assert_eq!(
+            fs::read_to_string(dir.path().join("ffit.log.2")).unwrap(),
+            "second generation\n"
+        );
+        assert!(!dir.path().join("ffit.log.3").exists());
+    }
+
+    #[test]
+    fn test_rotate_compresses() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ffit.log");
+        fs::write(&path, "compressed line\n").unwrap();
+
+        let mut rotator = LogRotator::new(&path, policy(3, true));
+        rotator.rotate().unwrap();
+
+        assert!(!dir.path().join("ffit.log.1").exists());
+        let mut contents = String::new();
+        GzDecoder::new(File::open(dir.path().join("ffit.log.1.gz")).unwrap())
+            .read_to_string(&mut contents)
+            .unwrap();
+        assert_eq!(contents, "compressed line\n");
+    }
+
+    #[test]
+    fn test_should_rotate_by_age() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ffit.log");
+        fs::write(&path, "").unwrap();
+
+        let rotator = LogRotator::new(
+            &path,
+            RotationPolicy {
+                max_size: None,
+                interval: Some(Duration::from_secs(60)),
+                max_files: 1,
+                compress: false,
+            },
+        );
+        assert!(!rotator.should_rotate(SystemTime::now()));
+        assert!(rotator.should_rotate(SystemTime::now() + Duration::from_secs(61)));
+    }
+}
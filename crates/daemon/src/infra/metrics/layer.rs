@@ -0,0 +1,225 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Instant;
+
+use http::{Request, Response};
+use http_body::{Body, Frame, SizeHint};
+use tonic::Code;
+use tower::{Layer, Service};
+
+use super::Metrics;
+use crate::infra::grpc_status;
+
+/// Records per-method RPC metrics for every request passing through the router.
+#[derive(Clone)]
+pub struct MetricsLayer {
+    metrics: Metrics,
+}
+
+impl MetricsLayer {
+    pub fn new(metrics: Metrics) -> Self {
+        Self { metrics }
+    }
+}
+
+impl<S> Layer<S> for MetricsLayer {
+    type Service = MetricsService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        MetricsService {
+            inner,
+            metrics: self.metrics.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct MetricsService<S> {
+    inner: S,
+    metrics: Metrics,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for MetricsService<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = Response<MetricsBody<ResBody>>;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<ReqBody>) -> Self::Future {
+        let mut call = RpcCall::start(&self.metrics, request.uri().path());
+        let future = self.inner.call(request);
+
+        Box::pin(async move {
+            // If the inner service fails, `call` is dropped here and recorded without a status.
+            let response = future.await?;
+            // Trailers-only responses (most errors) carry the status in the headers.
+            call.code = call.code.or(grpc_status(response.headers()));
+
+            let (parts, body) = response.into_parts();
+            Ok(Response::from_parts(
+                parts,
+                MetricsBody {
+                    inner: body,
+                    call: Some(call),
+                },
+            ))
+        })
+    }
+}
+
+/// Response body that finishes the RPC's metrics once the body is done or dropped,
+/// so streaming RPCs are timed until their last message.
+pub struct MetricsBody<B> {
+    inner: B,
+    call: Option<RpcCall>,
+}
+
+impl<B> Body for MetricsBody<B>
+where
+    B: Body + Unpin,
+{
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let poll = Pin::new(&mut self.inner).poll_frame(cx);
+        match &poll {
+            Poll::Ready(Some(Ok(frame))) => {
+                if let (Some(trailers), Some(call)) = (frame.trailers_ref(), self.call.as_mut()) {
+                    call.code = call.code.or(grpc_status(trailers));
+                }
+            }
+            Poll::Ready(None) | Poll::Ready(Some(Err(_))) => {
+                self.call.take();
+            }
+            Poll::Pending => {}
+        }
+        poll
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+/// One RPC in flight; recorded as handled when dropped.
+struct RpcCall {
+    metrics: Metrics,
+    service: String,
+    method: String,
+    code: Option<Code>,
+    start: Instant,
+}
+
+impl RpcCall {
+    fn start(metrics: &Metrics, path: &str) -> Self {
+        let (service, method) = metrics.labels(path);
+        metrics.rpc_started(service, method);
+
+        Self {
+            metrics: metrics.clone(),
+            service: service.to_string(),
+            method: method.to_string(),
+            code: None,
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Drop for RpcCall {
+    fn drop(&mut self) {
+        // No status means the response was abandoned before it completed,
+        // typically because the client went away.
+        let code = self.code.unwrap_or(Code::Cancelled);
+        self.metrics
+            .rpc_finished(&self.service, &self.method, code, self.start.elapsed());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::Infallible;
+    use std::future::Ready;
+
+    /// Answers every request with a trailers-only `NOT_FOUND` response.
+    #[derive(Clone)]
+    struct NotFound;
+
+    impl Service<Request<()>> for NotFound {
+        type Response = Response<String>;
+        type Error = Infallible;
+        type Future = Ready<Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _request: Request<()>) -> Self::Future {
+            let response = Response::builder()
+                .header("grpc-status", "5")
+                .body(String::new())
+                .unwrap();
+            std::future::ready(Ok(response))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_records_status_from_headers() {
+        let metrics = Metrics::new(["daemon.v1.DaemonService/GetStatus".to_string()]).unwrap();
+        let mut service = MetricsLayer::new(metrics.clone()).layer(NotFound);
+
+        let request = Request::builder()
+            .uri("/daemon.v1.DaemonService/GetStatus")
+            .body(())
+            .unwrap();
+        let response = service.call(request).await.unwrap();
+
+        let in_flight = r#"grpc_server_in_flight_requests{grpc_method="GetStatus",grpc_service="daemon.v1.DaemonService"}"#;
+        assert!(
+            metrics
+                .render()
+                .unwrap()
+                .contains(&format!("{} 1", in_flight))
+        );
+
+        drop(response);
+        let text = metrics.render().unwrap();
+        assert!(text.contains(&format!("{} 0", in_flight)));
+        assert!(text.contains(
+            r#"grpc_server_handled_total{grpc_code="NotFound",grpc_method="GetStatus",grpc_service="daemon.v1.DaemonService"} 1"#
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_labels_unknown_methods_alike() {
+        let metrics = Metrics::new(["daemon.v1.DaemonService/GetStatus".to_string()]).unwrap();
+        let mut service = MetricsLayer::new(metrics.clone()).layer(NotFound);
+
+        for path in ["/made.up.Service/One", "/daemon.v1.DaemonService/Two", "/x"] {
+            let request = Request::builder().uri(path).body(()).unwrap();
+            drop(service.call(request).await.unwrap());
+        }
+
+        let text = metrics.render().unwrap();
+        assert!(text.contains(
+            r#"grpc_server_started_total{grpc_method="unknown",grpc_service="unknown"} 3"#
+        ));
+        assert!(!text.contains("made.up.Service"));
+    }
+}
@@ -0,0 +1,171 @@
+mod layer;
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use prometheus::{
+    Gauge, HistogramOpts, HistogramVec, IntCounterVec, IntGaugeVec, Opts, Registry, TextEncoder,
+};
+use tonic::Code;
+
+use crate::config::{GIT_HASH, VERSION};
+use crate::error::Result;
+use crate::infra::rpc_method;
+
+pub use layer::{MetricsLayer, MetricsService};
+
+/// RPC and process metrics, rendered in the Prometheus text format.
+#[derive(Clone)]
+pub struct Metrics {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    registry: Registry,
+    started: IntCounterVec,
+    handled: IntCounterVec,
+    handling_seconds: HistogramVec,
+    in_flight: IntGaugeVec,
+    uptime: Gauge,
+    start: Instant,
+    /// `service/method` of every RPC served; anything else is labelled `unknown`
+    /// so made-up paths cannot add series.
+    methods: HashSet<String>,
+}
+
+impl Metrics {
+    pub fn new(methods: impl IntoIterator<Item = String>) -> Result<Self> {
+        let registry = Registry::new();
+
+        let started = IntCounterVec::new(
+            Opts::new("grpc_server_started_total", "RPCs started on the server."),
+            &["grpc_service", "grpc_method"],
+        )?;
+        let handled = IntCounterVec::new(
+            Opts::new(
+                "grpc_server_handled_total",
+                "RPCs completed on the server, by status code.",
+            ),
+            &["grpc_service", "grpc_method", "grpc_code"],
+        )?;
+        let handling_seconds = HistogramVec::new(
+            HistogramOpts::new(
+                "grpc_server_handling_seconds",
+                "Time from receiving an RPC until its response finished.",
+            ),
+            &["grpc_service", "grpc_method"],
+        )?;
+        let in_flight = IntGaugeVec::new(
+            Opts::new(
+                "grpc_server_in_flight_requests",
+                "RPCs currently being handled.",
+            ),
+            &["grpc_service", "grpc_method"],
+        )?;
+        let uptime = Gauge::new("ffit_uptime_seconds", "Seconds since the daemon started.")?;
+        let build_info = IntGaugeVec::new(
+            Opts::new("ffit_build_info", "Daemon version, always 1."),
+            &["version", "git_hash"],
+        )?;
+        build_info.with_label_values(&[VERSION, GIT_HASH]).set(1);
+
+        registry.register(Box::new(started.clone()))?;
+        registry.register(Box::new(handled.clone()))?;
+        registry.register(Box::new(handling_seconds.clone()))?;
+        registry.register(Box::new(in_flight.clone()))?;
+        registry.register(Box::new(uptime.clone()))?;
+        registry.register(Box::new(build_info))?;
+        // RSS, open fds, CPU time and start time.
+        #[cfg(target_os = "linux")]
+        registry.register(Box::new(
+            prometheus::process_collector::ProcessCollector::for_self(),
+        ))?;
+
+        Ok(Self {
+            inner: Arc::new(Inner {
+                registry,
+                started,
+                handled,
+                handling_seconds,
+                in_flight,
+                uptime,
+                start: Instant::now(),
+                methods: methods.into_iter().collect(),
+            }),
+        })
+    }
+
+    pub fn render(&self) -> Result<String> {
+        self.inner
+            .uptime
+            .set(self.inner.start.elapsed().as_secs_f64());
+        let text = TextEncoder::new().encode_to_string(&self.inner.registry.gather())?;
+        Ok(text)
+    }
+
+    /// The `grpc_service` and `grpc_method` labels for a request path.
+    fn labels<'a>(&self, path: &'a str) -> (&'a str, &'a str) {
+        let path = path.trim_start_matches('/');
+        if self.inner.methods.contains(path) {
+            rpc_method(path)
+        } else {
+            ("unknown", "unknown")
+        }
+    }
+
+    fn rpc_started(&self, service: &str, method: &str) {
+        self.inner
+            .started
+            .with_label_values(&[service, method])
+            .inc();
+        self.inner
+            .in_flight
+            .with_label_values(&[service, method])
+            .inc();
+    }
+
+    fn rpc_finished(&self, service: &str, method: &str, code: Code, elapsed: Duration) {
+        self.inner
+            .in_flight
+            .with_label_values(&[service, method])
+            .dec();
+        self.inner
+            .handled
+            .with_label_values(&[service, method, &format!("{:?}", code)])
+            .inc();
+        self.inner
+            .handling_seconds
+            .with_label_values(&[service, method])
+            .observe(elapsed.as_secs_f64());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_includes_build_and_process_metrics() {
+        let metrics = Metrics::new([]).unwrap();
+        metrics.rpc_started("daemon.v1.PingService", "Ping");
+        metrics.rpc_finished(
+            "daemon.v1.PingService",
+            "Ping",
+            Code::Ok,
+            Duration::from_millis(5),
+        );
+
+        let text = metrics.render().unwrap();
+        assert!(text.contains("ffit_build_info{"));
+        assert!(text.contains("ffit_uptime_seconds "));
+        assert!(text.contains(
+            r#"grpc_server_handled_total{grpc_code="Ok",grpc_method="Ping",grpc_service="daemon.v1.PingService"} 1"#
+        ));
+        assert!(text.contains(
+            r#"grpc_server_in_flight_requests{grpc_method="Ping",grpc_service="daemon.v1.PingService"} 0"#
+        ));
+        #[cfg(target_os = "linux")]
+        assert!(text.contains("process_resident_memory_bytes "));
+    }
+}
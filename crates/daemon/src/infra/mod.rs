@@ -1 +1,23 @@
+pub mod audit;
+pub mod auth;
+pub mod inflight;
+pub mod job_store;
+pub mod logging;
+pub mod metrics;
+pub mod telemetry;
 
+/// Splits a gRPC request path (`/daemon.v1.PingService/Ping`) into service and method.
+pub(crate) fn rpc_method(path: &str) -> (&str, &str) {
+    path.trim_start_matches('/')
+        .split_once('/')
+        .unwrap_or(("unknown", "unknown"))
+}
+
+/// Status code of a trailers-only response (in its headers) or of the trailers.
+pub(crate) fn grpc_status(headers: &http::HeaderMap) -> Option<tonic::Code> {
+    headers
+        .get("grpc-status")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<i32>().ok())
+        .map(tonic::Code::from)
+}
@@ -0,0 +1,67 @@
+use std::task::{Context, Poll};
+
+use http::Request;
+use tower::{Layer, Service};
+use tracing::Span;
+use tracing::instrument::{Instrument, Instrumented};
+
+/// Runs each RPC inside a span continuing the caller's `traceparent`,
+/// so handler spans join the trace started by `ffit`.
+/// Without the `otel` feature this is a pass-through.
+#[derive(Clone, Default)]
+pub struct TraceContextLayer;
+
+impl<S> Layer<S> for TraceContextLayer {
+    type Service = TraceContextService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        TraceContextService { inner }
+    }
+}
+
+#[derive(Clone)]
+pub struct TraceContextService<S> {
+    inner: S,
+}
+
+impl<S, ReqBody> Service<Request<ReqBody>> for TraceContextService<S>
+where
+    S: Service<Request<ReqBody>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Instrumented<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<ReqBody>) -> Self::Future {
+        let span = request_span(&request);
+        let future = {
+            let _entered = span.enter();
+            self.inner.call(request)
+        };
+        future.instrument(span)
+    }
+}
+
+#[cfg(feature = "otel")]
+fn request_span<B>(request: &Request<B>) -> Span {
+    let (service, method) = crate::infra::rpc_method(request.uri().path());
+    let span = tracing::info_span!(
+        "rpc",
+        otel.name = %format!("{}/{}", service, method),
+        otel.kind = "server",
+        rpc.system = "grpc",
+        rpc.service = %service,
+        rpc.method = %method,
+    );
+    super::otlp::set_remote_parent(&span, request.headers());
+    span
+}
+
+#[cfg(not(feature = "otel"))]
+fn request_span<B>(_request: &Request<B>) -> Span {
+    Span::none()
+}
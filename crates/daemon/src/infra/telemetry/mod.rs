@@ -0,0 +1,193 @@
+mod layer;
+#[cfg(feature = "otel")]
+mod otlp;
+
+use crate::config::DaemonConfig;
+use crate::error::Result;
+
+pub use layer::{TraceContextLayer, TraceContextService};
+#[cfg(feature = "otel")]
+pub use otlp::tracer_provider;
+
+/// Whether this build can export traces (the `otel` cargo feature).
+pub const ENABLED: bool = cfg!(feature = "otel");
+
+/// Owns the span export pipeline, if any.
+#[derive(Clone, Default)]
+pub struct Telemetry {
+    #[cfg(feature = "otel")]
+    provider: Option<opentelemetry_sdk::trace::SdkTracerProvider>,
+}
+
+impl Telemetry {
+    /// Flushes buffered spans and stops the exporter.
+    pub async fn shutdown(self) {
+        #[cfg(feature = "otel")]
+        if let Some(provider) = self.provider {
+            // Shutting down blocks on the exporter thread, whose gRPC calls need the runtime.
+            match tokio::task::spawn_blocking(move || provider.shutdown()).await {
+                Ok(Ok(())) => tracing::debug!("Trace exporter stopped"),
+                Ok(Err(e)) => tracing::warn!(error = %e, "Failed to flush traces"),
+                Err(e) => tracing::warn!(error = %e, "Trace exporter shutdown panicked"),
+            }
+        }
+    }
+}
+
+/// Builds the layer exporting spans to `otlp_endpoint`; `None` when unset.
+/// Must be called inside the tokio runtime.
+#[cfg(feature = "otel")]
+pub fn layer<S>(
+    config: &DaemonConfig,
+) -> Result<(
+    Option<tracing_opentelemetry::OpenTelemetryLayer<S, opentelemetry_sdk::trace::SdkTracer>>,
+    Telemetry,
+)>
+where
+    S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+{
+    use opentelemetry::trace::TracerProvider;
+
+    let Some(endpoint) = &config.otlp_endpoint else {
+        return Ok((None, Telemetry::default()));
+    };
+
+    let provider = tracer_provider(endpoint, config.trace_sample_ratio)?;
+    let tracer = provider.tracer(crate::config::DAEMON_BINARY);
+    let layer = tracing_opentelemetry::layer().with_tracer(tracer);
+    Ok((
+        Some(layer),
+        Telemetry {
+            provider: Some(provider),
+        },
+    ))
+}
+
+#[cfg(not(feature = "otel"))]
+pub fn layer(
+    _config: &DaemonConfig,
+) -> Result<(Option<tracing_subscriber::layer::Identity>, Telemetry)> {
+    Ok((None, Telemetry::default()))
+}
+
+#[cfg(all(test, feature = "otel"))]
+mod tests {
+    use super::*;
+    use std::convert::Infallible;
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::{Arc, Mutex};
+    use std::task::{Context, Poll};
+
+    use opentelemetry::trace::TracerProvider;
+    use opentelemetry_proto::tonic::collector::trace::v1::trace_service_server::{
+        TraceService, TraceServiceServer,
+    };
+    use opentelemetry_proto::tonic::collector::trace::v1::{
+        ExportTraceServiceRequest, ExportTraceServiceResponse,
+    };
+    use opentelemetry_proto::tonic::trace::v1::Span;
+    use tokio_stream::wrappers::TcpListenerStream;
+    use tower::{Layer, Service};
+    use tracing_subscriber::layer::SubscriberExt;
+
+    /// Collector stand-in that keeps every exported span.
+    #[derive(Clone, Default)]
+    struct Collector(Arc<Mutex<Vec<Span>>>);
+
+    #[tonic::async_trait]
+    impl TraceService for Collector {
+        async fn export(
+            &self,
+            request: tonic::Request<ExportTraceServiceRequest>,
+        ) -> std::result::Result<tonic::Response<ExportTraceServiceResponse>, tonic::Status>
+        {
+            let spans = request
+                .into_inner()
+                .resource_spans
+                .into_iter()
+                .flat_map(|resource| resource.scope_spans)
+                .flat_map(|scope| scope.spans);
+            self.0.lock().unwrap().extend(spans);
+            Ok(tonic::Response::new(ExportTraceServiceResponse::default()))
+        }
+    }
+
+    /// Stands in for a gRPC handler with its own `#[instrument]` span.
+    struct Handler;
+
+    impl Service<http::Request<()>> for Handler {
+        type Response = ();
+        type Error = Infallible;
+        type Future = Pin<Box<dyn Future<Output = std::result::Result<(), Infallible>> + Send>>;
+
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _request: http::Request<()>) -> Self::Future {
+            Box::pin(async {
+                tracing::info_span!("handler").in_scope(|| tracing::info!("handled"));
+                Ok(())
+            })
+        }
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_exports_spans_continuing_traceparent() {
+        let collector = Collector::default();
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(
+            tonic::transport::Server::builder()
+                .add_service(TraceServiceServer::new(collector.clone()))
+                .serve_with_incoming(TcpListenerStream::new(listener)),
+        );
+
+        // A ratio of 0 drops new traces, so anything exported was sampled by the caller.
+        let provider = tracer_provider(&format!("http://{}", addr), 0.0).unwrap();
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let trace_id = "4bf92f3577b34da6a3ce929d0e0e4736";
+        let parent_id = "00f067aa0ba902b7";
+        let request = http::Request::builder()
+            .uri("/daemon.v1.PingService/Ping")
+            .header("traceparent", format!("00-{}-{}-01", trace_id, parent_id))
+            .body(())
+            .unwrap();
+        TraceContextLayer
+            .layer(Handler)
+            .call(request)
+            .await
+            .unwrap();
+
+        tokio::task::spawn_blocking(move || provider.shutdown())
+            .await
+            .unwrap()
+            .unwrap();
+
+        let spans = collector.0.lock().unwrap().clone();
+        let rpc = spans
+            .iter()
+            .find(|span| span.name == "daemon.v1.PingService/Ping")
+            .expect("rpc span exported");
+        assert_eq!(hex(&rpc.trace_id), trace_id);
+        assert_eq!(hex(&rpc.parent_span_id), parent_id);
+
+        let handler = spans
+            .iter()
+            .find(|span| span.name == "handler")
+            .expect("handler span exported");
+        assert_eq!(handler.trace_id, rpc.trace_id);
+        assert_eq!(handler.parent_span_id, rpc.span_id);
+    }
+}
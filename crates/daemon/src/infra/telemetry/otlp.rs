@@ -0,0 +1,53 @@
+use opentelemetry::KeyValue;
+use opentelemetry::propagation::{Extractor, TextMapPropagator};
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::Resource;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::{Sampler, SdkTracerProvider};
+use tracing::Span;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+use crate::config::{DAEMON_BINARY, VERSION};
+use crate::error::{DaemonError, Result};
+
+/// Batches spans and sends them to an OTLP/gRPC collector at `endpoint`.
+/// Remote parents from `traceparent` are always honoured; new traces are
+/// sampled at `sample_ratio`.
+pub fn tracer_provider(endpoint: &str, sample_ratio: f64) -> Result<SdkTracerProvider> {
+    let exporter = SpanExporter::builder()
+        .with_tonic()
+        .with_endpoint(endpoint)
+        .build()
+        .map_err(|e| DaemonError::TelemetryError(format!("{}: {}", endpoint, e)))?;
+
+    let resource = Resource::builder()
+        .with_service_name(DAEMON_BINARY)
+        .with_attribute(KeyValue::new("service.version", VERSION))
+        .build();
+
+    Ok(SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+            sample_ratio,
+        ))))
+        .with_resource(resource)
+        .build())
+}
+
+/// Makes `span` a child of the W3C trace context in `headers`, if there is one.
+pub(super) fn set_remote_parent(span: &Span, headers: &http::HeaderMap) {
+    let parent = TraceContextPropagator::new().extract(&HeaderExtractor(headers));
+    let _ = span.set_parent(parent);
+}
+
+struct HeaderExtractor<'a>(&'a http::HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|key| key.as_str()).collect()
+    }
+}
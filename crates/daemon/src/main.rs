@@ -2,58 +2,146 @@ use clap::Parser;
 use daemon::cli::Args;
 use daemon::config::DaemonConfig;
 use daemon::error::Result;
+use daemon::infra::auth::Authorizer;
+#[cfg(unix)]
+use daemon::infra::auth::PeerPolicy;
+use daemon::infra::logging::{self, LogRotator, RotationPolicy};
 use daemon::server::LockGuard;
-use daemon::server::process;
+#[cfg(unix)]
+use daemon::server::SocketPermissions;
+#[cfg(unix)]
+use daemon::server::systemd;
+#[cfg(unix)]
+use daemon::server::upgrade::Handoff;
+use daemon::server::{process, tls};
 use daemon::{Server, ServerConfig};
 use tracing::{debug, info, warn};
-use tracing_subscriber::EnvFilter;
 
 fn main() -> Result<()> {
-    tracing_subscriber::fmt()
-        .with_env_filter(
-            EnvFilter::from_default_env()
-                .add_directive("daemon=info".parse().expect("static directive is valid")),
-        )
-        .init();
-
     let args = Args::parse();
 
-    debug!("Loading configuration");
-    let config = DaemonConfig::load()?.with_foreground(args.foreground);
-    debug!(
-        tcp_addr = %config.tcp_addr,
-        socket = %config.socket.display(),
-        pid_file = %config.pid_file.display(),
-        foreground = config.foreground,
-        "Configuration loaded"
-    );
+    let overrides = args.overrides();
+    let config = DaemonConfig::load()?.with_overrides(overrides);
+    // Reject a bad filter, certificate, listen address, socket owner or authz policy while
+    // errors still reach the terminal that started us.
+    logging::validate(&config)?;
+    let mut server_config = server_config(&config)?;
+
+    // Under systemd the service manager supervises us directly: forking would
+    // hide the main process from Type=notify and lose passed sockets.
+    #[cfg(unix)]
+    let managed = systemd::is_managed();
+    #[cfg(not(unix))]
+    let managed = false;
+
+    // Started by `ffit upgrade`: the previous daemon already detached from the
+    // terminal and passes us its listeners and its lock.
+    #[cfg(unix)]
+    let mut handoff = Handoff::from_env()?;
+    #[cfg(unix)]
+    let inherited_lock = handoff.lock.take();
+    #[cfg(unix)]
+    let upgrading = inherited_lock.is_some();
+    #[cfg(not(unix))]
+    let upgrading = false;
+    #[cfg(unix)]
+    {
+        server_config = server_config.with_handoff(handoff);
+    }
 
     // Daemonize first, then acquire lock in the child process
     // (flock is not inherited across fork, so we must acquire it after daemonizing)
-    if !config.foreground {
-        if process::is_daemon_supported() {
-            info!("Daemonizing process");
-            process::daemonize(&config)?;
-        } else {
+    let daemonize = !config.foreground && !managed && !upgrading && process::is_daemon_supported();
+    if daemonize {
+        process::daemonize(&config)?;
+    }
+    // Output goes to the log file only when daemonized, or when upgraded from
+    // a daemon that was; in the foreground or under systemd it goes to the
+    // terminal or the journal, which are not ours to rotate.
+    if daemonize || (upgrading && !managed && !config.foreground) {
+        let policy = RotationPolicy::from_config(&config);
+        server_config = server_config.with_log_rotation(LogRotator::new(&config.log_file, policy));
+    }
+
+    // The subscriber is installed after the fork and inside the runtime:
+    // the span exporter's background thread would not survive a fork,
+    // and its gRPC channel needs tokio.
+    tokio::runtime::Runtime::new()?.block_on(async {
+        let logging = logging::init(&config)?;
+        debug!(
+            log_level = %config.log_level,
+            log_format = %config.log_format,
+            tcp_addr = %config.tcp_addr,
+            socket = %config.socket.display(),
+            pid_file = %config.pid_file.display(),
+            foreground = config.foreground,
+            "Configuration loaded"
+        );
+        if daemonize {
+            info!("Daemonized process");
+        } else if upgrading {
+            info!("Started by an upgrade, taking over from the previous daemon");
+        } else if managed {
+            info!("Running under systemd, staying in the foreground");
+        } else if !config.foreground {
             warn!("Daemon mode not supported on this platform, running in foreground");
         }
-    }
 
+        #[cfg(unix)]
+        let lock = match inherited_lock {
+            Some(fd) => LockGuard::inherit(fd)?,
+            None => acquire_lock(&config)?,
+        };
+        #[cfg(not(unix))]
+        let lock = acquire_lock(&config)?;
+        // An upgraded daemon writes it once it serves.
+        if managed && !upgrading {
+            process::write_pid_file(&config)?;
+        }
+
+        info!("Starting server");
+        let telemetry = logging.telemetry.clone();
+        let server_config = server_config
+            .with_overrides(overrides)
+            .with_logging(logging)
+            .with_lock(lock);
+        let result = Server::new(server_config).run().await;
+        telemetry.shutdown().await;
+        result
+    })
+}
+
+fn acquire_lock(config: &DaemonConfig) -> Result<LockGuard> {
     debug!(lock_file = %config.lock_file.display(), "Acquiring lock");
-    let _lock_guard = LockGuard::try_acquire(&config.lock_file)?;
+    let lock = LockGuard::try_acquire(&config.lock_file)?;
     debug!("Lock acquired");
-
-    info!("Starting server");
-    tokio::runtime::Runtime::new()?.block_on(run_server(config))
+    Ok(lock)
 }
 
-async fn run_server(config: DaemonConfig) -> Result<()> {
-    let mut server_config = ServerConfig::default().with_tcp(config.tcp_addr.parse()?);
+/// Listeners and their access settings; logging is attached once the subscriber is installed.
+fn server_config(config: &DaemonConfig) -> Result<ServerConfig> {
+    let tls = tls::load(config)?;
+    let mut server_config = ServerConfig::default()
+        .with_daemon_config(config.clone())
+        .with_authorizer(Authorizer::from_config(&config.authz)?);
+    // An empty address turns the TCP listener off.
+    if !config.tcp_addr.is_empty() {
+        let tcp_addr = config.tcp_addr.parse()?;
+        tls::ensure_secure(tcp_addr, tls.is_some())?;
+        server_config = server_config.with_tcp(tcp_addr);
+    }
+    if let Some(tls) = tls {
+        server_config = server_config.with_tls(tls);
+    }
 
     #[cfg(unix)]
     {
-        server_config = server_config.with_uds(&config.socket);
+        server_config = server_config
+            .with_uds(&config.socket)
+            .with_socket_permissions(SocketPermissions::from_config(config)?)
+            .with_peer_policy(PeerPolicy::from_config(config)?)
+            .with_activated_sockets(systemd::listen_fds()?);
     }
 
-    Server::new(server_config).run().await
+    Ok(server_config)
 }
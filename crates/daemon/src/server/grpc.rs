@@ -1,125 +1,932 @@
-use std::sync::Arc;
+#[cfg(unix)]
+use std::os::fd::{AsFd, OwnedFd};
+#[cfg(unix)]
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, TryLockError};
+use std::time::{Duration, SystemTime};
 
+use prost::Message;
+use prost_types::FileDescriptorSet;
+use tokio::net::TcpListener;
+use tokio::sync::{mpsc, oneshot, watch};
+use tokio::task::JoinHandle;
+use tonic::service::{InterceptorLayer, Routes};
+use tonic::transport::ServerTlsConfig;
+use tonic::transport::server::Router;
+use tonic_health::pb::health_server::{Health, HealthServer};
 use tonic_reflection::server::v1::ServerReflectionServer;
-use tracing::{error, info};
+use tower::layer::util::{Identity, Stack};
+use tracing::{debug, error, info, warn};
 
+use crate::config::{ConfigOverrides, DaemonConfig};
 use crate::di::Container;
+#[cfg(unix)]
+use crate::domain::Upgraded;
+use crate::domain::{ShutdownPhase, ShutdownRequest};
 use crate::error::{DaemonError, Result};
 use crate::generated::FILE_DESCRIPTOR_SET;
+use crate::generated::daemon_service_server::DaemonServiceServer;
+use crate::generated::job_service_server::JobServiceServer;
 use crate::generated::ping_service_server::PingServiceServer;
+use crate::infra::audit::{AuditLayer, AuditLog};
+#[cfg(unix)]
+use crate::infra::auth::PeerPolicy;
+use crate::infra::auth::{AuthInterceptor, Authenticator, Authorizer, AuthzLayer};
+use crate::infra::inflight::{InFlight, InFlightLayer};
+use crate::infra::job_store::JobStore;
+use crate::infra::logging::{LogRotator, Logging};
+use crate::infra::metrics::{Metrics, MetricsLayer};
+use crate::infra::telemetry::TraceContextLayer;
+#[cfg(unix)]
+use crate::server::listener::SocketPermissions;
 use crate::server::listener::{ListenAddr, ListenerStream};
-use crate::server::shutdown::{ShutdownSignal, wait_for_signal};
+use crate::server::lock::LockGuard;
+use crate::server::shutdown::{SignalEvent, SignalListener};
+#[cfg(unix)]
+use crate::server::systemd::{self, ActivatedSocket, Notifier};
+#[cfg(unix)]
+use crate::server::upgrade::{self, Handoff, SocketKind};
+use crate::server::{health, metrics, process, tls};
+use crate::usecase::{JobsUseCase, ShutdownSignal, UpgradeCommand};
 
 #[derive(Default)]
 pub struct ServerConfig {
+    pub daemon: DaemonConfig,
+    pub overrides: ConfigOverrides,
+    pub logging: Option<Logging>,
+    pub log_rotator: Option<LogRotator>,
     pub tcp: Option<ListenAddr>,
+    pub tls: Option<ServerTlsConfig>,
+    pub authorizer: Authorizer,
     #[cfg(unix)]
     pub uds: Option<ListenAddr>,
+    #[cfg(unix)]
+    pub socket_permissions: SocketPermissions,
+    #[cfg(unix)]
+    pub peer_policy: Option<PeerPolicy>,
+    #[cfg(unix)]
+    pub activated_sockets: Vec<ActivatedSocket>,
+    pub lock: Option<LockGuard>,
+    #[cfg(unix)]
+    pub handoff: Handoff,
 }
 
 impl ServerConfig {
+    pub fn with_daemon_config(mut self, config: DaemonConfig) -> Self {
+        self.daemon = config;
+        self
+    }
+
+    /// Command-line settings that reloads keep applying over the config sources.
+    pub fn with_overrides(mut self, overrides: ConfigOverrides) -> Self {
+        self.overrides = overrides;
+        self
+    }
+
+    pub fn with_logging(mut self, logging: Logging) -> Self {
+        self.logging = Some(logging);
+        self
+    }
+
+    /// Rotates the log file that stdout and stderr were redirected to when daemonizing.
+    pub fn with_log_rotation(mut self, rotator: LogRotator) -> Self {
+        self.log_rotator = Some(rotator);
+        self
+    }
+
     pub fn with_tcp(mut self, addr: std::net::SocketAddr) -> Self {
         self.tcp = Some(ListenAddr::tcp(addr));
         self
     }
 
+    /// Serves the TCP listener over TLS; the Unix socket stays plaintext.
+    pub fn with_tls(mut self, tls: ServerTlsConfig) -> Self {
+        self.tls = Some(tls);
+        self
+    }
+
+    /// Applies `[authz]` roles to every listener; reloads update it in place.
+    pub fn with_authorizer(mut self, authorizer: Authorizer) -> Self {
+        self.authorizer = authorizer;
+        self
+    }
+
     #[cfg(unix)]
     pub fn with_uds(mut self, path: impl AsRef<std::path::Path>) -> Self {
         self.uds = Some(ListenAddr::unix(path));
         self
     }
+
+    #[cfg(unix)]
+    pub fn with_socket_permissions(mut self, permissions: SocketPermissions) -> Self {
+        self.socket_permissions = permissions;
+        self
+    }
+
+    /// Restricts the Unix socket to the peers `policy` allows.
+    #[cfg(unix)]
+    pub fn with_peer_policy(mut self, policy: PeerPolicy) -> Self {
+        self.peer_policy = Some(policy);
+        self
+    }
+
+    /// Serves sockets passed by systemd in addition to the configured listeners;
+    /// a configured address that systemd already opened is not bound again.
+    #[cfg(unix)]
+    pub fn with_activated_sockets(mut self, sockets: Vec<ActivatedSocket>) -> Self {
+        self.activated_sockets = sockets;
+        self
+    }
+
+    /// Holds the daemon's lock while serving, so an upgrade can pass it on.
+    pub fn with_lock(mut self, lock: LockGuard) -> Self {
+        self.lock = Some(lock);
+        self
+    }
+
+    /// Serves the listeners passed by the daemon being upgraded instead of binding
+    /// them, and tells it once this process serves.
+    #[cfg(unix)]
+    pub fn with_handoff(mut self, handoff: Handoff) -> Self {
+        self.handoff = handoff;
+        self
+    }
+
+    pub fn listen_addrs(&self) -> Vec<&ListenAddr> {
+        let mut addrs: Vec<&ListenAddr> = self.tcp.iter().collect();
+        #[cfg(unix)]
+        addrs.extend(self.uds.iter());
+        addrs
+    }
 }
 
+/// How often the log file is checked against the rotation policy.
+const LOG_ROTATION_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+/// How long cancelled requests get to send their final status once draining times out.
+const CANCEL_GRACE: Duration = Duration::from_secs(1);
+
 pub struct Server {
     config: ServerConfig,
 }
 
+/// Everything a listener needs to serve the daemon, shared by reload rebinds.
+struct ServeContext {
+    routes: Routes,
+    metrics: Metrics,
+    tls: Option<ServerTlsConfig>,
+    auth: Authenticator,
+    authz: Authorizer,
+    audit: AuditLog,
+    in_flight: InFlight,
+    #[cfg(unix)]
+    socket_permissions: SocketPermissions,
+    #[cfg(unix)]
+    peer_policy: Option<PeerPolicy>,
+    shutdown: ShutdownSignal,
+}
+
+/// A bound listener serving the router until shutdown or until it is replaced on reload.
+struct RunningListener {
+    addr: ListenAddr,
+    /// Opened by systemd, which owns the address: never rebound or removed by us.
+    inherited: bool,
+    /// Shares the listening socket, to pass it on in an upgrade.
+    #[cfg(unix)]
+    fd: OwnedFd,
+    stop: oneshot::Sender<()>,
+    handle: JoinHandle<()>,
+}
+
 impl Server {
     pub fn new(config: ServerConfig) -> Self {
         Self { config }
     }
 
-    pub async fn run(self) -> Result<()> {
-        let container = Arc::new(Container::new());
+    pub async fn run(mut self) -> Result<()> {
         let shutdown = ShutdownSignal::new();
+        let mut shutdown_requested = shutdown.subscribe();
+        let (config_tx, mut config_rx) = watch::channel(self.config.daemon.clone());
+        let (listeners_tx, listeners_rx) = watch::channel(Vec::new());
+        let (upgrade_tx, mut upgrades) = mpsc::channel(1);
+        let container = Arc::new(Container::new(
+            config_tx,
+            self.config.overrides,
+            listeners_rx,
+            shutdown.clone(),
+            self.config.logging.clone(),
+            upgrade_tx,
+            JobStore::open(&self.config.daemon.job_store_file)?,
+        )?);
+        // Resolved now: once the binary is replaced on disk, the running one reads as deleted.
+        #[cfg(unix)]
+        let executable = std::env::current_exe().ok();
 
-        let reflection = build_reflection()?;
+        let (health_reporter, health_service) = tonic_health::server::health_reporter();
+        let routes = build_routes(&container, build_reflection()?, health_service);
+        let serve = ServeContext {
+            routes,
+            metrics: Metrics::new(served_methods()?)?,
+            tls: self.config.tls.clone(),
+            auth: Authenticator::from_config(&self.config.daemon),
+            authz: self.config.authorizer.clone(),
+            audit: AuditLog::open(&self.config.daemon.audit_file)?,
+            in_flight: InFlight::new(),
+            #[cfg(unix)]
+            socket_permissions: self.config.socket_permissions.clone(),
+            #[cfg(unix)]
+            peer_policy: self.config.peer_policy.clone(),
+            shutdown: shutdown.clone(),
+        };
 
-        let mut handles = Vec::new();
+        let mut listeners = Vec::new();
+        let mut handed_metrics = None;
+        #[cfg(unix)]
+        let mut handed = Vec::new();
+        #[cfg(unix)]
+        let ready = {
+            let mut handoff = std::mem::take(&mut self.config.handoff);
+            for socket in handoff.sockets {
+                match socket.kind {
+                    SocketKind::Grpc => handed.push(ListenerStream::from_fd(socket.fd)?),
+                    // Still systemd's: served like the sockets it passed us directly.
+                    SocketKind::Systemd => self.config.activated_sockets.push(ActivatedSocket {
+                        name: None,
+                        fd: socket.fd,
+                    }),
+                    SocketKind::Metrics => {
+                        handed_metrics = Some(std::net::TcpListener::from(socket.fd));
+                    }
+                }
+            }
+            handoff.ready.take()
+        };
+        #[cfg(unix)]
+        let inherited = std::mem::take(&mut self.config.activated_sockets)
+            .into_iter()
+            .map(|socket| {
+                let (addr, stream) = ListenerStream::from_fd(socket.fd)?;
+                Ok((addr, socket.name, stream))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        for addr in self.config.listen_addrs() {
+            #[cfg(unix)]
+            if inherited.iter().any(|(inherited, ..)| inherited == addr) {
+                debug!(address = %addr, "Using the socket passed by systemd");
+                continue;
+            }
+            #[cfg(unix)]
+            if let Some(index) = handed.iter().position(|(handed, _)| handed == addr) {
+                let (addr, stream) = handed.swap_remove(index);
+                debug!(address = %addr, "Using the socket handed over by the previous daemon");
+                listeners.push(serve_listener(addr, stream, false, &serve)?);
+                continue;
+            }
+            listeners.push(spawn_listener(addr.clone(), &serve).await?);
+        }
+        #[cfg(unix)]
+        for (addr, name, stream) in inherited {
+            info!(address = %addr, name = ?name, "Serving socket passed by systemd");
+            listeners.push(serve_listener(addr, stream, true, &serve)?);
+        }
+        #[cfg(unix)]
+        for (addr, _) in handed {
+            warn!(address = %addr, "Closing handed-over listener that is no longer configured");
+            addr.cleanup();
+        }
 
-        if let Some(addr) = &self.config.tcp {
-            let stream = addr.bind().await?;
-            let container = Arc::clone(&container);
-            let reflection = reflection.clone();
-            let shutdown = shutdown.clone();
-            let addr_display = addr.to_string();
+        if listeners.is_empty() {
+            return Err(DaemonError::NoListenersConfigured);
+        }
 
-            info!(address = %addr_display, "Listening on TCP");
+        #[cfg(unix)]
+        let mut metrics_fd = None;
+        let metrics_listener = match &self.config.daemon.metrics_addr {
+            Some(addr) => {
+                let addr = addr.parse()?;
+                let listener = match handed_metrics.filter(|handed: &std::net::TcpListener| {
+                    handed.local_addr().is_ok_and(|handed| handed == addr)
+                }) {
+                    Some(handed) => {
+                        handed.set_nonblocking(true)?;
+                        TcpListener::from_std(handed)?
+                    }
+                    None => TcpListener::bind(addr).await?,
+                };
+                #[cfg(unix)]
+                {
+                    metrics_fd = Some(listener.as_fd().try_clone_to_owned()?);
+                }
+                Some(metrics::spawn_listener(
+                    listener,
+                    serve.metrics.clone(),
+                    &shutdown,
+                )?)
+            }
+            None => None,
+        };
 
-            let handle = tokio::spawn(async move {
-                if let ListenerStream::Tcp(listener) = stream {
-                    let router = build_router(&container, reflection);
-                    let result = router
-                        .serve_with_incoming_shutdown(listener, shutdown.wait())
-                        .await;
+        publish_listeners(&listeners_tx, &listeners);
+        health::report(health_reporter, &shutdown).await;
+        #[cfg(unix)]
+        let notifier = Notifier::from_env();
+        #[cfg(unix)]
+        let watchdog = systemd::watchdog_interval().map(|interval| {
+            info!(interval = ?interval, "Pinging the systemd watchdog");
+            notifier.spawn_watchdog(interval)
+        });
+        #[cfg(unix)]
+        notifier.ready(&serving_status(&listeners));
+        #[cfg(unix)]
+        if let Some(ready) = ready {
+            // Only now, so a new daemon that fails to start leaves the PID file alone.
+            process::write_pid_file(&self.config.daemon)?;
+            ready.send();
+            info!("Took over from the previous daemon");
+        }
 
-                    if let Err(e) = result {
-                        error!(error = %e, "TCP server error");
+        let mut signals = SignalListener::new();
+        let log_rotator = self
+            .config
+            .log_rotator
+            .take()
+            .map(|r| Arc::new(Mutex::new(r)));
+        let mut rotation_check = tokio::time::interval(LOG_ROTATION_CHECK_INTERVAL);
+        let mut retired = Vec::new();
+        let mut handed_over = false;
+        let mut pending_upgrade: Option<PendingUpgrade> = None;
+        loop {
+            tokio::select! {
+                event = signals.recv() => match event {
+                    SignalEvent::Shutdown => break,
+                    SignalEvent::Reload => {
+                        if let Err(e) = container.reload_use_case.reload() {
+                            error!(error = %e, "Failed to reload configuration");
+                        }
+                    }
+                    SignalEvent::RotateLogs => match &log_rotator {
+                        Some(rotator) => spawn_rotation(rotator, true),
+                        None => info!("Log rotation is only available in daemon mode"),
+                    },
+                },
+                _ = rotation_check.tick() => {
+                    if let Some(rotator) = &log_rotator {
+                        spawn_rotation(rotator, false);
+                    }
+                }
+                _ = shutdown_requested.recv() => break,
+                Some(command) = upgrades.recv() => {
+                    if pending_upgrade.is_some() {
+                        let _ = command.reply.send(Err(DaemonError::UpgradeError(
+                            "an upgrade is already in progress".to_string(),
+                        )));
+                        continue;
+                    }
+                    #[cfg(unix)]
+                    {
+                        pending_upgrade = Some(PendingUpgrade::start(
+                            command,
+                            executable.clone(),
+                            container.jobs_use_case.clone(),
+                            &listeners,
+                            metrics_fd.as_ref(),
+                            self.config.lock.as_ref(),
+                            &notifier,
+                        ));
                     }
+                    #[cfg(not(unix))]
+                    let _ = command.reply.send(Err(DaemonError::UpgradeError(
+                        "not supported on this platform".to_string(),
+                    )));
                 }
+                (command, result) = finish_upgrade(&mut pending_upgrade), if pending_upgrade.is_some() => {
+                    if take_over(command, result, &shutdown) {
+                        handed_over = true;
+                        break;
+                    }
+                }
+                // Held back while the new daemon starts on the current listeners.
+                Ok(()) = config_rx.changed(), if pending_upgrade.is_none() => {
+                    let config = config_rx.borrow_and_update().clone();
+                    if let Err(e) = serve.authz.update(&config.authz) {
+                        error!(error = %e, "Keeping previous authorization policy");
+                    }
+                    retired.extend(
+                        reconcile(&mut listeners, &config, &serve).await,
+                    );
+                    publish_listeners(&listeners_tx, &listeners);
+                    #[cfg(unix)]
+                    notifier.status(&serving_status(&listeners));
+                }
+            }
+        }
+
+        // Shutdown begins here, whatever asked for it: from now on signals only
+        // escalate it; see `SignalListener::escalate`.
+        tokio::spawn(signals.escalate(shutdown.clone()));
+        upgrades.close();
+        // A new daemon may be about to serve these addresses: find out before
+        // deciding whether they are ours to remove.
+        if pending_upgrade.is_some() {
+            info!("Waiting for the upgrade in progress before shutting down");
+            let (command, result) = finish_upgrade(&mut pending_upgrade).await;
+            handed_over = take_over(command, result, &shutdown);
+        }
+        shutdown.advance(ShutdownPhase::Draining);
+        serve.in_flight.start_draining();
+        let request = shutdown.requested();
+        let in_flight = serve.in_flight.len();
+        match &request {
+            Some(request) => info!(
+                reason = %request.reason(),
+                in_flight,
+                "Shutting down gracefully..."
+            ),
+            None => info!(in_flight, "Shutting down gracefully..."),
+        }
+        // After an upgrade the new daemon is the main process systemd tracks.
+        #[cfg(unix)]
+        if !handed_over {
+            notifier.stopping(&match &request {
+                Some(request) => format!("Shutting down: {}", request.reason()),
+                None => "Shutting down".to_string(),
             });
+        }
+
+        shutdown.trigger();
+
+        let mut addrs = Vec::new();
+        let mut handles = retired;
+        handles.extend(metrics_listener);
+        for listener in listeners {
+            if !listener.inherited {
+                addrs.push(listener.addr);
+            }
+            handles.push(listener.handle);
+        }
+        let timeout = request
+            .and_then(|r| r.drain_timeout())
+            .unwrap_or_else(|| Duration::from_secs(config_rx.borrow().shutdown_timeout_secs));
+        let jobs_settled = container.jobs_use_case.settled();
+        drain(handles, jobs_settled, timeout, &serve.in_flight, &shutdown).await;
+        serve.audit.flush().await;
+        container.jobs_use_case.flush().await;
+        #[cfg(unix)]
+        if let Some(watchdog) = watchdog {
+            watchdog.abort();
+        }
 
-            handles.push(handle);
+        shutdown.advance(ShutdownPhase::CleaningUp);
+
+        if handed_over {
+            // The new daemon serves these addresses and holds the lock now.
+            if let Some(lock) = self.config.lock.take() {
+                lock.hand_over();
+            }
+        } else {
+            #[cfg(unix)]
+            for addr in &addrs {
+                addr.cleanup();
+            }
+            process::remove_pid_file(&self.config.daemon);
         }
 
+        info!("Server stopped");
+        Ok(())
+    }
+}
+
+/// Rotates the log file on a blocking thread, as compressing a large one takes a
+/// while: always when `forced`, otherwise once the rotation policy asks for it.
+/// Does nothing while an earlier rotation is still running.
+fn spawn_rotation(rotator: &Arc<Mutex<LogRotator>>, forced: bool) {
+    let rotator = Arc::clone(rotator);
+    tokio::task::spawn_blocking(move || {
+        let mut rotator = match rotator.try_lock() {
+            Ok(rotator) => rotator,
+            Err(TryLockError::Poisoned(e)) => e.into_inner(),
+            Err(TryLockError::WouldBlock) => {
+                debug!("Log rotation already in progress");
+                return;
+            }
+        };
+        if forced || rotator.should_rotate(SystemTime::now()) {
+            rotate_logs(&mut rotator);
+        }
+    });
+}
+
+fn rotate_logs(rotator: &mut LogRotator) {
+    let result = rotator
+        .rotate()
+        .map_err(DaemonError::from)
+        .and_then(|file| process::redirect_output(&file));
+    match result {
+        Ok(()) => info!(
+            path = %rotator.path().display(),
+            max_files = rotator.policy().max_files,
+            compress = rotator.policy().compress,
+            "Rotated log file"
+        ),
+        Err(e) => error!(error = %e, "Failed to rotate log file"),
+    }
+}
+
+/// Binds `addr` and serves the routes on it.
+async fn spawn_listener(addr: ListenAddr, serve: &ServeContext) -> Result<RunningListener> {
+    let stream = addr.bind().await?;
+    #[cfg(unix)]
+    if let ListenAddr::Unix(path) = &addr
+        && let Err(e) = serve.socket_permissions.apply(path)
+    {
+        addr.cleanup();
+        return Err(e.into());
+    }
+    let listener = serve_listener(addr.clone(), stream, false, serve);
+    #[cfg(unix)]
+    if listener.is_err() {
+        addr.cleanup();
+    }
+    listener
+}
+
+/// Serves the routes on an already listening `stream` bound to `addr`.
+/// TCP is protected by TLS and bearer tokens, the Unix socket by its permissions and peer policy.
+fn serve_listener(
+    addr: ListenAddr,
+    stream: ListenerStream,
+    inherited: bool,
+    serve: &ServeContext,
+) -> Result<RunningListener> {
+    let (tls, auth) = match &addr {
+        ListenAddr::Tcp(socket) => {
+            tls::ensure_secure(*socket, serve.tls.is_some())?;
+            (
+                serve.tls.as_ref(),
+                AuthInterceptor::token(serve.auth.clone()),
+            )
+        }
         #[cfg(unix)]
-        if let Some(addr) = &self.config.uds {
-            let stream = addr.bind().await?;
-            let container = Arc::clone(&container);
-            let reflection = reflection.clone();
-            let shutdown = shutdown.clone();
-            let addr_display = addr.to_string();
+        ListenAddr::Unix(_) => (
+            None,
+            serve
+                .peer_policy
+                .clone()
+                .map(AuthInterceptor::peer)
+                .unwrap_or_default(),
+        ),
+    };
+    let router = build_router(
+        serve.routes.clone(),
+        serve.metrics.clone(),
+        tls,
+        auth,
+        InFlightLayer::new(serve.in_flight.clone()),
+        AuditLayer::new(serve.audit.clone(), addr.to_string()),
+        serve.authz.clone(),
+    )?;
+    info!(address = %addr, tls = tls.is_some(), "Listening");
 
-            info!(address = %addr_display, "Listening on UDS");
+    // A listener bound by a reload just as shutdown begins must still stop, or
+    // the drain would wait out its whole timeout on it.
+    let mut shutdown_rx = serve.shutdown.subscribe();
+    let (stop, stopped) = oneshot::channel();
+    let signal = async move {
+        tokio::select! {
+            _ = shutdown_rx.recv() => {}
+            _ = stopped => {}
+        }
+    };
 
-            let handle = tokio::spawn(async move {
-                if let ListenerStream::Unix(listener) = stream {
-                    let router = build_router(&container, reflection);
-                    let result = router
-                        .serve_with_incoming_shutdown(listener, shutdown.wait())
-                        .await;
+    #[cfg(unix)]
+    let fd = stream.as_fd().try_clone_to_owned()?;
+    let address = addr.to_string();
+    let handle = tokio::spawn(async move {
+        let result = match stream {
+            ListenerStream::Tcp(incoming) => {
+                router.serve_with_incoming_shutdown(incoming, signal).await
+            }
+            #[cfg(unix)]
+            ListenerStream::Unix(incoming) => {
+                router.serve_with_incoming_shutdown(incoming, signal).await
+            }
+        };
 
-                    if let Err(e) = result {
-                        error!(error = %e, "UDS server error");
-                    }
-                }
-            });
+        if let Err(e) = result {
+            error!(address = %address, error = %e, "Server error");
+        }
+    });
+
+    Ok(RunningListener {
+        addr,
+        inherited,
+        #[cfg(unix)]
+        fd,
+        stop,
+        handle,
+    })
+}
 
-            handles.push(handle);
+/// Brings the listeners in line with the addresses in `config`: binds those not
+/// served yet and retires those no longer configured, e.g. TCP once `tcp_addr`
+/// is emptied. A listener whose replacement fails to bind is kept.
+/// Returns the handles of the retired listeners, which keep draining in the background.
+async fn reconcile(
+    listeners: &mut Vec<RunningListener>,
+    config: &DaemonConfig,
+    serve: &ServeContext,
+) -> Vec<JoinHandle<()>> {
+    let desired = match configured_addrs(config) {
+        Ok(desired) => desired,
+        Err(e) => {
+            error!(error = %e, "Invalid listener address, keeping current listeners");
+            return Vec::new();
         }
+    };
 
-        if handles.is_empty() {
-            return Err(DaemonError::NoListenersConfigured);
+    for addr in &desired {
+        if listeners.iter().any(|listener| listener.addr == *addr) {
+            continue;
+        }
+        match spawn_listener(addr.clone(), serve).await {
+            Ok(listener) => {
+                info!(address = %addr, "Added listener");
+                listeners.push(listener);
+            }
+            Err(e) => error!(address = %addr, error = %e, "Failed to bind new listener"),
         }
+    }
 
-        wait_for_signal().await;
-        info!("Shutting down gracefully...");
+    let (stale, kept): (Vec<_>, Vec<_>) = std::mem::take(listeners)
+        .into_iter()
+        .partition(|listener| !listener.inherited && !desired.contains(&listener.addr));
+    *listeners = kept;
+    let mut retired = Vec::new();
+    for listener in stale {
+        let unreplaced = desired.iter().any(|addr| {
+            same_kind(addr, &listener.addr) && !listeners.iter().any(|served| served.addr == *addr)
+        });
+        if unreplaced {
+            warn!(address = %listener.addr, "Keeping listener until its new address binds");
+            listeners.push(listener);
+            continue;
+        }
+        info!(address = %listener.addr, "Retiring listener");
+        retired.push(tokio::spawn(retire(listener)));
+    }
 
-        shutdown.trigger();
+    retired
+}
+
+/// The listeners `config` asks for; an empty `tcp_addr` turns TCP off.
+fn configured_addrs(config: &DaemonConfig) -> Result<Vec<ListenAddr>> {
+    let mut addrs = Vec::new();
+    if !config.tcp_addr.is_empty() {
+        addrs.push(ListenAddr::tcp(config.tcp_addr.parse()?));
+    }
+    #[cfg(unix)]
+    addrs.push(ListenAddr::unix(&config.socket));
+    Ok(addrs)
+}
 
+fn same_kind(a: &ListenAddr, b: &ListenAddr) -> bool {
+    std::mem::discriminant(a) == std::mem::discriminant(b)
+}
+
+/// Stops accepting on a replaced listener and lets its connections drain.
+async fn retire(listener: RunningListener) {
+    let _ = listener.stop.send(());
+    if let Err(e) = listener.handle.await {
+        error!(error = %e, "Listener task panicked while retiring");
+    }
+
+    #[cfg(unix)]
+    listener.addr.cleanup();
+    debug!(address = %listener.addr, "Retired listener");
+}
+
+/// An upgrade whose new daemon is starting in the background, so the server
+/// loop keeps handling signals, reloads and shutdown meanwhile.
+struct PendingUpgrade {
+    command: UpgradeCommand,
+    task: JoinHandle<Result<Upgraded>>,
+}
+
+impl PendingUpgrade {
+    #[cfg(unix)]
+    fn start(
+        command: UpgradeCommand,
+        executable: Option<PathBuf>,
+        jobs: JobsUseCase,
+        listeners: &[RunningListener],
+        metrics: Option<&OwnedFd>,
+        lock: Option<&LockGuard>,
+        notifier: &Notifier,
+    ) -> Self {
+        let handoff = handoff_fds(listeners, metrics, lock);
+        let notifier = notifier.clone();
+        let task = tokio::spawn(async move {
+            let (sockets, lock) = handoff?;
+            // The new daemon recovers the job store as it starts, so no job may
+            // be running or written here by then.
+            jobs.suspend().await;
+            let result = hand_over(executable, sockets, lock, notifier).await;
+            if result.is_err()
+                && let Err(e) = jobs.resume()
+            {
+                error!(error = %e, "Failed to resume jobs after the upgrade");
+            }
+            result
+        });
+        Self { command, task }
+    }
+}
+
+/// Waits for the pending upgrade; only polled while there is one.
+async fn finish_upgrade(
+    pending: &mut Option<PendingUpgrade>,
+) -> (UpgradeCommand, Result<Upgraded>) {
+    let Some(upgrade) = pending.as_mut() else {
+        return std::future::pending().await;
+    };
+    let result = match (&mut upgrade.task).await {
+        Ok(result) => result,
+        Err(e) => Err(DaemonError::UpgradeError(format!(
+            "upgrade task failed: {}",
+            e
+        ))),
+    };
+    let upgrade = pending.take().expect("upgrade still pending");
+    (upgrade.command, result)
+}
+
+/// Answers the upgrade request and, if the new daemon serves, starts shutting
+/// down in its favour. Returns whether it took over.
+fn take_over(command: UpgradeCommand, result: Result<Upgraded>, shutdown: &ShutdownSignal) -> bool {
+    match result {
+        Ok(upgraded) => {
+            info!(pid = upgraded.pid, "New daemon is serving, handing over");
+            let _ = command.reply.send(Ok(upgraded));
+            shutdown.request(ShutdownRequest::new(
+                "upgrade",
+                command.request.drain_timeout,
+            ));
+            true
+        }
+        Err(e) => {
+            error!(error = %e, "Upgrade failed, keeping the current daemon");
+            let _ = command.reply.send(Err(e));
+            false
+        }
+    }
+}
+
+/// Copies of the listening sockets and the lock for the new daemon to inherit.
+#[cfg(unix)]
+fn handoff_fds(
+    listeners: &[RunningListener],
+    metrics: Option<&OwnedFd>,
+    lock: Option<&LockGuard>,
+) -> Result<(Vec<(SocketKind, OwnedFd)>, OwnedFd)> {
+    let lock =
+        lock.ok_or_else(|| DaemonError::UpgradeError("no lock file to hand over".to_string()))?;
+
+    let mut sockets = listeners
+        .iter()
+        .map(|listener| {
+            let kind = if listener.inherited {
+                SocketKind::Systemd
+            } else {
+                SocketKind::Grpc
+            };
+            Ok((kind, listener.fd.try_clone()?))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if let Some(fd) = metrics {
+        sockets.push((SocketKind::Metrics, fd.try_clone()?));
+    }
+    Ok((sockets, lock.as_fd().try_clone_to_owned()?))
+}
+
+/// Starts the new daemon on this one's listeners, metrics endpoint and lock,
+/// and makes it systemd's main process once it serves.
+#[cfg(unix)]
+async fn hand_over(
+    executable: Option<PathBuf>,
+    sockets: Vec<(SocketKind, OwnedFd)>,
+    lock: OwnedFd,
+    notifier: Notifier,
+) -> Result<Upgraded> {
+    let executable = executable.ok_or_else(|| {
+        DaemonError::UpgradeError("cannot locate the daemon executable".to_string())
+    })?;
+    let sockets: Vec<_> = sockets
+        .iter()
+        .map(|(kind, fd)| (*kind, fd.as_fd()))
+        .collect();
+
+    info!(executable = %executable.display(), "Starting new daemon for upgrade");
+    let args: Vec<_> = std::env::args_os().skip(1).collect();
+    let pid = upgrade::spawn_successor(
+        &executable,
+        &args,
+        &sockets,
+        lock.as_fd(),
+        upgrade::READY_TIMEOUT,
+    )
+    .await?;
+    notifier.main_pid(pid);
+
+    Ok(Upgraded {
+        previous_pid: std::process::id(),
+        pid,
+    })
+}
+
+/// `STATUS=` text for systemd, e.g. "Serving on tcp://127.0.0.1:50051".
+#[cfg(unix)]
+fn serving_status(listeners: &[RunningListener]) -> String {
+    let addrs: Vec<String> = listeners
+        .iter()
+        .map(|listener| listener.addr.to_string())
+        .collect();
+    format!("Serving on {}", addrs.join(", "))
+}
+
+fn publish_listeners(sender: &watch::Sender<Vec<String>>, listeners: &[RunningListener]) {
+    sender.send_replace(
+        listeners
+            .iter()
+            .map(|listener| listener.addr.to_string())
+            .collect(),
+    );
+}
+
+/// Waits up to `timeout` for listener tasks to finish serving the requests in flight,
+/// and for running jobs to finish. Then cancels and logs the requests still running,
+/// as the jobs are by the dispatcher, and aborts listeners that do not finish within
+/// [`CANCEL_GRACE`] after that. Skipping to cleanup ends the wait at any point and
+/// aborts the listeners at once.
+async fn drain(
+    handles: Vec<JoinHandle<()>>,
+    jobs_settled: impl Future<Output = ()>,
+    timeout: Duration,
+    in_flight: &InFlight,
+    shutdown: &ShutdownSignal,
+) {
+    let aborts: Vec<_> = handles.iter().map(JoinHandle::abort_handle).collect();
+    let mut wait_all = std::pin::pin!(async move {
         for handle in handles {
             if let Err(e) = handle.await {
                 error!(error = %e, "Listener task panicked during shutdown");
             }
         }
+        jobs_settled.await;
+    });
+    let mut skipped = std::pin::pin!(shutdown.reached(ShutdownPhase::CleaningUp));
 
-        #[cfg(unix)]
-        if let Some(addr) = &self.config.uds {
-            addr.cleanup();
-        }
+    // `None` when a second signal skipped to cleanup before the timeout elapsed.
+    let drained = tokio::select! {
+        result = tokio::time::timeout(timeout, wait_all.as_mut()) => Some(result.is_ok()),
+        () = skipped.as_mut() => None,
+    };
+    if drained == Some(true) {
+        return;
+    }
 
-        info!("Server stopped");
-        Ok(())
+    let abandoned = in_flight.requests();
+    if drained.is_some() && shutdown.advance(ShutdownPhase::Cancelling) {
+        warn!(
+            timeout = ?timeout,
+            requests = abandoned.len(),
+            "Drain timeout elapsed, cancelling in-flight requests"
+        );
+    } else {
+        warn!(
+            requests = abandoned.len(),
+            "Skipping drain, cancelling in-flight requests"
+        );
+    }
+    for request in &abandoned {
+        warn!(
+            method = %request.method,
+            caller = %request.caller,
+            elapsed = ?request.elapsed,
+            "Abandoned request"
+        );
+    }
+    in_flight.cancel();
+
+    let closed = drained.is_some()
+        && tokio::select! {
+            result = tokio::time::timeout(CANCEL_GRACE, wait_all) => result.is_ok(),
+            () = skipped => false,
+        };
+    if !closed {
+        debug!("Aborting listeners that are still open");
+        for abort in aborts {
+            abort.abort();
+        }
     }
 }
 
@@ -127,15 +934,87 @@ fn build_reflection()
 -> Result<ServerReflectionServer<impl tonic_reflection::server::v1::ServerReflection>> {
     tonic_reflection::server::Builder::configure()
         .register_encoded_file_descriptor_set(FILE_DESCRIPTOR_SET)
+        .register_encoded_file_descriptor_set(tonic_health::pb::FILE_DESCRIPTOR_SET)
         .build_v1()
         .map_err(|e| DaemonError::ReflectionError(e.to_string()))
 }
 
-fn build_router(
+/// `service/method` of every RPC the routes serve, from the descriptors reflection serves.
+fn served_methods() -> Result<Vec<String>> {
+    let mut methods = Vec::new();
+    for encoded in [
+        FILE_DESCRIPTOR_SET,
+        tonic_health::pb::FILE_DESCRIPTOR_SET,
+        tonic_reflection::pb::v1::FILE_DESCRIPTOR_SET,
+    ] {
+        let set = FileDescriptorSet::decode(encoded)
+            .map_err(|e| DaemonError::ReflectionError(e.to_string()))?;
+        for file in set.file {
+            for service in &file.service {
+                for method in &service.method {
+                    methods.push(format!(
+                        "{}.{}/{}",
+                        file.package(),
+                        service.name(),
+                        method.name()
+                    ));
+                }
+            }
+        }
+    }
+    Ok(methods)
+}
+
+fn build_routes(
     container: &Container,
     reflection: ServerReflectionServer<impl tonic_reflection::server::v1::ServerReflection>,
-) -> tonic::transport::server::Router {
-    tonic::transport::Server::builder()
-        .add_service(reflection)
+    health: HealthServer<impl Health>,
+) -> Routes {
+    Routes::new(reflection)
+        .add_service(health)
         .add_service(PingServiceServer::from_arc(container.ping_handler.clone()))
+        .add_service(DaemonServiceServer::from_arc(
+            container.daemon_handler.clone(),
+        ))
+        .add_service(JobServiceServer::from_arc(container.job_handler.clone()))
+}
+
+/// Outermost first: rejected requests are still counted, traced and audited,
+/// and in-flight tracking and authorization see the caller established by
+/// authentication.
+type RouterLayers = Stack<
+    AuthzLayer,
+    Stack<
+        InFlightLayer,
+        Stack<
+            InterceptorLayer<AuthInterceptor>,
+            Stack<AuditLayer, Stack<TraceContextLayer, Stack<MetricsLayer, Identity>>>,
+        >,
+    >,
+>;
+
+fn build_router(
+    routes: Routes,
+    metrics: Metrics,
+    tls: Option<&ServerTlsConfig>,
+    auth: AuthInterceptor,
+    in_flight: InFlightLayer,
+    audit: AuditLayer,
+    authz: Authorizer,
+) -> Result<Router<RouterLayers>> {
+    let mut builder = tonic::transport::Server::builder();
+    if let Some(tls) = tls {
+        builder = builder
+            .tls_config(tls.clone())
+            .map_err(|e| DaemonError::TlsError(e.to_string()))?;
+    }
+
+    Ok(builder
+        .layer(MetricsLayer::new(metrics))
+        .layer(TraceContextLayer)
+        .layer(audit)
+        .layer(InterceptorLayer::new(auth))
+        .layer(in_flight)
+        .layer(AuthzLayer::new(authz))
+        .add_routes(routes))
 }
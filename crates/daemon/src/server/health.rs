@@ -0,0 +1,97 @@
+use tonic_health::ServingStatus;
+use tonic_health::server::HealthReporter;
+use tracing::debug;
+
+use crate::generated::{daemon_service_server, job_service_server, ping_service_server};
+use crate::usecase::ShutdownSignal;
+
+/// Services reported over `grpc.health.v1.Health`.
+/// The empty name is the overall server status.
+const SERVICE_NAMES: &[&str] = &[
+    "",
+    ping_service_server::SERVICE_NAME,
+    daemon_service_server::SERVICE_NAME,
+    job_service_server::SERVICE_NAME,
+];
+
+pub async fn set_all(reporter: &HealthReporter, status: ServingStatus) {
+    for name in SERVICE_NAMES {
+        reporter.set_service_status(name, status).await;
+    }
+}
+
+/// Marks every service as serving, then flips them to NOT_SERVING once `shutdown` fires.
+pub async fn report(reporter: HealthReporter, shutdown: &ShutdownSignal) {
+    set_all(&reporter, ServingStatus::Serving).await;
+
+    // A broadcast receiver only sees later sends, so take it before the task
+    // runs; otherwise a shutdown starting meanwhile would leave watchers on SERVING.
+    let mut receiver = shutdown.subscribe();
+    tokio::spawn(async move {
+        let _ = receiver.recv().await;
+        debug!("Reporting NOT_SERVING to health watchers");
+        set_all(&reporter, ServingStatus::NotServing).await;
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tokio_stream::StreamExt;
+    use tonic::Request;
+    use tonic_health::pb::health_check_response::ServingStatus as PbStatus;
+    use tonic_health::pb::health_server::Health;
+    use tonic_health::pb::{HealthCheckRequest, HealthCheckResponse};
+    use tonic_health::server::HealthService;
+
+    async fn check(service: &HealthService, name: &str) -> PbStatus {
+        let response: HealthCheckResponse = service
+            .check(Request::new(HealthCheckRequest {
+                service: name.to_string(),
+            }))
+            .await
+            .expect("service is registered")
+            .into_inner();
+        response.status()
+    }
+
+    async fn wait_for(service: &HealthService, name: &str, status: PbStatus) {
+        let mut updates = service
+            .watch(Request::new(HealthCheckRequest {
+                service: name.to_string(),
+            }))
+            .await
+            .expect("service is registered")
+            .into_inner();
+        let reached = async {
+            while let Some(update) = updates.next().await {
+                if update.expect("watch update").status() == status {
+                    return;
+                }
+            }
+            panic!("watch for {name:?} ended before {status:?}");
+        };
+        tokio::time::timeout(Duration::from_secs(5), reached)
+            .await
+            .unwrap_or_else(|_| panic!("{name:?} never reported {status:?}"));
+    }
+
+    #[tokio::test]
+    async fn test_flips_to_not_serving_on_shutdown() {
+        let reporter = HealthReporter::new();
+        let service = HealthService::from_health_reporter(reporter.clone());
+        let shutdown = ShutdownSignal::new();
+
+        report(reporter, &shutdown).await;
+        for name in SERVICE_NAMES {
+            assert_eq!(check(&service, name).await, PbStatus::Serving);
+        }
+
+        shutdown.trigger();
+        for name in SERVICE_NAMES {
+            wait_for(&service, name, PbStatus::NotServing).await;
+            assert_eq!(check(&service, name).await, PbStatus::NotServing);
+        }
+    }
+}
@@ -2,22 +2,249 @@ use std::io::{self, ErrorKind};
 use std::net::SocketAddr;
 #[cfg(unix)]
 use std::path::{Path, PathBuf};
+#[cfg(unix)]
+use std::pin::Pin;
+#[cfg(unix)]
+use std::task::{Context, Poll, ready};
 
+#[cfg(unix)]
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
 use tokio::net::TcpListener;
 #[cfg(unix)]
-use tokio::net::UnixListener;
+use tokio::net::{UnixListener, UnixStream};
 use tokio_stream::wrappers::TcpListenerStream;
 #[cfg(unix)]
-use tokio_stream::wrappers::UnixListenerStream;
+use tonic::transport::server::Connected;
 use tracing::debug;
 
+#[cfg(unix)]
+use crate::config::DaemonConfig;
+#[cfg(unix)]
+use crate::domain::PeerCredentials;
+#[cfg(unix)]
+use crate::error::{DaemonError, Result};
+#[cfg(unix)]
+use crate::infra::auth::{resolve_group, resolve_user};
+
 pub enum ListenerStream {
     Tcp(TcpListenerStream),
     #[cfg(unix)]
-    Unix(UnixListenerStream),
+    Unix(UnixIncoming),
+}
+
+impl ListenerStream {
+    /// Wraps a listening TCP or Unix stream socket opened by someone else,
+    /// e.g. passed by systemd, and returns the address it is bound to.
+    /// Must be called inside the tokio runtime.
+    #[cfg(unix)]
+    pub fn from_fd(fd: std::os::fd::OwnedFd) -> io::Result<(ListenAddr, Self)> {
+        use nix::sys::socket::{
+            AddressFamily, SockType, SockaddrLike, SockaddrStorage, getsockname, getsockopt,
+            sockopt,
+        };
+        use std::os::fd::AsRawFd;
+
+        let unsupported = |what: &str| {
+            io::Error::new(
+                ErrorKind::InvalidInput,
+                format!("inherited socket {:?} {}", fd, what),
+            )
+        };
+        if getsockopt(&fd, sockopt::SockType)? != SockType::Stream
+            || !getsockopt(&fd, sockopt::AcceptConn)?
+        {
+            return Err(unsupported("is not a listening stream socket"));
+        }
+
+        let local: SockaddrStorage = getsockname(fd.as_raw_fd())?;
+        match local.family() {
+            Some(AddressFamily::Inet | AddressFamily::Inet6) => {
+                let listener = std::net::TcpListener::from(fd);
+                listener.set_nonblocking(true)?;
+                let addr = listener.local_addr()?;
+                let listener = TcpListener::from_std(listener)?;
+                Ok((
+                    ListenAddr::Tcp(addr),
+                    Self::Tcp(TcpListenerStream::new(listener)),
+                ))
+            }
+            Some(AddressFamily::Unix) => {
+                let Some(path) = local.as_unix_addr().and_then(|addr| addr.path()) else {
+                    return Err(unsupported(
+                        "has no path (abstract sockets are not supported)",
+                    ));
+                };
+                let path = path.to_path_buf();
+                let listener = std::os::unix::net::UnixListener::from(fd);
+                listener.set_nonblocking(true)?;
+                let listener = UnixListener::from_std(listener)?;
+                Ok((
+                    ListenAddr::Unix(path),
+                    Self::Unix(UnixIncoming { listener }),
+                ))
+            }
+            _ => Err(unsupported("is neither TCP nor a Unix socket")),
+        }
+    }
+}
+
+#[cfg(unix)]
+impl std::os::fd::AsFd for ListenerStream {
+    fn as_fd(&self) -> std::os::fd::BorrowedFd<'_> {
+        match self {
+            Self::Tcp(incoming) => incoming.as_ref().as_fd(),
+            Self::Unix(incoming) => incoming.listener.as_fd(),
+        }
+    }
+}
+
+/// Mode and ownership applied to the Unix socket right after it is bound.
+#[cfg(unix)]
+#[derive(Debug, Clone, Default)]
+pub struct SocketPermissions {
+    mode: Option<u32>,
+    owner: Option<nix::unistd::Uid>,
+    group: Option<nix::unistd::Gid>,
+}
+
+#[cfg(unix)]
+impl SocketPermissions {
+    pub fn from_config(config: &DaemonConfig) -> Result<Self> {
+        let mode = config
+            .socket_mode
+            .as_deref()
+            .map(|mode| {
+                u32::from_str_radix(mode, 8)
+                    .ok()
+                    .filter(|mode| *mode <= 0o777)
+                    .ok_or_else(|| {
+                        DaemonError::SocketAccessError(format!(
+                            "invalid socket_mode '{}': expected octal such as \"0660\"",
+                            mode
+                        ))
+                    })
+            })
+            .transpose()?;
+
+        Ok(Self {
+            mode,
+            owner: config
+                .socket_owner
+                .as_deref()
+                .map(resolve_user)
+                .transpose()?,
+            group: config
+                .socket_group
+                .as_deref()
+                .map(resolve_group)
+                .transpose()?,
+        })
+    }
+
+    pub fn apply(&self, path: &Path) -> io::Result<()> {
+        if self.owner.is_some() || self.group.is_some() {
+            nix::unistd::chown(path, self.owner, self.group)?;
+        }
+        if let Some(mode) = self.mode {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(path, std::fs::Permissions::from_mode(mode))?;
+        }
+        Ok(())
+    }
+}
+
+/// Accepts Unix socket connections, tagging each with the peer's credentials.
+#[cfg(unix)]
+pub struct UnixIncoming {
+    listener: UnixListener,
+}
+
+#[cfg(unix)]
+impl tokio_stream::Stream for UnixIncoming {
+    type Item = io::Result<UnixConnection>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let accepted = ready!(self.listener.poll_accept(cx));
+        // A connection whose credentials cannot be read is dropped rather than served anonymously.
+        Poll::Ready(Some(
+            accepted.and_then(|(stream, _)| UnixConnection::new(stream)),
+        ))
+    }
+}
+
+/// A Unix socket connection whose [`PeerCredentials`] tonic adds to each request's extensions.
+#[cfg(unix)]
+pub struct UnixConnection {
+    stream: UnixStream,
+    peer: PeerCredentials,
+}
+
+#[cfg(unix)]
+impl UnixConnection {
+    fn new(stream: UnixStream) -> io::Result<Self> {
+        let cred = stream.peer_cred()?;
+        let peer = PeerCredentials {
+            uid: cred.uid(),
+            gid: cred.gid(),
+            pid: cred.pid(),
+        };
+        debug!(uid = peer.uid, gid = peer.gid, pid = ?peer.pid, "Accepted Unix socket connection");
+        Ok(Self { stream, peer })
+    }
+}
+
+#[cfg(unix)]
+impl Connected for UnixConnection {
+    type ConnectInfo = PeerCredentials;
+
+    fn connect_info(&self) -> Self::ConnectInfo {
+        self.peer
+    }
+}
+
+#[cfg(unix)]
+impl AsyncRead for UnixConnection {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.stream).poll_read(cx, buf)
+    }
 }
 
-#[derive(Debug, Clone)]
+#[cfg(unix)]
+impl AsyncWrite for UnixConnection {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.stream).poll_write(cx, buf)
+    }
+
+    fn poll_write_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.stream).poll_write_vectored(cx, bufs)
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.stream.is_write_vectored()
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.stream).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.stream).poll_shutdown(cx)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ListenAddr {
     Tcp(SocketAddr),
     #[cfg(unix)]
@@ -55,7 +282,7 @@ impl ListenAddr {
                 }
                 debug!(path = %path.display(), "Binding Unix socket");
                 let listener = UnixListener::bind(path)?;
-                Ok(ListenerStream::Unix(UnixListenerStream::new(listener)))
+                Ok(ListenerStream::Unix(UnixIncoming { listener }))
             }
         }
     }
@@ -92,3 +319,83 @@ async fn is_socket_active(path: &Path) -> bool {
         _ => false,
     }
 }
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+    use tokio_stream::StreamExt;
+
+    #[tokio::test]
+    async fn test_unix_connections_carry_peer_credentials() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ffit.sock");
+        let addr = ListenAddr::unix(&path);
+
+        let ListenerStream::Unix(mut incoming) = addr.bind().await.unwrap() else {
+            panic!("expected a Unix listener");
+        };
+        let config = DaemonConfig {
+            socket_mode: Some("0600".to_string()),
+            ..DaemonConfig::default()
+        };
+        SocketPermissions::from_config(&config)
+            .unwrap()
+            .apply(&path)
+            .unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+
+        let _client = UnixStream::connect(&path).await.unwrap();
+        let connection = incoming.next().await.unwrap().unwrap();
+        let peer = connection.connect_info();
+        assert_eq!(peer.uid, nix::unistd::getuid().as_raw());
+        assert_eq!(peer.gid, nix::unistd::getgid().as_raw());
+        assert_eq!(peer.pid, Some(std::process::id() as i32));
+
+        addr.cleanup();
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_wraps_inherited_sockets() {
+        let tcp = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = tcp.local_addr().unwrap();
+        let (listen_addr, stream) = ListenerStream::from_fd(tcp.into()).unwrap();
+        assert_eq!(listen_addr, ListenAddr::tcp(addr));
+        assert!(matches!(stream, ListenerStream::Tcp(_)));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ffit.sock");
+        let unix = std::os::unix::net::UnixListener::bind(&path).unwrap();
+        let (listen_addr, stream) = ListenerStream::from_fd(unix.into()).unwrap();
+        assert_eq!(listen_addr, ListenAddr::unix(&path));
+        let ListenerStream::Unix(mut incoming) = stream else {
+            panic!("expected a Unix listener");
+        };
+        let _client = UnixStream::connect(&path).await.unwrap();
+        assert!(incoming.next().await.unwrap().is_ok());
+
+        // A connected (not listening) socket is refused.
+        let (left, _right) = std::os::unix::net::UnixStream::pair().unwrap();
+        assert!(ListenerStream::from_fd(left.into()).is_err());
+    }
+
+    #[test]
+    fn test_socket_mode_must_be_octal() {
+        for mode in ["rw-rw----", "0999", "10000"] {
+            let config = DaemonConfig {
+                socket_mode: Some(mode.to_string()),
+                ..DaemonConfig::default()
+            };
+            assert!(
+                matches!(
+                    SocketPermissions::from_config(&config),
+                    Err(DaemonError::SocketAccessError(_))
+                ),
+                "{}",
+                mode
+            );
+        }
+    }
+}
@@ -5,6 +5,9 @@ use tracing::{debug, warn};
 
 use crate::error::{DaemonError, Result};
 
+#[cfg(unix)]
+use std::os::fd::{AsFd, BorrowedFd, OwnedFd};
+
 #[cfg(unix)]
 use nix::fcntl::{Flock, FlockArg};
 
@@ -41,6 +44,28 @@ impl LockGuard {
         debug!(path = %lock_path.display(), "Exclusive lock acquired");
         Ok(Self { _flock: flock })
     }
+
+    /// Adopts the lock held through `fd`, inherited from the daemon being upgraded.
+    /// flock locks belong to the open file, so locking it again succeeds at once.
+    pub fn inherit(fd: OwnedFd) -> Result<Self> {
+        let flock = Flock::lock(File::from(fd), FlockArg::LockExclusiveNonblock)
+            .map_err(|(_, errno)| DaemonError::LockError(errno.to_string()))?;
+        debug!("Adopted lock from previous daemon");
+        Ok(Self { _flock: flock })
+    }
+
+    /// Leaves the lock to the process it was handed to: unlocking on drop would
+    /// release it for that process too.
+    pub fn hand_over(self) {
+        std::mem::forget(self);
+    }
+}
+
+#[cfg(unix)]
+impl AsFd for LockGuard {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self._flock.as_fd()
+    }
 }
 
 #[cfg(not(unix))]
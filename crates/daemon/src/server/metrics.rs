@@ -0,0 +1,49 @@
+use axum::extract::State;
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+use tracing::{error, info};
+
+use crate::error::Result;
+use crate::infra::metrics::Metrics;
+use crate::usecase::ShutdownSignal;
+
+/// Serves `GET /metrics` over plain HTTP on `listener` until shutdown.
+pub fn spawn_listener(
+    listener: TcpListener,
+    metrics: Metrics,
+    shutdown: &ShutdownSignal,
+) -> Result<JoinHandle<()>> {
+    let addr = listener.local_addr()?;
+    info!(address = %format!("http://{}/metrics", addr), "Serving metrics");
+
+    let app = axum::Router::new()
+        .route("/metrics", get(render))
+        .with_state(metrics);
+
+    // Taken now so a shutdown during startup still stops the endpoint.
+    let mut shutdown_rx = shutdown.subscribe();
+    Ok(tokio::spawn(async move {
+        let result = axum::serve(listener, app)
+            .with_graceful_shutdown(async move {
+                let _ = shutdown_rx.recv().await;
+            })
+            .await;
+
+        if let Err(e) = result {
+            error!(address = %addr, error = %e, "Metrics server error");
+        }
+    }))
+}
+
+async fn render(State(metrics): State<Metrics>) -> Response {
+    match metrics.render() {
+        Ok(text) => ([(header::CONTENT_TYPE, prometheus::TEXT_FORMAT)], text).into_response(),
+        Err(e) => {
+            error!(error = %e, "Failed to render metrics");
+            (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response()
+        }
+    }
+}
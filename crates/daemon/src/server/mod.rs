@@ -1,10 +1,18 @@
 mod grpc;
+mod health;
 mod listener;
 pub mod lock;
+mod metrics;
 pub mod process;
 mod shutdown;
+#[cfg(unix)]
+pub mod systemd;
+pub mod tls;
+#[cfg(unix)]
+pub mod upgrade;
 
 pub use grpc::{Server, ServerConfig};
-pub use listener::ListenAddr;
+#[cfg(unix)]
+pub use listener::SocketPermissions;
+pub use listener::{ListenAddr, ListenerStream};
 pub use lock::LockGuard;
-pub use shutdown::ShutdownSignal;
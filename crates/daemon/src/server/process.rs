@@ -1,3 +1,5 @@
+use tracing::warn;
+
 use crate::config::DaemonConfig;
 use crate::error::{DaemonError, Result};
 
@@ -32,6 +34,36 @@ pub fn daemonize(config: &DaemonConfig) -> Result<()> {
     Ok(())
 }
 
+/// Records our PID where daemonizing would have, so `ffit` finds a daemon
+/// that systemd keeps in the foreground.
+pub fn write_pid_file(config: &DaemonConfig) -> Result<()> {
+    if let Some(parent) = config.pid_file.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&config.pid_file, format!("{}\n", std::process::id()))?;
+    Ok(())
+}
+
+/// Removes the PID file if it still names this process, so one written by
+/// the daemon that took over in an upgrade is left alone.
+pub fn remove_pid_file(config: &DaemonConfig) {
+    let ours = std::fs::read_to_string(&config.pid_file)
+        .is_ok_and(|pid| pid.trim() == std::process::id().to_string());
+    if ours && let Err(e) = std::fs::remove_file(&config.pid_file) {
+        warn!(path = %config.pid_file.display(), error = %e, "Failed to remove PID file");
+    }
+}
+
+/// Points stdout and stderr at `file`, e.g. after the log file was rotated.
+#[cfg(unix)]
+pub fn redirect_output(file: &std::fs::File) -> Result<()> {
+    use nix::unistd::{dup2_stderr, dup2_stdout};
+
+    dup2_stdout(file).map_err(std::io::Error::from)?;
+    dup2_stderr(file).map_err(std::io::Error::from)?;
+    Ok(())
+}
+
 #[cfg(not(unix))]
 pub fn daemonize(_config: &DaemonConfig) -> Result<()> {
     Err(DaemonError::DaemonizeError(
@@ -39,6 +71,13 @@ pub fn daemonize(_config: &DaemonConfig) -> Result<()> {
     ))
 }
 
+#[cfg(not(unix))]
+pub fn redirect_output(_file: &std::fs::File) -> Result<()> {
+    Err(DaemonError::DaemonizeError(
+        "Output redirection not supported on this platform".to_string(),
+    ))
+}
+
 pub const fn is_daemon_supported() -> bool {
     cfg!(unix)
 }
@@ -1,77 +1,130 @@
-use tokio::sync::broadcast;
-use tracing::{info, warn};
+#[cfg(unix)]
+use tokio::signal::unix::{Signal, SignalKind};
+use tracing::{debug, info, warn};
 
-#[derive(Clone)]
-pub struct ShutdownSignal {
-    sender: broadcast::Sender<()>,
+use crate::domain::ShutdownPhase;
+use crate::usecase::ShutdownSignal;
+
+/// What an OS signal asks the server to do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignalEvent {
+    /// Ctrl+C or SIGTERM
+    Shutdown,
+    /// SIGHUP
+    Reload,
+    /// SIGUSR1
+    RotateLogs,
 }
 
-impl ShutdownSignal {
+/// Signal handlers installed once for the lifetime of the server,
+/// so signals arriving between `recv` calls are not lost.
+pub struct SignalListener {
+    #[cfg(unix)]
+    sigterm: Option<Signal>,
+    #[cfg(unix)]
+    sighup: Option<Signal>,
+    #[cfg(unix)]
+    sigusr1: Option<Signal>,
+}
+
+impl SignalListener {
     pub fn new() -> Self {
-        let (sender, _) = broadcast::channel(1);
-        Self { sender }
+        Self {
+            #[cfg(unix)]
+            sigterm: install(SignalKind::terminate(), "SIGTERM"),
+            #[cfg(unix)]
+            sighup: install(SignalKind::hangup(), "SIGHUP"),
+            #[cfg(unix)]
+            sigusr1: install(SignalKind::user_defined1(), "SIGUSR1"),
+        }
     }
 
-    pub fn trigger(&self) {
-        let _ = self.sender.send(());
-    }
+    pub async fn recv(&mut self) -> SignalEvent {
+        #[cfg(unix)]
+        {
+            tokio::select! {
+                result = tokio::signal::ctrl_c() => {
+                    log_ctrl_c(result);
+                    SignalEvent::Shutdown
+                }
+                Some(()) = recv_installed(&mut self.sigterm) => {
+                    info!("Received SIGTERM");
+                    SignalEvent::Shutdown
+                }
+                Some(()) = recv_installed(&mut self.sighup) => {
+                    info!("Received SIGHUP");
+                    SignalEvent::Reload
+                }
+                Some(()) = recv_installed(&mut self.sigusr1) => {
+                    info!("Received SIGUSR1");
+                    SignalEvent::RotateLogs
+                }
+            }
+        }
 
-    pub fn subscribe(&self) -> broadcast::Receiver<()> {
-        self.sender.subscribe()
+        #[cfg(not(unix))]
+        {
+            log_ctrl_c(tokio::signal::ctrl_c().await);
+            SignalEvent::Shutdown
+        }
     }
+}
 
-    pub async fn wait(&self) {
-        let mut receiver = self.subscribe();
-        let _ = receiver.recv().await;
+impl SignalListener {
+    /// Escalates a shutdown already underway, counting the shutdown signals that
+    /// arrive after it began: the first skips the rest of the drain and goes
+    /// straight to cleanup, and the second exits at once.
+    pub async fn escalate(mut self, shutdown: ShutdownSignal) {
+        let mut received = 0;
+        loop {
+            match self.recv().await {
+                SignalEvent::Shutdown => received += 1,
+                event => {
+                    debug!(event = ?event, "Ignoring signal while shutting down");
+                    continue;
+                }
+            }
+            if received > 1 {
+                warn!("Shutdown signal received again, exiting immediately");
+                std::process::exit(1);
+            }
+            shutdown.advance(ShutdownPhase::CleaningUp);
+            warn!("Skipping to cleanup; send another shutdown signal to exit immediately");
+        }
     }
 }
 
-impl Default for ShutdownSignal {
+impl Default for SignalListener {
     fn default() -> Self {
         Self::new()
     }
 }
 
-pub async fn wait_for_signal() {
-    #[cfg(unix)]
-    let sigterm = match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+fn log_ctrl_c(result: std::io::Result<()>) {
+    match result {
+        Ok(()) => info!("Received Ctrl+C"),
+        Err(e) => warn!("Error waiting for Ctrl+C: {}", e),
+    }
+}
+
+#[cfg(unix)]
+fn install(kind: SignalKind, name: &str) -> Option<Signal> {
+    match tokio::signal::unix::signal(kind) {
         Ok(signal) => Some(signal),
         Err(e) => {
             warn!(
-                "Failed to install SIGTERM handler: {}. SIGTERM will be ignored.",
-                e
+                "Failed to install {} handler: {}. {} will be ignored.",
+                name, e, name
             );
             None
         }
-    };
-
-    #[cfg(unix)]
-    {
-        let ctrl_c = tokio::signal::ctrl_c();
-
-        if let Some(mut sigterm) = sigterm {
-            tokio::select! {
-                result = ctrl_c => {
-                    match result {
-                        Ok(()) => info!("Received Ctrl+C"),
-                        Err(e) => warn!("Error waiting for Ctrl+C: {}", e),
-                    }
-                }
-                _ = sigterm.recv() => info!("Received SIGTERM"),
-            }
-        } else {
-            match ctrl_c.await {
-                Ok(()) => info!("Received Ctrl+C"),
-                Err(e) => warn!("Error waiting for Ctrl+C: {}", e),
-            }
-        }
     }
+}
 
-    #[cfg(not(unix))]
-    {
-        match tokio::signal::ctrl_c().await {
-            Ok(()) => info!("Received Ctrl+C"),
-            Err(e) => warn!("Error waiting for Ctrl+C: {}", e),
-        }
+#[cfg(unix)]
+async fn recv_installed(signal: &mut Option<Signal>) -> Option<()> {
+    match signal {
+        Some(signal) => signal.recv().await,
+        None => std::future::pending().await,
     }
 }
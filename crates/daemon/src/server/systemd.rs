@@ -0,0 +1,284 @@
+//! systemd integration: socket activation (`LISTEN_FDS`, see sd_listen_fds(3))
+//! and the notify protocol (`NOTIFY_SOCKET`, `WATCHDOG_USEC`, see sd_notify(3)).
+//! Outside systemd none of these variables are set and everything here is a no-op.
+
+use std::env;
+use std::io;
+use std::os::fd::{FromRawFd, OwnedFd, RawFd};
+use std::os::unix::net::{SocketAddr, UnixDatagram};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use nix::fcntl::{FcntlArg, FdFlag, fcntl};
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+
+/// First descriptor passed by systemd (`SD_LISTEN_FDS_START`).
+const LISTEN_FDS_START: RawFd = 3;
+
+/// Set once the passed descriptors have been claimed, so they are never owned twice.
+static LISTEN_FDS_TAKEN: AtomicBool = AtomicBool::new(false);
+
+/// Whether systemd started the daemon as a notify service or passed it sockets.
+/// The daemon then stays in the foreground so systemd keeps tracking its main process.
+pub fn is_managed() -> bool {
+    env::var_os("NOTIFY_SOCKET").is_some() || env::var_os("LISTEN_FDS").is_some()
+}
+
+/// A listening socket passed by systemd, named by the socket unit's `FileDescriptorName=`.
+#[derive(Debug)]
+pub struct ActivatedSocket {
+    pub name: Option<String>,
+    pub fd: OwnedFd,
+}
+
+/// Takes ownership of the sockets systemd passed to this process.
+/// Returns nothing when called again or when the sockets were meant for another process.
+pub fn listen_fds() -> io::Result<Vec<ActivatedSocket>> {
+    let count = listen_fd_count(
+        env::var("LISTEN_PID").ok().as_deref(),
+        env::var("LISTEN_FDS").ok().as_deref(),
+        std::process::id(),
+    );
+    if count == 0 || LISTEN_FDS_TAKEN.swap(true, Ordering::SeqCst) {
+        return Ok(Vec::new());
+    }
+
+    let names = fd_names(env::var("LISTEN_FDNAMES").ok().as_deref(), count);
+    let mut sockets = Vec::with_capacity(count);
+    for (fd, name) in (LISTEN_FDS_START..).zip(names) {
+        // SAFETY: LISTEN_PID names this process, so systemd passed descriptors
+        // 3..3+LISTEN_FDS to it, and LISTEN_FDS_TAKEN guarantees they are wrapped once.
+        let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+        // systemd passes them inheritable; keep them out of anything we spawn.
+        fcntl(&fd, FcntlArg::F_SETFD(FdFlag::FD_CLOEXEC)).map_err(io::Error::from)?;
+        debug!(fd = ?fd, name = ?name, "Received socket from systemd");
+        sockets.push(ActivatedSocket { name, fd });
+    }
+    Ok(sockets)
+}
+
+/// Number of passed descriptors, or 0 unless `LISTEN_PID` is this process.
+fn listen_fd_count(listen_pid: Option<&str>, listen_fds: Option<&str>, pid: u32) -> usize {
+    if listen_pid.and_then(|listen_pid| listen_pid.parse::<u32>().ok()) != Some(pid) {
+        return 0;
+    }
+    listen_fds.and_then(|count| count.parse().ok()).unwrap_or(0)
+}
+
+/// Names from the colon-separated `LISTEN_FDNAMES`, one per descriptor.
+fn fd_names(names: Option<&str>, count: usize) -> Vec<Option<String>> {
+    let mut names = names.map(|names| names.split(':')).into_iter().flatten();
+    (0..count)
+        .map(|_| {
+            names
+                .next()
+                .filter(|name| !name.is_empty())
+                .map(str::to_string)
+        })
+        .collect()
+}
+
+/// Interval within which systemd expects `WATCHDOG=1`, if it watches this process.
+pub fn watchdog_interval() -> Option<Duration> {
+    watchdog_interval_from(
+        env::var("WATCHDOG_USEC").ok().as_deref(),
+        env::var("WATCHDOG_PID").ok().as_deref(),
+        std::process::id(),
+    )
+}
+
+fn watchdog_interval_from(
+    usec: Option<&str>,
+    watchdog_pid: Option<&str>,
+    pid: u32,
+) -> Option<Duration> {
+    if let Some(watchdog_pid) = watchdog_pid
+        && watchdog_pid.parse::<u32>().ok() != Some(pid)
+    {
+        return None;
+    }
+    usec.and_then(|usec| usec.parse().ok())
+        .filter(|usec| *usec > 0)
+        .map(Duration::from_micros)
+}
+
+/// Reports state changes to systemd over `NOTIFY_SOCKET`.
+/// Clones share one socket; without `NOTIFY_SOCKET` every call does nothing.
+#[derive(Clone, Default)]
+pub struct Notifier {
+    target: Option<Arc<Target>>,
+}
+
+struct Target {
+    socket: UnixDatagram,
+    addr: SocketAddr,
+}
+
+impl Notifier {
+    pub fn from_env() -> Self {
+        match env::var("NOTIFY_SOCKET") {
+            Ok(path) => Self::new(&path).unwrap_or_else(|e| {
+                warn!(socket = %path, error = %e, "Cannot notify systemd");
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Sends to the datagram socket at `path`; a leading `@` names a Linux abstract socket.
+    pub fn new(path: &str) -> io::Result<Self> {
+        let addr = match path.strip_prefix('@') {
+            #[cfg(target_os = "linux")]
+            Some(name) => {
+                use std::os::linux::net::SocketAddrExt;
+                SocketAddr::from_abstract_name(name)?
+            }
+            _ => SocketAddr::from_pathname(path)?,
+        };
+        Ok(Self {
+            target: Some(Arc::new(Target {
+                socket: UnixDatagram::unbound()?,
+                addr,
+            })),
+        })
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.target.is_some()
+    }
+
+    /// Startup finished: systemd starts dependent units and `systemctl start` returns.
+    pub fn ready(&self, status: &str) {
+        self.notify(&format!("READY=1\nSTATUS={}", status));
+    }
+
+    pub fn stopping(&self, status: &str) {
+        self.notify(&format!("STOPPING=1\nSTATUS={}", status));
+    }
+
+    /// Free-form text shown by `systemctl status`.
+    pub fn status(&self, status: &str) {
+        self.notify(&format!("STATUS={}", status));
+    }
+
+    /// Makes `pid` the main process, e.g. the new daemon after an upgrade.
+    pub fn main_pid(&self, pid: u32) {
+        self.notify(&format!("MAINPID={}", pid));
+    }
+
+    pub fn watchdog(&self) {
+        self.notify("WATCHDOG=1");
+    }
+
+    fn notify(&self, state: &str) {
+        let Some(target) = &self.target else {
+            return;
+        };
+        if let Err(e) = target.socket.send_to_addr(state.as_bytes(), &target.addr) {
+            warn!(error = %e, "Failed to notify systemd");
+        }
+    }
+
+    /// Pings the watchdog at half `interval` for as long as the runtime is alive,
+    /// so a hung runtime gets the daemon restarted.
+    pub fn spawn_watchdog(&self, interval: Duration) -> JoinHandle<()> {
+        let notifier = self.clone();
+        tokio::spawn(async move {
+            let mut ticks = tokio::time::interval(interval / 2);
+            loop {
+                ticks.tick().await;
+                notifier.watchdog();
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A socket standing in for systemd's, in a directory removed on drop.
+    fn notify_socket() -> (tempfile::TempDir, String, UnixDatagram) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notify");
+        let socket = UnixDatagram::bind(&path).unwrap();
+        socket
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        (dir, path.to_str().unwrap().to_string(), socket)
+    }
+
+    fn recv(socket: &UnixDatagram) -> String {
+        let mut buf = [0; 256];
+        let len = socket.recv(&mut buf).unwrap();
+        String::from_utf8_lossy(&buf[..len]).into_owned()
+    }
+
+    #[test]
+    fn test_notifier_sends_state_datagrams() {
+        let (_dir, path, socket) = notify_socket();
+        let notifier = Notifier::new(&path).unwrap();
+        assert!(notifier.is_enabled());
+
+        notifier.ready("Serving on tcp://127.0.0.1:50051");
+        assert_eq!(
+            recv(&socket),
+            "READY=1\nSTATUS=Serving on tcp://127.0.0.1:50051"
+        );
+        notifier.status("Reloaded");
+        assert_eq!(recv(&socket), "STATUS=Reloaded");
+        notifier.stopping("Shutting down");
+        assert_eq!(recv(&socket), "STOPPING=1\nSTATUS=Shutting down");
+
+        // Without NOTIFY_SOCKET nothing is sent and nothing fails.
+        Notifier::default().ready("ignored");
+    }
+
+    #[tokio::test]
+    async fn test_watchdog_pings_at_half_interval() {
+        let (_dir, path, socket) = notify_socket();
+        let notifier = Notifier::new(&path).unwrap();
+
+        let watchdog = notifier.spawn_watchdog(Duration::from_millis(40));
+        let pings = tokio::task::spawn_blocking(move || [recv(&socket), recv(&socket)])
+            .await
+            .unwrap();
+        watchdog.abort();
+
+        assert_eq!(pings, ["WATCHDOG=1", "WATCHDOG=1"]);
+    }
+
+    #[test]
+    fn test_listen_fds_require_matching_pid() {
+        assert_eq!(listen_fd_count(Some("42"), Some("2"), 42), 2);
+        assert_eq!(listen_fd_count(Some("41"), Some("2"), 42), 0);
+        assert_eq!(listen_fd_count(None, Some("2"), 42), 0);
+        assert_eq!(listen_fd_count(Some("42"), Some("many"), 42), 0);
+
+        assert_eq!(
+            fd_names(Some("grpc:metrics"), 3),
+            vec![Some("grpc".to_string()), Some("metrics".to_string()), None]
+        );
+        assert_eq!(fd_names(None, 1), vec![None]);
+    }
+
+    #[test]
+    fn test_watchdog_interval() {
+        assert_eq!(
+            watchdog_interval_from(Some("30000000"), None, 42),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            watchdog_interval_from(Some("30000000"), Some("42"), 42),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            watchdog_interval_from(Some("30000000"), Some("7"), 42),
+            None
+        );
+        assert_eq!(watchdog_interval_from(Some("0"), None, 42), None);
+        assert_eq!(watchdog_interval_from(None, None, 42), None);
+    }
+}
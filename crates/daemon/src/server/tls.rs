@@ -0,0 +1,181 @@
+use std::fs;
+use std::net::SocketAddr;
+use std::path::Path;
+
+use tonic::transport::{Certificate, Identity, ServerTlsConfig};
+
+use crate::config::DaemonConfig;
+use crate::error::{DaemonError, Result};
+
+/// Builds the TCP listener's TLS settings from `tls_cert`, `tls_key` and `tls_client_ca`.
+/// Returns `None` when TLS is not configured.
+pub fn load(config: &DaemonConfig) -> Result<Option<ServerTlsConfig>> {
+    let (cert, key) = match (&config.tls_cert, &config.tls_key) {
+        (Some(cert), Some(key)) => (cert, key),
+        (None, None) if config.tls_client_ca.is_some() => {
+            return Err(DaemonError::TlsError(
+                "tls_client_ca requires tls_cert and tls_key".to_string(),
+            ));
+        }
+        (None, None) => return Ok(None),
+        _ => {
+            return Err(DaemonError::TlsError(
+                "tls_cert and tls_key must be set together".to_string(),
+            ));
+        }
+    };
+
+    let mut tls = ServerTlsConfig::new().identity(Identity::from_pem(read(cert)?, read(key)?));
+    if let Some(ca) = &config.tls_client_ca {
+        tls = tls.client_ca_root(Certificate::from_pem(read(ca)?));
+    }
+
+    // PEM contents are only parsed when a router is built; do it now so a bad
+    // certificate fails at startup instead of when a listener is spawned.
+    tonic::transport::Server::builder()
+        .tls_config(tls.clone())
+        .map_err(|e| DaemonError::TlsError(e.to_string()))?;
+
+    Ok(Some(tls))
+}
+
+/// Plaintext TCP is only allowed on loopback addresses.
+pub fn ensure_secure(addr: SocketAddr, tls: bool) -> Result<()> {
+    if tls || addr.ip().is_loopback() {
+        Ok(())
+    } else {
+        Err(DaemonError::InsecureListener(addr))
+    }
+}
+
+fn read(path: &Path) -> Result<Vec<u8>> {
+    fs::read(path).map_err(|e| DaemonError::TlsError(format!("{}: {}", path.display(), e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rcgen::{BasicConstraints, CertificateParams, CertifiedIssuer, IsCa, KeyPair};
+    use std::path::PathBuf;
+    use tokio::net::TcpListener;
+    use tokio_stream::wrappers::TcpListenerStream;
+    use tonic::transport::{Channel, ClientTlsConfig};
+    use tonic_health::pb::HealthCheckRequest;
+    use tonic_health::pb::health_client::HealthClient;
+
+    struct Pki {
+        dir: tempfile::TempDir,
+        ca: String,
+        client_cert: String,
+        client_key: String,
+    }
+
+    /// Writes a CA plus server certificate for 127.0.0.1 and returns a client identity.
+    fn pki() -> Pki {
+        let dir = tempfile::tempdir().unwrap();
+
+        let mut ca_params = CertificateParams::new(Vec::<String>::new()).unwrap();
+        ca_params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+        let ca = CertifiedIssuer::self_signed(ca_params, KeyPair::generate().unwrap()).unwrap();
+
+        let signed = |names: &[&str]| {
+            let key = KeyPair::generate().unwrap();
+            let names: Vec<String> = names.iter().map(|name| name.to_string()).collect();
+            let cert = CertificateParams::new(names)
+                .unwrap()
+                .signed_by(&key, &ca)
+                .unwrap();
+            (cert.pem(), key.serialize_pem())
+        };
+
+        let (server_cert, server_key) = signed(&["127.0.0.1"]);
+        fs::write(dir.path().join("server.pem"), server_cert).unwrap();
+        fs::write(dir.path().join("server.key"), server_key).unwrap();
+        fs::write(dir.path().join("ca.pem"), ca.pem()).unwrap();
+
+        let (client_cert, client_key) = signed(&["ffit"]);
+        Pki {
+            dir,
+            ca: ca.pem(),
+            client_cert,
+            client_key,
+        }
+    }
+
+    async fn serve(tls: ServerTlsConfig) -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (_, health) = tonic_health::server::health_reporter();
+        tokio::spawn(
+            tonic::transport::Server::builder()
+                .tls_config(tls)
+                .unwrap()
+                .add_service(health)
+                .serve_with_incoming(TcpListenerStream::new(listener)),
+        );
+        addr
+    }
+
+    async fn check(addr: SocketAddr, tls: ClientTlsConfig) -> bool {
+        let Ok(endpoint) = Channel::from_shared(format!("https://{}", addr))
+            .unwrap()
+            .tls_config(tls)
+        else {
+            return false;
+        };
+        let Ok(channel) = endpoint.connect().await else {
+            return false;
+        };
+        HealthClient::new(channel)
+            .check(HealthCheckRequest::default())
+            .await
+            .is_ok()
+    }
+
+    #[tokio::test]
+    async fn test_mutual_tls_requires_client_certificate() {
+        let pki = pki();
+        let config = DaemonConfig {
+            tls_cert: Some(pki.dir.path().join("server.pem")),
+            tls_key: Some(pki.dir.path().join("server.key")),
+            tls_client_ca: Some(pki.dir.path().join("ca.pem")),
+            ..DaemonConfig::default()
+        };
+        let addr = serve(load(&config).unwrap().unwrap()).await;
+
+        let trusted = ClientTlsConfig::new().ca_certificate(Certificate::from_pem(&pki.ca));
+        let with_identity = trusted
+            .clone()
+            .identity(Identity::from_pem(&pki.client_cert, &pki.client_key));
+        assert!(check(addr, with_identity).await);
+        assert!(!check(addr, trusted).await);
+    }
+
+    #[test]
+    fn test_load_rejects_partial_config() {
+        assert!(load(&DaemonConfig::default()).unwrap().is_none());
+
+        let cert_only = DaemonConfig {
+            tls_cert: Some(PathBuf::from("/nonexistent/server.pem")),
+            ..DaemonConfig::default()
+        };
+        assert!(matches!(load(&cert_only), Err(DaemonError::TlsError(_))));
+
+        let ca_only = DaemonConfig {
+            tls_client_ca: Some(PathBuf::from("/nonexistent/ca.pem")),
+            ..DaemonConfig::default()
+        };
+        assert!(matches!(load(&ca_only), Err(DaemonError::TlsError(_))));
+    }
+
+    #[test]
+    fn test_ensure_secure() {
+        assert!(ensure_secure("127.0.0.1:50051".parse().unwrap(), false).is_ok());
+        assert!(ensure_secure("[::1]:50051".parse().unwrap(), false).is_ok());
+        assert!(ensure_secure("0.0.0.0:50051".parse().unwrap(), true).is_ok());
+        assert!(matches!(
+            ensure_secure("0.0.0.0:50051".parse().unwrap(), false),
+            Err(DaemonError::InsecureListener(_))
+        ));
+    }
+}
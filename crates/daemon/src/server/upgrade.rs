@@ -0,0 +1,314 @@
+//! Zero-downtime upgrades: the running daemon starts its binary again with its
+//! listening sockets and lock file inherited, waits until the new process serves,
+//! then drains and exits without removing the sockets it handed over.
+//!
+//! Descriptors are passed by number in environment variables, like `LISTEN_FDS`,
+//! and readiness is reported by writing to an inherited pipe.
+
+use std::env;
+use std::ffi::OsString;
+use std::io::{self, Read, Write};
+use std::os::fd::{AsRawFd, BorrowedFd, FromRawFd, OwnedFd, RawFd};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use nix::fcntl::{FcntlArg, FdFlag, fcntl};
+use tracing::{debug, warn};
+
+use crate::error::{DaemonError, Result};
+
+const LISTEN_FDS_ENV: &str = "FFIT_UPGRADE_LISTEN_FDS";
+const LOCK_FD_ENV: &str = "FFIT_UPGRADE_LOCK_FD";
+const READY_FD_ENV: &str = "FFIT_UPGRADE_READY_FD";
+
+/// systemd variables that must not reach the new process: its sockets arrive
+/// through the handoff, and the watchdog now watches whichever process is main.
+const SYSTEMD_ENV: &[&str] = &["LISTEN_FDS", "LISTEN_PID", "LISTEN_FDNAMES", "WATCHDOG_PID"];
+
+/// How long the new process has to start serving before the upgrade is abandoned.
+pub const READY_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Set once the inherited descriptors have been claimed, so they are never owned twice.
+static HANDOFF_TAKEN: AtomicBool = AtomicBool::new(false);
+
+/// What a handed-over socket serves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SocketKind {
+    /// A gRPC listener the daemon bound itself and removes on shutdown.
+    Grpc,
+    /// A gRPC listener opened by systemd, which keeps owning its address.
+    Systemd,
+    /// The Prometheus endpoint.
+    Metrics,
+}
+
+impl SocketKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Grpc => "grpc",
+            Self::Systemd => "systemd",
+            Self::Metrics => "metrics",
+        }
+    }
+
+    fn parse(kind: &str) -> Option<Self> {
+        match kind {
+            "grpc" => Some(Self::Grpc),
+            "systemd" => Some(Self::Systemd),
+            "metrics" => Some(Self::Metrics),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct HandedSocket {
+    pub kind: SocketKind,
+    pub fd: OwnedFd,
+}
+
+/// Descriptors received from the daemon being upgraded; empty on a normal start.
+#[derive(Debug, Default)]
+pub struct Handoff {
+    pub sockets: Vec<HandedSocket>,
+    /// Shares the previous daemon's lock on the lock file.
+    pub lock: Option<OwnedFd>,
+    pub ready: Option<ReadySignal>,
+}
+
+impl Handoff {
+    /// Takes ownership of the descriptors the previous daemon passed to this process.
+    /// Returns an empty handoff when called again or when not started by an upgrade.
+    pub fn from_env() -> io::Result<Self> {
+        let Some(lock) = env::var_os(LOCK_FD_ENV) else {
+            return Ok(Self::default());
+        };
+        if HANDOFF_TAKEN.swap(true, Ordering::SeqCst) {
+            return Ok(Self::default());
+        }
+
+        let sockets = parse_sockets(&env::var(LISTEN_FDS_ENV).unwrap_or_default())?;
+        let lock = parse_fd(LOCK_FD_ENV, &lock.to_string_lossy())?;
+        let ready = env::var(READY_FD_ENV)
+            .ok()
+            .map(|fd| parse_fd(READY_FD_ENV, &fd))
+            .transpose()?;
+
+        // SAFETY: the previous daemon passed exactly these descriptors, and
+        // HANDOFF_TAKEN guarantees they are wrapped once.
+        let adopt = |fd: RawFd| -> io::Result<OwnedFd> {
+            let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+            // Keep them out of whatever this process starts, including the next upgrade
+            // until it passes them on explicitly.
+            fcntl(&fd, FcntlArg::F_SETFD(FdFlag::FD_CLOEXEC)).map_err(io::Error::from)?;
+            Ok(fd)
+        };
+        let handoff = Self {
+            sockets: sockets
+                .into_iter()
+                .map(|(fd, kind)| {
+                    Ok(HandedSocket {
+                        kind,
+                        fd: adopt(fd)?,
+                    })
+                })
+                .collect::<io::Result<_>>()?,
+            lock: Some(adopt(lock)?),
+            ready: ready
+                .map(|fd| adopt(fd).map(|fd| ReadySignal(fd.into())))
+                .transpose()?,
+        };
+        debug!(
+            sockets = handoff.sockets.len(),
+            "Received descriptors from previous daemon"
+        );
+        Ok(handoff)
+    }
+
+    pub fn is_upgrade(&self) -> bool {
+        self.lock.is_some()
+    }
+}
+
+/// Tells the previous daemon that this process serves and it may drain.
+#[derive(Debug)]
+pub struct ReadySignal(std::fs::File);
+
+impl ReadySignal {
+    pub fn send(mut self) {
+        if let Err(e) = self.0.write_all(b"1") {
+            warn!(error = %e, "Failed to report readiness to previous daemon");
+        }
+    }
+}
+
+/// Starts `binary` with `args` and the given sockets and lock inherited,
+/// and waits up to `timeout` until it reports that it serves.
+/// Returns the new process's PID; on failure the new process is killed.
+pub async fn spawn_successor(
+    binary: &Path,
+    args: &[OsString],
+    sockets: &[(SocketKind, BorrowedFd<'_>)],
+    lock: BorrowedFd<'_>,
+    timeout: Duration,
+) -> Result<u32> {
+    let failed = |what: &str, e: io::Error| DaemonError::UpgradeError(format!("{}: {}", what, e));
+
+    let passed = sockets
+        .iter()
+        .map(|(kind, fd)| Ok((*kind, inheritable(*fd)?)))
+        .collect::<io::Result<Vec<_>>>()
+        .map_err(|e| failed("cannot pass listeners", e))?;
+    let lock = inheritable(lock).map_err(|e| failed("cannot pass lock file", e))?;
+    let (mut reader, writer) = io::pipe().map_err(|e| failed("cannot create pipe", e))?;
+    let writer = OwnedFd::from(writer);
+    fcntl(&writer, FcntlArg::F_SETFD(FdFlag::empty()))
+        .map_err(|e| failed("cannot create pipe", e.into()))?;
+
+    let listen_fds = format_sockets(passed.iter().map(|(kind, fd)| (fd.as_raw_fd(), *kind)));
+    let mut command = Command::new(binary);
+    command
+        .args(args)
+        .env(LISTEN_FDS_ENV, listen_fds)
+        .env(LOCK_FD_ENV, lock.as_raw_fd().to_string())
+        .env(READY_FD_ENV, writer.as_raw_fd().to_string())
+        .stdin(Stdio::null());
+    for name in SYSTEMD_ENV {
+        command.env_remove(name);
+    }
+    let mut child = command
+        .spawn()
+        .map_err(|e| failed(&format!("cannot start {}", binary.display()), e))?;
+    // Only the child may hold the write end, so its exit reads as end of file.
+    drop((passed, lock, writer));
+    let pid = child.id();
+    debug!(pid, binary = %binary.display(), "Started new daemon, waiting until it serves");
+
+    let ready = tokio::task::spawn_blocking(move || {
+        let mut byte = [0; 1];
+        reader.read(&mut byte)
+    });
+    let error = match tokio::time::timeout(timeout, ready).await {
+        Ok(Ok(Ok(1))) => return Ok(pid),
+        Ok(Ok(Ok(_))) => "exited before serving".to_string(),
+        Ok(Ok(Err(e))) => format!("readiness pipe failed: {}", e),
+        Ok(Err(e)) => e.to_string(),
+        Err(_) => format!("did not serve within {:?}", timeout),
+    };
+
+    let status = tokio::task::spawn_blocking(move || {
+        let _ = child.kill();
+        child.wait()
+    })
+    .await;
+    let status = match status {
+        Ok(Ok(status)) => format!(" ({})", status),
+        _ => String::new(),
+    };
+    Err(DaemonError::UpgradeError(format!(
+        "new daemon (PID {}) {}{}",
+        pid, error, status
+    )))
+}
+
+/// A copy of `fd` that survives exec.
+fn inheritable(fd: BorrowedFd<'_>) -> io::Result<OwnedFd> {
+    let fd = fd.try_clone_to_owned()?;
+    fcntl(&fd, FcntlArg::F_SETFD(FdFlag::empty())).map_err(io::Error::from)?;
+    Ok(fd)
+}
+
+/// `3:grpc,4:systemd,5:metrics`
+fn format_sockets(sockets: impl Iterator<Item = (RawFd, SocketKind)>) -> String {
+    sockets
+        .map(|(fd, kind)| format!("{}:{}", fd, kind.as_str()))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn parse_sockets(value: &str) -> io::Result<Vec<(RawFd, SocketKind)>> {
+    value
+        .split(',')
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            entry
+                .split_once(':')
+                .and_then(|(fd, kind)| Some((fd.parse().ok()?, SocketKind::parse(kind)?)))
+                .ok_or_else(|| invalid(LISTEN_FDS_ENV, entry))
+        })
+        .collect()
+}
+
+fn parse_fd(name: &str, value: &str) -> io::Result<RawFd> {
+    value
+        .parse()
+        .ok()
+        .filter(|fd| *fd > 2)
+        .ok_or_else(|| invalid(name, value))
+}
+
+fn invalid(name: &str, value: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("invalid {} entry '{}'", name, value),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::fd::AsFd;
+
+    #[test]
+    fn test_socket_list_round_trips() {
+        let sockets = vec![
+            (3, SocketKind::Grpc),
+            (4, SocketKind::Systemd),
+            (7, SocketKind::Metrics),
+        ];
+        let value = format_sockets(sockets.iter().copied());
+        assert_eq!(value, "3:grpc,4:systemd,7:metrics");
+        assert_eq!(parse_sockets(&value).unwrap(), sockets);
+
+        assert!(parse_sockets("").unwrap().is_empty());
+        assert!(parse_sockets("3:http").is_err());
+        assert!(parse_fd(LOCK_FD_ENV, "1").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_successor_that_exits_fails_the_upgrade() {
+        let lock = std::fs::File::open("/dev/null").unwrap();
+        let err = spawn_successor(
+            Path::new("/bin/true"),
+            &[],
+            &[],
+            lock.as_fd(),
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("exited before serving"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_successor_reports_ready_through_inherited_pipe() {
+        let lock = std::fs::File::open("/dev/null").unwrap();
+        // The child writes to the descriptor named in the environment, like the daemon does.
+        let args = [
+            "-c".into(),
+            format!("printf 1 > /dev/fd/${}", READY_FD_ENV).into(),
+        ];
+        let pid = spawn_successor(
+            Path::new("/bin/sh"),
+            &args,
+            &[],
+            lock.as_fd(),
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+        assert!(pid > 0);
+    }
+}
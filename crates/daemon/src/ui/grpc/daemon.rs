@@ -0,0 +1,235 @@
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use super::unix_millis;
+use crate::domain::{LogQuery, LogRecord, ShutdownRequest as Shutdown, UpgradeRequest as Upgrade};
+use crate::generated::{
+    GetLogLevelRequest, GetLogLevelResponse, GetStatusRequest, GetStatusResponse, LogField,
+    ReloadConfigRequest, ReloadConfigResponse, SetLogLevelRequest, SetLogLevelResponse,
+    ShutdownRequest, ShutdownResponse, StreamLogsRequest, StreamLogsResponse, UpgradeRequest,
+    UpgradeResponse, daemon_service_server::DaemonService,
+};
+use crate::usecase::{
+    LogLevelUseCase, LogsUseCase, ReloadUseCase, ShutdownUseCase, StatusUseCase, UpgradeUseCase,
+};
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+use tokio_stream::{Stream, StreamExt};
+use tonic::{Request, Response, Status};
+use tracing::{Level, debug, instrument};
+
+type LogStream = Pin<Box<dyn Stream<Item = Result<StreamLogsResponse, Status>> + Send>>;
+
+pub struct DaemonHandler {
+    status_use_case: StatusUseCase,
+    shutdown_use_case: ShutdownUseCase,
+    reload_use_case: Arc<ReloadUseCase>,
+    log_level_use_case: LogLevelUseCase,
+    logs_use_case: LogsUseCase,
+    upgrade_use_case: UpgradeUseCase,
+}
+
+impl DaemonHandler {
+    pub fn new(
+        status_use_case: StatusUseCase,
+        shutdown_use_case: ShutdownUseCase,
+        reload_use_case: Arc<ReloadUseCase>,
+        log_level_use_case: LogLevelUseCase,
+        logs_use_case: LogsUseCase,
+        upgrade_use_case: UpgradeUseCase,
+    ) -> Self {
+        Self {
+            status_use_case,
+            shutdown_use_case,
+            reload_use_case,
+            log_level_use_case,
+            logs_use_case,
+            upgrade_use_case,
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl DaemonService for DaemonHandler {
+    type StreamLogsStream = LogStream;
+
+    #[instrument(skip_all)]
+    async fn get_status(
+        &self,
+        _request: Request<GetStatusRequest>,
+    ) -> Result<Response<GetStatusResponse>, Status> {
+        debug!("Received status request");
+        let status = self.status_use_case.status();
+
+        let config = toml::to_string_pretty(&status.config)
+            .map_err(|e| Status::internal(format!("failed to serialize config: {}", e)))?;
+
+        Ok(Response::new(GetStatusResponse {
+            pid: status.pid,
+            version: status.version.to_string(),
+            git_hash: status.git_hash.to_string(),
+            start_time_unix_secs: status.start_time_unix_secs(),
+            uptime_secs: status.uptime.as_secs(),
+            listeners: status.listeners,
+            config,
+        }))
+    }
+
+    #[instrument(skip_all, fields(reason = %request.get_ref().reason))]
+    async fn shutdown(
+        &self,
+        request: Request<ShutdownRequest>,
+    ) -> Result<Response<ShutdownResponse>, Status> {
+        debug!("Received shutdown request");
+        let req = request.into_inner();
+
+        let drain_timeout = req
+            .drain_timeout_secs
+            .map(|secs| Duration::from_secs(secs.into()));
+        self.shutdown_use_case
+            .shutdown(Shutdown::new(req.reason, drain_timeout));
+
+        Ok(Response::new(ShutdownResponse {}))
+    }
+
+    #[instrument(skip_all)]
+    async fn reload_config(
+        &self,
+        _request: Request<ReloadConfigRequest>,
+    ) -> Result<Response<ReloadConfigResponse>, Status> {
+        debug!("Received reload request");
+        let changes = self.reload_use_case.reload().map_err(Status::from)?;
+
+        Ok(Response::new(ReloadConfigResponse {
+            applied: changes.applied,
+            requires_restart: changes.requires_restart,
+        }))
+    }
+
+    #[instrument(skip_all)]
+    async fn get_log_level(
+        &self,
+        _request: Request<GetLogLevelRequest>,
+    ) -> Result<Response<GetLogLevelResponse>, Status> {
+        let directive = self.log_level_use_case.get().map_err(Status::from)?;
+        Ok(Response::new(GetLogLevelResponse { directive }))
+    }
+
+    #[instrument(skip_all, fields(directive = %request.get_ref().directive))]
+    async fn set_log_level(
+        &self,
+        request: Request<SetLogLevelRequest>,
+    ) -> Result<Response<SetLogLevelResponse>, Status> {
+        debug!("Received set log level request");
+        let directive = request.into_inner().directive;
+
+        let previous = self
+            .log_level_use_case
+            .set(&directive)
+            .map_err(Status::from)?;
+
+        Ok(Response::new(SetLogLevelResponse {
+            previous,
+            directive,
+        }))
+    }
+
+    #[instrument(skip_all, fields(level = %request.get_ref().level, follow = request.get_ref().follow))]
+    async fn stream_logs(
+        &self,
+        request: Request<StreamLogsRequest>,
+    ) -> Result<Response<Self::StreamLogsStream>, Status> {
+        debug!("Received log stream request");
+        let req = request.into_inner();
+
+        let min_level = if req.level.is_empty() {
+            None
+        } else {
+            let level = req.level.parse::<Level>().map_err(|_| {
+                Status::invalid_argument(format!("invalid log level: {}", req.level))
+            })?;
+            Some(level)
+        };
+        let query = LogQuery {
+            min_level,
+            target: Some(req.target).filter(|target| !target.is_empty()),
+            since: req
+                .since_unix_millis
+                .map(|millis| SystemTime::UNIX_EPOCH + Duration::from_millis(millis.max(0) as u64)),
+        };
+
+        let subscription = self
+            .logs_use_case
+            .subscribe(&query, req.replay as usize)
+            .map_err(Status::from)?;
+        let replay =
+            tokio_stream::iter(subscription.replay.into_iter().map(|r| Ok(to_log_entry(r))));
+
+        if !req.follow {
+            return Ok(Response::new(Box::pin(replay)));
+        }
+
+        // `None` marks shutdown and ends the stream.
+        let stop = BroadcastStream::new(subscription.shutdown).map(|_| None);
+        let live = BroadcastStream::new(subscription.live)
+            .map(Some)
+            .merge(stop)
+            .take_while(Option::is_some)
+            .filter_map(move |item| match item? {
+                Ok(record) if query.matches(&record) => Some(Ok(to_log_entry(record))),
+                Ok(_) => None,
+                Err(BroadcastStreamRecvError::Lagged(skipped)) => Some(Ok(lagged_entry(skipped))),
+            });
+
+        Ok(Response::new(Box::pin(replay.chain(live))))
+    }
+
+    #[instrument(skip_all)]
+    async fn upgrade(
+        &self,
+        request: Request<UpgradeRequest>,
+    ) -> Result<Response<UpgradeResponse>, Status> {
+        debug!("Received upgrade request");
+        let drain_timeout = request
+            .into_inner()
+            .drain_timeout_secs
+            .map(|secs| Duration::from_secs(secs.into()));
+
+        let upgraded = self
+            .upgrade_use_case
+            .upgrade(Upgrade { drain_timeout })
+            .await
+            .map_err(Status::from)?;
+
+        Ok(Response::new(UpgradeResponse {
+            previous_pid: upgraded.previous_pid,
+            pid: upgraded.pid,
+        }))
+    }
+}
+
+fn to_log_entry(record: LogRecord) -> StreamLogsResponse {
+    StreamLogsResponse {
+        timestamp_unix_millis: unix_millis(record.timestamp),
+        level: record.level.to_string(),
+        target: record.target,
+        message: record.message,
+        fields: record
+            .fields
+            .into_iter()
+            .map(|(name, value)| LogField { name, value })
+            .collect(),
+    }
+}
+
+/// Tells a slow subscriber that records were dropped instead of silently skipping them.
+fn lagged_entry(skipped: u64) -> StreamLogsResponse {
+    StreamLogsResponse {
+        timestamp_unix_millis: unix_millis(SystemTime::now()),
+        level: Level::WARN.to_string(),
+        target: module_path!().to_string(),
+        message: format!("{} log records dropped, subscriber fell behind", skipped),
+        fields: Vec::new(),
+    }
+}
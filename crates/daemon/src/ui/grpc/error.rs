@@ -8,19 +8,34 @@ impl From<DaemonError> for Status {
             // Client errors (4xx equivalent)
             DaemonError::EmptyMessage => Status::invalid_argument(err.to_string()),
             DaemonError::InvalidAddress(_) => Status::invalid_argument(err.to_string()),
+            DaemonError::InvalidLogFilter(_) => Status::invalid_argument(err.to_string()),
+            DaemonError::InvalidJob(_) => Status::invalid_argument(err.to_string()),
+            DaemonError::JobNotFound(_) => Status::not_found(err.to_string()),
 
             // Precondition failures
             DaemonError::ConfigError(_) => Status::failed_precondition(err.to_string()),
             DaemonError::NoListenersConfigured => Status::failed_precondition(err.to_string()),
+            DaemonError::LogFilterError(_) => Status::failed_precondition(err.to_string()),
+            DaemonError::LogStreamUnavailable(_) => Status::failed_precondition(err.to_string()),
+            DaemonError::TlsError(_) => Status::failed_precondition(err.to_string()),
+            DaemonError::InsecureListener(_) => Status::failed_precondition(err.to_string()),
+            DaemonError::TokenError(_) => Status::failed_precondition(err.to_string()),
+            DaemonError::AuthzError(_) => Status::failed_precondition(err.to_string()),
+            DaemonError::SocketAccessError(_) => Status::failed_precondition(err.to_string()),
+            DaemonError::JobFinished(..) => Status::failed_precondition(err.to_string()),
 
             // Resource conflicts
             DaemonError::AlreadyRunning => Status::already_exists(err.to_string()),
             DaemonError::LockError(_) => Status::unavailable(err.to_string()),
+            DaemonError::UpgradeError(_) => Status::unavailable(err.to_string()),
+            DaemonError::JobsClosed => Status::unavailable(err.to_string()),
 
             // Internal server errors
             DaemonError::DaemonizeError(_) => Status::internal(err.to_string()),
             DaemonError::ReflectionError(_) => Status::internal(err.to_string()),
             DaemonError::IoError(_) => Status::internal(err.to_string()),
+            DaemonError::MetricsError(_) => Status::internal(err.to_string()),
+            DaemonError::TelemetryError(_) => Status::internal(err.to_string()),
         }
     }
 }
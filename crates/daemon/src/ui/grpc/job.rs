@@ -0,0 +1,204 @@
+use std::pin::Pin;
+use std::time::Duration;
+
+use super::unix_millis;
+use crate::domain::{Caller, Job as DomainJob, JobId, JobQuery, JobSpec, JobState as DomainState};
+use crate::generated::{
+    CancelJobRequest, CancelJobResponse, GetJobRequest, GetJobResponse, Job, JobProgress, JobState,
+    ListJobsRequest, ListJobsResponse, SubmitJobRequest, SubmitJobResponse, WaitJobRequest,
+    WaitJobResponse, WatchJobRequest, WatchJobResponse, job_service_server::JobService,
+};
+use crate::usecase::JobsUseCase;
+use tokio::sync::mpsc;
+use tokio_stream::Stream;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Request, Response, Status};
+use tracing::{debug, instrument};
+
+type WatchStream = Pin<Box<dyn Stream<Item = Result<WatchJobResponse, Status>> + Send>>;
+
+pub struct JobHandler {
+    use_case: JobsUseCase,
+}
+
+impl JobHandler {
+    pub fn new(use_case: JobsUseCase) -> Self {
+        Self { use_case }
+    }
+}
+
+#[tonic::async_trait]
+impl JobService for JobHandler {
+    type WatchStream = WatchStream;
+
+    #[instrument(skip_all, fields(kind = %request.get_ref().kind))]
+    async fn submit(
+        &self,
+        request: Request<SubmitJobRequest>,
+    ) -> Result<Response<SubmitJobResponse>, Status> {
+        debug!("Received job submission");
+        let caller = request
+            .extensions()
+            .get::<Caller>()
+            .cloned()
+            .unwrap_or_default();
+        let req = request.into_inner();
+
+        let spec = JobSpec {
+            kind: req.kind,
+            params: req.params.into_iter().collect(),
+            max_retries: req.max_retries,
+            priority: req.priority,
+        };
+        let job = self.use_case.submit(spec, &caller).map_err(Status::from)?;
+
+        Ok(Response::new(SubmitJobResponse {
+            job: Some(to_job(job)),
+        }))
+    }
+
+    #[instrument(skip_all, fields(id = request.get_ref().id))]
+    async fn get(
+        &self,
+        request: Request<GetJobRequest>,
+    ) -> Result<Response<GetJobResponse>, Status> {
+        let job = self
+            .use_case
+            .get(JobId(request.into_inner().id))
+            .map_err(Status::from)?;
+        Ok(Response::new(GetJobResponse {
+            job: Some(to_job(job)),
+        }))
+    }
+
+    #[instrument(skip_all)]
+    async fn list(
+        &self,
+        request: Request<ListJobsRequest>,
+    ) -> Result<Response<ListJobsResponse>, Status> {
+        let req = request.into_inner();
+        let state = JobState::try_from(req.state)
+            .map_err(|_| Status::invalid_argument(format!("invalid job state: {}", req.state)))?;
+        let query = JobQuery {
+            state: from_state(state),
+            limit: Some(req.limit as usize).filter(|limit| *limit > 0),
+        };
+
+        let jobs = self.use_case.list(&query);
+        Ok(Response::new(ListJobsResponse {
+            jobs: jobs.into_iter().map(to_job).collect(),
+        }))
+    }
+
+    #[instrument(skip_all, fields(id = request.get_ref().id))]
+    async fn cancel(
+        &self,
+        request: Request<CancelJobRequest>,
+    ) -> Result<Response<CancelJobResponse>, Status> {
+        debug!("Received job cancellation");
+        let job = self
+            .use_case
+            .cancel(JobId(request.into_inner().id))
+            .map_err(Status::from)?;
+        Ok(Response::new(CancelJobResponse {
+            job: Some(to_job(job)),
+        }))
+    }
+
+    #[instrument(skip_all, fields(id = request.get_ref().id))]
+    async fn wait(
+        &self,
+        request: Request<WaitJobRequest>,
+    ) -> Result<Response<WaitJobResponse>, Status> {
+        let req = request.into_inner();
+        let timeout = req
+            .timeout_secs
+            .map(|secs| Duration::from_secs(secs.into()));
+
+        let job = self
+            .use_case
+            .wait(JobId(req.id), timeout)
+            .await
+            .map_err(Status::from)?;
+        Ok(Response::new(WaitJobResponse {
+            job: Some(to_job(job)),
+        }))
+    }
+
+    #[instrument(skip_all, fields(id = request.get_ref().id))]
+    async fn watch(
+        &self,
+        request: Request<WatchJobRequest>,
+    ) -> Result<Response<Self::WatchStream>, Status> {
+        let id = JobId(request.into_inner().id);
+        let mut watch = self.use_case.watch(id).map_err(Status::from)?;
+
+        let (tx, rx) = mpsc::channel(1);
+        tokio::spawn(async move {
+            loop {
+                let job = tokio::select! {
+                    job = watch.next() => job,
+                    // The client went away.
+                    _ = tx.closed() => break,
+                };
+                let Some(job) = job else {
+                    break;
+                };
+                let response = WatchJobResponse {
+                    job: Some(to_job(job)),
+                };
+                if tx.send(Ok(response)).await.is_err() {
+                    break;
+                }
+            }
+        });
+        Ok(Response::new(Box::pin(ReceiverStream::new(rx))))
+    }
+}
+
+fn to_job(job: DomainJob) -> Job {
+    Job {
+        id: job.id.0,
+        kind: job.spec.kind,
+        params: job.spec.params.into_iter().collect(),
+        state: to_state(job.state).into(),
+        submitter: job.submitter,
+        created_unix_millis: unix_millis(job.created_at),
+        started_unix_millis: job.started_at.map(unix_millis),
+        finished_unix_millis: job.finished_at.map(unix_millis),
+        result: job.result.unwrap_or_default(),
+        error: job.error.unwrap_or_default(),
+        max_retries: job.spec.max_retries,
+        attempts: job.attempts,
+        priority: job.spec.priority,
+        queue_position: job.queue_position.map(|position| position as u32),
+        progress: job.progress.map(|progress| JobProgress {
+            fraction: progress.fraction,
+            phase: progress.phase,
+            metrics: progress.metrics.into_iter().collect(),
+        }),
+    }
+}
+
+fn to_state(state: DomainState) -> JobState {
+    match state {
+        DomainState::Queued => JobState::Queued,
+        DomainState::Running => JobState::Running,
+        DomainState::Succeeded => JobState::Succeeded,
+        DomainState::Failed => JobState::Failed,
+        DomainState::Cancelled => JobState::Cancelled,
+        DomainState::Interrupted => JobState::Interrupted,
+    }
+}
+
+fn from_state(state: JobState) -> Option<DomainState> {
+    match state {
+        JobState::Unspecified => None,
+        JobState::Queued => Some(DomainState::Queued),
+        JobState::Running => Some(DomainState::Running),
+        JobState::Succeeded => Some(DomainState::Succeeded),
+        JobState::Failed => Some(DomainState::Failed),
+        JobState::Cancelled => Some(DomainState::Cancelled),
+        JobState::Interrupted => Some(DomainState::Interrupted),
+    }
+}
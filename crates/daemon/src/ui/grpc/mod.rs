@@ -1,4 +1,16 @@
+mod daemon;
 mod error;
+mod job;
 mod ping;
 
+use std::time::SystemTime;
+
+pub use daemon::DaemonHandler;
+pub use job::JobHandler;
 pub use ping::PingHandler;
+
+fn unix_millis(time: SystemTime) -> i64 {
+    time.duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or_default()
+}
@@ -0,0 +1,205 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap};
+
+use crate::config::JobQuotaConfig;
+use crate::domain::{Job, JobId};
+
+/// Virtual time an owner of weight 1 is charged for starting a job.
+const JOB_COST: u64 = 1 << 32;
+
+/// Queued jobs grouped by owner and started by weighted fair queueing.
+///
+/// Each start charges the owner `JOB_COST / weight` of virtual time, and the
+/// owner with the least goes next, so owners get workers in proportion to
+/// their weights however many jobs each has queued. Priorities only order an
+/// owner's own jobs. An owner with nothing queued or running catches up to
+/// the current virtual time when it returns, so idling builds up no credit.
+#[derive(Default)]
+pub struct FairQueue {
+    owners: BTreeMap<String, Owner>,
+    /// Virtual time of the latest start.
+    clock: u64,
+}
+
+#[derive(Default)]
+struct Owner {
+    /// Highest priority first, then oldest.
+    queued: BTreeSet<(Reverse<i32>, JobId)>,
+    running: usize,
+    /// Virtual time charged so far.
+    used: u64,
+}
+
+impl Owner {
+    fn next(&self) -> Option<JobId> {
+        self.queued.first().map(|(_, id)| *id)
+    }
+}
+
+impl FairQueue {
+    pub fn push(&mut self, job: &Job) {
+        let owner = self.owners.entry(job.owner.clone()).or_default();
+        if owner.queued.is_empty() && owner.running == 0 {
+            owner.used = owner.used.max(self.clock);
+        }
+        owner.queued.insert((Reverse(job.spec.priority), job.id));
+    }
+
+    /// Takes out a job that will not start after all.
+    pub fn remove(&mut self, job: &Job) {
+        if let Some(owner) = self.owners.get_mut(&job.owner) {
+            owner.queued.remove(&(Reverse(job.spec.priority), job.id));
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.owners.values().map(|owner| owner.queued.len()).sum()
+    }
+
+    /// Takes the next job to start among owners below their `max_running`,
+    /// with its owner, counting it as running until [`FairQueue::finish`].
+    pub fn pop(&mut self, quotas: &JobQuotaConfig) -> Option<(JobId, String)> {
+        let (name, owner) = self
+            .owners
+            .iter_mut()
+            .filter(|(name, owner)| {
+                let max_running = quotas.max_running(name);
+                max_running == 0 || owner.running < max_running
+            })
+            .filter_map(|(name, owner)| Some((owner.used, owner.next()?, name, owner)))
+            .min_by_key(|(used, next, ..)| (*used, *next))
+            .map(|(_, _, name, owner)| (name, owner))?;
+
+        let (_, id) = owner.queued.pop_first()?;
+        owner.running += 1;
+        self.clock = self.clock.max(owner.used);
+        owner.used += JOB_COST / u64::from(quotas.weight(name));
+        Some((id, name.clone()))
+    }
+
+    /// Frees the place a job of `owner` took in [`FairQueue::pop`].
+    pub fn finish(&mut self, owner: &str) {
+        if let Some(owner) = self.owners.get_mut(owner) {
+            owner.running = owner.running.saturating_sub(1);
+        }
+    }
+
+    /// The order queued jobs would start in if no owner were at its
+    /// `max_running`, as 1-based positions.
+    pub fn positions(&self, quotas: &JobQuotaConfig) -> HashMap<JobId, usize> {
+        let mut owners: Vec<_> = self
+            .owners
+            .iter()
+            .map(|(name, owner)| {
+                let cost = JOB_COST / u64::from(quotas.weight(name));
+                (cost, owner.queued.iter().map(|(_, id)| *id).peekable())
+            })
+            .collect();
+        let mut heap: BinaryHeap<_> = self
+            .owners
+            .values()
+            .enumerate()
+            .filter_map(|(index, owner)| Some(Reverse((owner.used, owner.next()?, index))))
+            .collect();
+
+        let mut positions = HashMap::new();
+        while let Some(Reverse((used, id, index))) = heap.pop() {
+            positions.insert(id, positions.len() + 1);
+            let (cost, jobs) = &mut owners[index];
+            jobs.next();
+            if let Some(next) = jobs.peek() {
+                heap.push(Reverse((used + *cost, *next, index)));
+            }
+        }
+        positions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::CallerQuota;
+    use crate::domain::{Caller, JobSpec};
+
+    fn job(id: u64, uid: u32, priority: i32) -> Job {
+        let spec = JobSpec {
+            priority,
+            ..JobSpec::default()
+        };
+        let caller = Caller {
+            uid: Some(uid),
+            ..Caller::default()
+        };
+        Job::new(JobId(id), spec, &caller)
+    }
+
+    fn drain(queue: &mut FairQueue, quotas: &JobQuotaConfig) -> Vec<u64> {
+        std::iter::from_fn(|| queue.pop(quotas))
+            .map(|(id, _)| id.0)
+            .collect()
+    }
+
+    #[test]
+    fn test_owners_share_by_weight() {
+        let quotas = JobQuotaConfig {
+            callers: BTreeMap::from([(
+                "uid:2".to_string(),
+                CallerQuota {
+                    weight: Some(2),
+                    ..CallerQuota::default()
+                },
+            )]),
+            ..JobQuotaConfig::default()
+        };
+        let mut queue = FairQueue::default();
+        // uid 1 floods the queue before uid 2 shows up.
+        for id in 1..=6 {
+            queue.push(&job(id, 1, 0));
+        }
+        for id in 7..=10 {
+            queue.push(&job(id, 2, 0));
+        }
+
+        let positions = queue.positions(&quotas);
+        let order = drain(&mut queue, &quotas);
+        assert_eq!(order, [1, 7, 8, 2, 9, 10, 3, 4, 5, 6]);
+        for (index, id) in order.iter().enumerate() {
+            assert_eq!(positions[&JobId(*id)], index + 1);
+        }
+    }
+
+    #[test]
+    fn test_priorities_order_an_owners_jobs() {
+        let quotas = JobQuotaConfig::default();
+        let mut queue = FairQueue::default();
+        queue.push(&job(1, 1, 0));
+        queue.push(&job(2, 1, 5));
+        queue.push(&job(3, 2, 9));
+        queue.push(&job(4, 1, -1));
+        queue.remove(&job(1, 1, 0));
+        assert_eq!(queue.len(), 3);
+        assert_eq!(drain(&mut queue, &quotas), [2, 3, 4]);
+    }
+
+    #[test]
+    fn test_max_running_holds_back_an_owner() {
+        let quotas = JobQuotaConfig {
+            max_running: 1,
+            ..JobQuotaConfig::default()
+        };
+        let mut queue = FairQueue::default();
+        queue.push(&job(1, 1, 0));
+        queue.push(&job(2, 1, 0));
+        queue.push(&job(3, 2, 0));
+        assert_eq!(drain(&mut queue, &quotas), [1, 3]);
+
+        queue.finish("uid:1");
+        assert_eq!(drain(&mut queue, &quotas), [2]);
+        // Idle owners start level with the others instead of with old credit.
+        queue.finish("uid:1");
+        queue.finish("uid:2");
+        queue.push(&job(4, 2, 0));
+        queue.push(&job(5, 3, 0));
+        assert_eq!(drain(&mut queue, &quotas), [4, 5]);
+    }
+}
@@ -0,0 +1,968 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::future::Future;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use compute::{Context, Progress, Reporter, Task};
+use tokio::sync::{Notify, broadcast, watch};
+use tokio::time::Instant;
+use tracing::{debug, info, warn};
+
+use super::fair_queue::FairQueue;
+use super::shutdown::ShutdownSignal;
+use crate::config::DaemonConfig;
+use crate::domain::{Caller, Job, JobId, JobQuery, JobSpec, JobState, ShutdownPhase};
+use crate::error::{DaemonError, Result};
+use crate::infra::job_store::{JobStore, JobStoreWriter};
+
+const CANCELLED_BY_REQUEST: &str = "cancelled by request";
+
+/// Least time between two progress updates from [`JobWatch::next`].
+const WATCH_PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Queues jobs and runs up to `max_concurrent_jobs` of them on blocking
+/// threads, sharing them between callers as `job_quotas` sets out; both are
+/// re-read whenever the configuration is reloaded. Every change is written
+/// to the [`JobStore`], and only the latest `max_finished_jobs` finished jobs
+/// are kept. On shutdown queued jobs stay queued for the next start, and
+/// running ones may finish until the drain timeout; then they are stopped
+/// and settled by [`Job::recover`], as they are on startup after a crash.
+/// Before an upgrade the jobs are [suspended](Self::suspend) instead, so only
+/// one daemon ever runs them.
+#[derive(Clone)]
+pub struct JobsUseCase {
+    jobs: Arc<Jobs>,
+}
+
+struct Jobs {
+    table: Mutex<Table>,
+    /// Wakes the dispatcher when a job is queued or a worker frees up.
+    dispatch: Notify,
+    config: watch::Receiver<DaemonConfig>,
+    shutdown: ShutdownSignal,
+}
+
+struct Table {
+    next_id: u64,
+    entries: BTreeMap<JobId, Entry>,
+    queue: FairQueue,
+    /// Jobs started and not yet finished, watched by [`JobsUseCase::settled`].
+    running: watch::Sender<usize>,
+    /// Set on shutdown or for an upgrade; no jobs are accepted or started afterwards.
+    closed: bool,
+    /// Finished jobs in the order they finished, the first to be pruned in front.
+    finished: VecDeque<JobId>,
+    store: JobStoreWriter,
+}
+
+/// Why a running job's context was cancelled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stop {
+    Request,
+    Shutdown,
+    Upgrade,
+}
+
+struct Entry {
+    job: Job,
+    /// Taken when the job starts; only queued jobs have one.
+    task: Option<Task>,
+    context: Context,
+    /// Set once the job's context is cancelled, and read when its task stops.
+    stop: Option<Stop>,
+    /// Follows `job.state` for `wait` and `watch`.
+    state: watch::Sender<JobState>,
+    /// What the task last reported, for `watch`.
+    progress: watch::Sender<Progress>,
+}
+
+impl Entry {
+    fn new(job: Job, task: Option<Task>) -> Self {
+        let state = watch::Sender::new(job.state);
+        Self {
+            job,
+            task,
+            context: Context::new(),
+            stop: None,
+            state,
+            progress: watch::Sender::new(Progress::default()),
+        }
+    }
+}
+
+impl JobsUseCase {
+    /// Recovers the jobs in `store` and starts the dispatcher, which stops with `shutdown`.
+    /// Must be called inside the tokio runtime.
+    pub fn new(
+        config: watch::Receiver<DaemonConfig>,
+        store: JobStore,
+        shutdown: &ShutdownSignal,
+    ) -> Result<Self> {
+        let table = Table::recover(store, config.borrow().max_finished_jobs)?;
+        let jobs = Arc::new(Jobs {
+            table: Mutex::new(table),
+            dispatch: Notify::new(),
+            config,
+            shutdown: shutdown.clone(),
+        });
+
+        // Taken now so a shutdown during startup still stops the dispatcher.
+        let mut shutdown_rx = shutdown.subscribe();
+        let cancelling = shutdown.reached(ShutdownPhase::Cancelling);
+        let mut config = jobs.config.clone();
+        let dispatcher = Arc::clone(&jobs);
+        tokio::spawn(async move {
+            loop {
+                dispatcher.start_ready();
+                tokio::select! {
+                    _ = dispatcher.dispatch.notified() => {}
+                    // Limits may have been raised.
+                    Ok(()) = config.changed() => {}
+                    _ = shutdown_rx.recv() => break,
+                }
+            }
+            dispatcher.close();
+            // Running jobs get the drain to finish in.
+            cancelling.await;
+            dispatcher.stop_running(Stop::Shutdown);
+        });
+
+        Ok(Self { jobs })
+    }
+
+    pub fn submit(&self, spec: JobSpec, caller: &Caller) -> Result<Job> {
+        let task = Task::parse(&spec.kind, &spec.params)
+            .map_err(|e| DaemonError::InvalidJob(e.to_string()))?;
+
+        let mut table = self.jobs.table();
+        if table.closed {
+            return Err(DaemonError::JobsClosed);
+        }
+        table.next_id += 1;
+        let id = JobId(table.next_id);
+        let mut job = Job::new(id, spec, caller);
+        table.queue.push(&job);
+        table.insert(Entry::new(job.clone(), Some(task)));
+        self.jobs.annotate(&table, std::slice::from_mut(&mut job));
+        drop(table);
+
+        info!(
+            job = %id,
+            kind = %job.spec.kind,
+            submitter = %job.submitter,
+            priority = job.spec.priority,
+            position = job.queue_position,
+            "Job queued"
+        );
+        self.jobs.dispatch.notify_one();
+        Ok(job)
+    }
+
+    pub fn get(&self, id: JobId) -> Result<Job> {
+        self.jobs.job(id)
+    }
+
+    /// Jobs matching `query`, oldest first.
+    pub fn list(&self, query: &JobQuery) -> Vec<Job> {
+        let table = self.jobs.table();
+        let mut jobs: Vec<Job> = table
+            .entries
+            .values()
+            .rev()
+            .map(|entry| &entry.job)
+            .filter(|job| query.matches(job))
+            .take(query.limit.unwrap_or(usize::MAX))
+            .cloned()
+            .collect();
+        jobs.reverse();
+        self.jobs.annotate(&table, &mut jobs);
+        jobs
+    }
+
+    /// Cancels a queued job at once; a running one stops at its next check,
+    /// so the returned job may still be running.
+    pub fn cancel(&self, id: JobId) -> Result<Job> {
+        let mut table = self.jobs.table();
+        let state = table
+            .entries
+            .get(&id)
+            .map(|entry| entry.job.state)
+            .ok_or(DaemonError::JobNotFound(id))?;
+        match state {
+            // Queued jobs are left to the next daemon once jobs are closed.
+            JobState::Queued if table.closed => return Err(DaemonError::JobsClosed),
+            JobState::Queued => {
+                let job = table.entries[&id].job.clone();
+                table.queue.remove(&job);
+                table.update(id, |job| job.cancel(CANCELLED_BY_REQUEST));
+                info!(job = %id, "Cancelled queued job");
+                let job = table.entries[&id].job.clone();
+                table.prune(self.jobs.max_finished());
+                return Ok(job);
+            }
+            JobState::Running => {
+                let entry = table.entries.get_mut(&id).expect("entry was just found");
+                entry.stop.get_or_insert(Stop::Request);
+                entry.context.cancel();
+                info!(job = %id, "Cancelling running job");
+            }
+            state => return Err(DaemonError::JobFinished(id, state)),
+        }
+        Ok(table.entries[&id].job.clone())
+    }
+
+    /// Resolves once the job has finished, or with the job as it is after
+    /// `timeout` or once the daemon starts shutting down.
+    pub async fn wait(&self, id: JobId, timeout: Option<Duration>) -> Result<Job> {
+        let mut shutdown = self.jobs.shutdown.subscribe();
+        let mut state = {
+            let table = self.jobs.table();
+            let entry = table.entries.get(&id).ok_or(DaemonError::JobNotFound(id))?;
+            if table.closed {
+                return Ok(entry.job.clone());
+            }
+            entry.state.subscribe()
+        };
+
+        // Queued jobs stay queued through a shutdown, so it has to release their waiters.
+        let finished = async {
+            tokio::select! {
+                _ = state.wait_for(|state| state.is_terminal()) => {}
+                _ = shutdown.recv() => {}
+            }
+        };
+        match timeout {
+            Some(timeout) => {
+                let _ = tokio::time::timeout(timeout, finished).await;
+            }
+            None => finished.await,
+        }
+        self.get(id)
+    }
+
+    /// Follows a job until it finishes or the daemon shuts down.
+    pub fn watch(&self, id: JobId) -> Result<JobWatch> {
+        let shutdown = self.jobs.shutdown.subscribe();
+        let table = self.jobs.table();
+        let entry = table.entries.get(&id).ok_or(DaemonError::JobNotFound(id))?;
+        Ok(JobWatch {
+            jobs: Arc::clone(&self.jobs),
+            id,
+            state: entry.state.subscribe(),
+            progress: entry.progress.subscribe(),
+            shutdown,
+            last: None,
+            done: false,
+        })
+    }
+
+    /// Waits until every change so far is in the store.
+    pub async fn flush(&self) {
+        let store = self.jobs.table().store.clone();
+        store.flush().await;
+    }
+
+    /// Completes once no job is running. Once jobs are closed, by shutdown
+    /// or [`suspend`](Self::suspend), none start again and this is final.
+    pub fn settled(&self) -> impl Future<Output = ()> + Send + 'static {
+        let mut running = self.jobs.table().running.subscribe();
+        async move {
+            // The sender lives as long as the table.
+            let _ = running.wait_for(|running| *running == 0).await;
+        }
+    }
+
+    /// Hands the jobs over to a new daemon: refuses new ones, puts the
+    /// running ones back in the queue without using up a retry, and returns
+    /// once they have stopped and the store is written, so the new daemon
+    /// recovers every job as it stands and nothing runs twice.
+    pub async fn suspend(&self) {
+        self.jobs.close();
+        self.jobs.stop_running(Stop::Upgrade);
+        self.settled().await;
+        self.flush().await;
+    }
+
+    /// Takes the jobs back after a [`suspend`](Self::suspend) whose upgrade
+    /// failed. The store is read again, as the new daemon may have changed it
+    /// before it gave up.
+    pub fn resume(&self) -> Result<()> {
+        let mut table = self.jobs.table();
+        if self.jobs.shutdown.phase() != ShutdownPhase::Running {
+            // Shutting down meanwhile: the jobs stay suspended, as they would be closed.
+            return Ok(());
+        }
+        let path = table.store.path().to_path_buf();
+        *table = Table::recover(JobStore::open(path)?, self.jobs.max_finished())?;
+        drop(table);
+        info!("Resumed jobs after the upgrade failed");
+        self.jobs.dispatch.notify_one();
+        Ok(())
+    }
+}
+
+/// A job followed by [`JobsUseCase::watch`].
+pub struct JobWatch {
+    jobs: Arc<Jobs>,
+    id: JobId,
+    state: watch::Receiver<JobState>,
+    progress: watch::Receiver<Progress>,
+    shutdown: broadcast::Receiver<()>,
+    /// When the job was last handed out; `None` before the first time.
+    last: Option<Instant>,
+    /// Set once the job has finished or the daemon is shutting down.
+    done: bool,
+}
+
+impl JobWatch {
+    /// The job as it is now, then again whenever its state or progress
+    /// changes, progress at most every [`WATCH_PROGRESS_INTERVAL`]. `None`
+    /// once the job has finished or the daemon is shutting down.
+    pub async fn next(&mut self) -> Option<Job> {
+        if self.done {
+            return None;
+        }
+        if let Some(last) = self.last {
+            let changed = tokio::select! {
+                Ok(()) = self.state.changed() => true,
+                Ok(()) = self.progress.changed() => {
+                    // The job is read afterwards, so a state change meanwhile is not lost.
+                    tokio::time::sleep_until(last + WATCH_PROGRESS_INTERVAL).await;
+                    true
+                }
+                _ = self.shutdown.recv() => false,
+                else => false,
+            };
+            if !changed {
+                self.done = true;
+                return None;
+            }
+        }
+
+        self.state.mark_unchanged();
+        self.progress.mark_unchanged();
+        self.last = Some(Instant::now());
+        let job = self.jobs.job(self.id).ok()?;
+        self.done = job.state.is_terminal() || self.jobs.table().closed;
+        Some(job)
+    }
+}
+
+impl Table {
+    /// Loads the stored jobs, settles those the previous daemon left running,
+    /// queues the rest again and prunes finished ones beyond `max_finished`,
+    /// then compacts the store and hands it to its writer.
+    fn recover(mut store: JobStore, max_finished: usize) -> Result<Self> {
+        let mut entries = BTreeMap::new();
+        let mut queue = FairQueue::default();
+        let mut interrupted = 0;
+        for mut job in store.load()? {
+            if job.state == JobState::Running {
+                job.recover();
+                match job.state {
+                    JobState::Queued => {
+                        info!(job = %job.id, "Requeuing job interrupted by the last shutdown")
+                    }
+                    _ => {
+                        warn!(job = %job.id, "Job was interrupted by the last shutdown and has no retries left");
+                        interrupted += 1;
+                    }
+                }
+            }
+            let mut task = None;
+            if job.state == JobState::Queued {
+                // Tasks are checked again: the kinds may have changed with the binary.
+                match Task::parse(&job.spec.kind, &job.spec.params) {
+                    Ok(parsed) => {
+                        task = Some(parsed);
+                        queue.push(&job);
+                    }
+                    Err(e) => job.fail(format!("invalid job: {}", e)),
+                }
+            }
+            entries.insert(job.id, Entry::new(job, task));
+        }
+
+        let mut finished: Vec<&Job> = entries
+            .values()
+            .map(|entry| &entry.job)
+            .filter(|job| job.state.is_terminal())
+            .collect();
+        finished.sort_by_key(|job| (job.finished_at, job.id));
+        let finished: VecDeque<JobId> = finished.into_iter().map(|job| job.id).collect();
+        let pruned = finished.len().saturating_sub(max_finished.max(1));
+        for id in finished.iter().take(pruned) {
+            entries.remove(id);
+        }
+
+        store.compact(entries.values().map(|entry| &entry.job))?;
+        if !entries.is_empty() {
+            info!(
+                jobs = entries.len(),
+                queued = queue.len(),
+                interrupted,
+                pruned,
+                path = %store.path().display(),
+                "Recovered stored jobs"
+            );
+        }
+        let next_id = store.last_id().0;
+        let store = store.into_writer(entries.values().map(|entry| &entry.job))?;
+        Ok(Self {
+            next_id,
+            entries,
+            queue,
+            running: watch::Sender::new(0),
+            closed: false,
+            finished: finished.into_iter().skip(pruned).collect(),
+            store,
+        })
+    }
+
+    fn insert(&mut self, entry: Entry) {
+        self.store.append(&entry.job);
+        self.entries.insert(entry.job.id, entry);
+    }
+
+    /// Applies `apply` to a job, wakes its waiters and stores the result.
+    fn update(&mut self, id: JobId, apply: impl FnOnce(&mut Job)) {
+        if let Some(entry) = self.entries.get_mut(&id) {
+            let was_finished = entry.job.state.is_terminal();
+            apply(&mut entry.job);
+            entry.state.send_replace(entry.job.state);
+            if !was_finished && entry.job.state.is_terminal() {
+                self.finished.push_back(id);
+            }
+            self.store.append(&entry.job);
+        }
+    }
+
+    /// Drops the jobs that finished first while more than `keep` (at least
+    /// one) have finished.
+    fn prune(&mut self, keep: usize) {
+        while self.finished.len() > keep.max(1) {
+            let Some(id) = self.finished.pop_front() else {
+                break;
+            };
+            self.entries.remove(&id);
+            self.store.remove(id);
+            debug!(job = %id, "Pruned finished job");
+        }
+    }
+}
+
+impl Jobs {
+    fn table(&self) -> MutexGuard<'_, Table> {
+        self.table.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn max_finished(&self) -> usize {
+        self.config.borrow().max_finished_jobs
+    }
+
+    fn job(&self, id: JobId) -> Result<Job> {
+        let table = self.table();
+        let mut job = table
+            .entries
+            .get(&id)
+            .map(|entry| entry.job.clone())
+            .ok_or(DaemonError::JobNotFound(id))?;
+        self.annotate(&table, std::slice::from_mut(&mut job));
+        Ok(job)
+    }
+
+    /// Fills in what is not stored: the queue position of queued jobs and
+    /// the progress of running ones.
+    fn annotate(&self, table: &Table, jobs: &mut [Job]) {
+        for job in jobs.iter_mut() {
+            if job.state == JobState::Running
+                && let Some(entry) = table.entries.get(&job.id)
+            {
+                job.progress = Some(entry.progress.borrow().clone());
+            }
+        }
+        if !jobs.iter().any(|job| job.state == JobState::Queued) {
+            return;
+        }
+        let positions = table.queue.positions(&self.config.borrow().job_quotas);
+        for job in jobs {
+            job.queue_position = positions.get(&job.id).copied();
+        }
+    }
+
+    /// Starts queued jobs while workers are free and their callers are within quota.
+    fn start_ready(self: &Arc<Self>) {
+        let (max_running, quotas) = {
+            let config = self.config.borrow();
+            (config.max_concurrent_jobs.max(1), config.job_quotas.clone())
+        };
+        let mut table = self.table();
+        while !table.closed && *table.running.borrow() < max_running {
+            let Some((id, owner)) = table.queue.pop(&quotas) else {
+                break;
+            };
+            let Some(entry) = table
+                .entries
+                .get_mut(&id)
+                .filter(|entry| entry.task.is_some())
+            else {
+                // Nothing to start after all: give back the place it took.
+                table.queue.finish(&owner);
+                continue;
+            };
+            let task = entry.task.take().expect("entry was just checked");
+            let context = entry.context.clone();
+            let progress = entry.progress.clone();
+            progress.send_replace(Progress::default());
+            let reporter = Reporter::new(move |report| {
+                progress.send_replace(report.clone());
+            });
+            table.update(id, Job::start);
+            table.running.send_modify(|running| *running += 1);
+            debug!(job = %id, kind = task.kind(), "Job started");
+
+            let jobs = Arc::clone(self);
+            tokio::spawn(async move {
+                let outcome =
+                    tokio::task::spawn_blocking(move || task.run(&context, &reporter)).await;
+                jobs.finish(id, outcome);
+            });
+        }
+    }
+
+    fn finish(
+        &self,
+        id: JobId,
+        outcome: std::result::Result<compute::Result<String>, tokio::task::JoinError>,
+    ) {
+        let mut table = self.table();
+        table.running.send_modify(|running| *running -= 1);
+        let Some((stop, owner)) = table
+            .entries
+            .get(&id)
+            .map(|entry| (entry.stop, entry.job.owner.clone()))
+        else {
+            return;
+        };
+        table.queue.finish(&owner);
+        match outcome {
+            Ok(Ok(result)) => table.update(id, |job| job.succeed(result)),
+            Ok(Err(compute::Error::Cancelled)) if stop == Some(Stop::Shutdown) => {
+                table.update(id, Job::recover)
+            }
+            Ok(Err(compute::Error::Cancelled)) if stop == Some(Stop::Upgrade) => {
+                table.update(id, Job::requeue)
+            }
+            Ok(Err(compute::Error::Cancelled)) => {
+                table.update(id, |job| job.cancel(CANCELLED_BY_REQUEST))
+            }
+            Ok(Err(e)) => table.update(id, |job| job.fail(e.to_string())),
+            Err(e) => table.update(id, |job| job.fail(format!("task panicked: {}", e))),
+        }
+        let job = &table.entries[&id].job;
+        match job.state {
+            JobState::Failed => warn!(
+                job = %id,
+                error = job.error.as_deref().unwrap_or_default(),
+                "Job failed"
+            ),
+            JobState::Queued if stop == Some(Stop::Upgrade) => {
+                info!(job = %id, "Job stopped for the upgrade, requeued for the new daemon")
+            }
+            JobState::Queued => {
+                info!(job = %id, "Job stopped for shutdown, requeued for the next start")
+            }
+            state => info!(job = %id, state = %state, "Job finished"),
+        }
+        table.prune(self.max_finished());
+        drop(table);
+        self.dispatch.notify_one();
+    }
+
+    /// Refuses new jobs and stops starting queued ones, which stay in the
+    /// store for the next start.
+    fn close(&self) {
+        self.table().closed = true;
+    }
+
+    /// Cancels the running jobs; each is settled by `finish` as `stop` says.
+    fn stop_running(&self, stop: Stop) {
+        let mut table = self.table();
+        let mut stopped = 0;
+        for entry in table.entries.values_mut() {
+            if entry.job.state == JobState::Running {
+                entry.stop.get_or_insert(stop);
+                entry.context.cancel();
+                stopped += 1;
+            }
+        }
+        if stopped > 0 {
+            match stop {
+                Stop::Upgrade => info!(jobs = stopped, "Stopping running jobs for the upgrade"),
+                _ => info!(jobs = stopped, "Stopping running jobs for shutdown"),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::config::JobQuotaConfig;
+
+    fn spec(kind: &str, params: &[(&str, &str)]) -> JobSpec {
+        JobSpec {
+            kind: kind.to_string(),
+            params: params
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            max_retries: 0,
+            priority: 0,
+        }
+    }
+
+    fn sleep(millis: u64) -> JobSpec {
+        spec("sleep", &[("millis", &millis.to_string())])
+    }
+
+    /// A fresh store file for one test, removed with the returned directory.
+    fn store_path() -> (tempfile::TempDir, PathBuf) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("jobs.jsonl");
+        (dir, path)
+    }
+
+    fn caller(uid: u32) -> Caller {
+        Caller {
+            uid: Some(uid),
+            ..Caller::default()
+        }
+    }
+
+    fn jobs_with(path: &PathBuf, config: DaemonConfig, shutdown: &ShutdownSignal) -> JobsUseCase {
+        let (_, config) = watch::channel(config);
+        JobsUseCase::new(config, JobStore::open(path).unwrap(), shutdown).unwrap()
+    }
+
+    fn jobs_at(path: &PathBuf, workers: usize, shutdown: &ShutdownSignal) -> JobsUseCase {
+        let config = DaemonConfig {
+            max_concurrent_jobs: workers,
+            ..DaemonConfig::default()
+        };
+        jobs_with(path, config, shutdown)
+    }
+
+    #[tokio::test]
+    async fn test_runs_submitted_job() {
+        let shutdown = ShutdownSignal::new();
+        let (_dir, path) = store_path();
+        let jobs = jobs_at(&path, 2, &shutdown);
+
+        let job = jobs
+            .submit(spec("sum", &[("values", "1,2,3")]), &caller(1))
+            .unwrap();
+        assert_eq!(job.state, JobState::Queued);
+
+        let job = jobs.wait(job.id, None).await.unwrap();
+        assert_eq!(job.state, JobState::Succeeded);
+        assert_eq!(job.result.as_deref(), Some("6"));
+        assert!(job.started_at.is_some() && job.finished_at.is_some());
+        assert_eq!(job.attempts, 1);
+
+        let err = jobs
+            .submit(spec("fold", &[]), &Caller::default())
+            .unwrap_err();
+        assert!(matches!(err, DaemonError::InvalidJob(_)));
+    }
+
+    #[tokio::test]
+    async fn test_watch_follows_progress_until_finished() {
+        let shutdown = ShutdownSignal::new();
+        let (_dir, path) = store_path();
+        let jobs = jobs_at(&path, 1, &shutdown);
+        let job = jobs.submit(sleep(500), &Caller::default()).unwrap();
+
+        let mut watch = jobs.watch(job.id).unwrap();
+        let mut seen = Vec::new();
+        while let Some(job) = watch.next().await {
+            seen.push(job);
+        }
+        assert_eq!(seen[0].state, JobState::Queued);
+        assert_eq!(seen[0].queue_position, Some(1));
+        let running: Vec<_> = seen
+            .iter()
+            .filter_map(|job| job.progress.as_ref())
+            .collect();
+        assert!(running.len() > 1 && running.len() < 10);
+        // The task reports its phase right after starting.
+        assert_eq!(running.last().unwrap().phase, "sleep");
+        let last = seen.last().unwrap();
+        assert_eq!(last.state, JobState::Succeeded);
+        assert_eq!(last.progress, None);
+        assert!(matches!(
+            jobs.watch(JobId(99)),
+            Err(DaemonError::JobNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_runs_at_most_workers_at_once() {
+        let shutdown = ShutdownSignal::new();
+        let (_dir, path) = store_path();
+        let jobs = jobs_at(&path, 1, &shutdown);
+        let first = jobs.submit(sleep(60_000), &Caller::default()).unwrap();
+        let second = jobs.submit(sleep(0), &Caller::default()).unwrap();
+
+        let first = jobs
+            .wait(first.id, Some(Duration::from_millis(50)))
+            .await
+            .unwrap();
+        assert_eq!(first.state, JobState::Running);
+        assert_eq!(jobs.get(second.id).unwrap().state, JobState::Queued);
+
+        let cancelled = jobs.cancel(second.id).unwrap();
+        assert_eq!(cancelled.state, JobState::Cancelled);
+        jobs.cancel(first.id).unwrap();
+        let first = jobs.wait(first.id, None).await.unwrap();
+        assert_eq!(first.state, JobState::Cancelled);
+        assert_eq!(first.error.as_deref(), Some(CANCELLED_BY_REQUEST));
+
+        assert!(matches!(
+            jobs.cancel(first.id),
+            Err(DaemonError::JobFinished(_, JobState::Cancelled))
+        ));
+        let query = JobQuery {
+            state: Some(JobState::Cancelled),
+            limit: Some(1),
+        };
+        assert_eq!(jobs.list(&query)[0].id, second.id);
+    }
+
+    #[tokio::test]
+    async fn test_caller_quota_holds_back_its_jobs() {
+        let shutdown = ShutdownSignal::new();
+        let config = DaemonConfig {
+            max_concurrent_jobs: 2,
+            job_quotas: JobQuotaConfig {
+                max_running: 1,
+                ..JobQuotaConfig::default()
+            },
+            ..DaemonConfig::default()
+        };
+        let (_dir, path) = store_path();
+        let jobs = jobs_with(&path, config, &shutdown);
+        let first = jobs.submit(sleep(60_000), &caller(1)).unwrap();
+        let second = jobs.submit(sleep(60_000), &caller(1)).unwrap();
+        assert_eq!(second.queue_position, Some(2));
+        let urgent = JobSpec {
+            priority: 1,
+            ..sleep(60_000)
+        };
+        let urgent = jobs.submit(urgent, &caller(1)).unwrap();
+        let other = jobs
+            .submit(spec("sum", &[("values", "1")]), &caller(2))
+            .unwrap();
+
+        // The urgent job starts first; the second worker then goes to the
+        // other caller rather than to another job of the first.
+        let other = jobs.wait(other.id, None).await.unwrap();
+        assert_eq!(other.state, JobState::Succeeded);
+        let urgent = jobs.get(urgent.id).unwrap();
+        assert_eq!(urgent.state, JobState::Running);
+        assert_eq!(urgent.queue_position, None);
+        assert_eq!(jobs.get(first.id).unwrap().queue_position, Some(1));
+        assert_eq!(jobs.get(second.id).unwrap().queue_position, Some(2));
+
+        jobs.cancel(urgent.id).unwrap();
+        let first = jobs
+            .wait(first.id, Some(Duration::from_millis(100)))
+            .await
+            .unwrap();
+        assert_eq!(first.state, JobState::Running);
+        assert_eq!(jobs.get(second.id).unwrap().queue_position, Some(1));
+        jobs.cancel(first.id).unwrap();
+        jobs.cancel(second.id).unwrap();
+        jobs.wait(first.id, None).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_prunes_jobs_that_finished_first() {
+        let (_dir, path) = store_path();
+        let config = DaemonConfig {
+            max_concurrent_jobs: 1,
+            max_finished_jobs: 3,
+            ..DaemonConfig::default()
+        };
+        let shutdown = ShutdownSignal::new();
+        let jobs = jobs_with(&path, config.clone(), &shutdown);
+        let slow = jobs.submit(sleep(60_000), &Caller::default()).unwrap();
+        let quick: Vec<_> = (0..3)
+            .map(|_| jobs.submit(sleep(0), &Caller::default()).unwrap().id)
+            .collect();
+        // The first quick job finishes before the older slow one; stored
+        // times only keep milliseconds, so recovery needs them apart.
+        jobs.cancel(quick[0]).unwrap();
+        tokio::time::sleep(Duration::from_millis(2)).await;
+        jobs.cancel(slow.id).unwrap();
+        jobs.wait(quick[2], None).await.unwrap();
+        let ids = |jobs: &JobsUseCase| -> Vec<JobId> {
+            jobs.list(&JobQuery::default())
+                .iter()
+                .map(|job| job.id)
+                .collect()
+        };
+        assert_eq!(ids(&jobs), [slow.id, quick[1], quick[2]]);
+        assert!(matches!(
+            jobs.get(quick[0]),
+            Err(DaemonError::JobNotFound(_))
+        ));
+        jobs.flush().await;
+
+        // Pruned jobs stay gone after a restart, and their ids are not reused.
+        let jobs = jobs_with(&path, config, &ShutdownSignal::new());
+        assert_eq!(ids(&jobs), [slow.id, quick[1], quick[2]]);
+        let next = jobs.submit(sleep(0), &Caller::default()).unwrap();
+        assert_eq!(next.id, JobId(5));
+    }
+
+    #[tokio::test]
+    async fn test_pruned_ids_are_not_reissued() {
+        let (_dir, path) = store_path();
+        let config = DaemonConfig {
+            max_concurrent_jobs: 1,
+            max_finished_jobs: 1,
+            ..DaemonConfig::default()
+        };
+        let jobs = jobs_with(&path, config.clone(), &ShutdownSignal::new());
+        let older = jobs.submit(sleep(60_000), &Caller::default()).unwrap();
+        let newest = jobs.submit(sleep(60_000), &Caller::default()).unwrap();
+        // The newest job finishes first and is pruned when the older one does.
+        jobs.cancel(newest.id).unwrap();
+        tokio::time::sleep(Duration::from_millis(2)).await;
+        jobs.cancel(older.id).unwrap();
+        jobs.wait(older.id, None).await.unwrap();
+        assert!(matches!(
+            jobs.get(newest.id),
+            Err(DaemonError::JobNotFound(_))
+        ));
+        jobs.flush().await;
+
+        // Each restart compacts the store, dropping the pruned job's records.
+        for _ in 0..2 {
+            let jobs = jobs_with(&path, config.clone(), &ShutdownSignal::new());
+            assert_eq!(jobs.get(older.id).unwrap().state, JobState::Cancelled);
+            jobs.flush().await;
+        }
+        let jobs = jobs_with(&path, config, &ShutdownSignal::new());
+        let next = jobs.submit(sleep(0), &Caller::default()).unwrap();
+        assert_eq!(next.id, JobId(newest.id.0 + 1));
+    }
+
+    #[tokio::test]
+    async fn test_suspend_hands_jobs_to_the_next_daemon() {
+        let (_dir, path) = store_path();
+        let jobs = jobs_at(&path, 1, &ShutdownSignal::new());
+        let job = jobs.submit(sleep(60_000), &Caller::default()).unwrap();
+        jobs.wait(job.id, Some(Duration::from_millis(50)))
+            .await
+            .unwrap();
+
+        // Requeued without using up its only attempt; nothing else changes.
+        jobs.suspend().await;
+        let suspended = jobs.get(job.id).unwrap();
+        assert_eq!(suspended.state, JobState::Queued);
+        assert_eq!(suspended.attempts, 0);
+        assert!(matches!(
+            jobs.submit(sleep(0), &Caller::default()),
+            Err(DaemonError::JobsClosed)
+        ));
+        assert!(matches!(jobs.cancel(job.id), Err(DaemonError::JobsClosed)));
+
+        // A failed upgrade gives the jobs back.
+        jobs.resume().unwrap();
+        let resumed = jobs
+            .wait(job.id, Some(Duration::from_millis(50)))
+            .await
+            .unwrap();
+        assert_eq!(resumed.state, JobState::Running);
+        assert_eq!(resumed.attempts, 1);
+
+        jobs.suspend().await;
+        let successor = jobs_at(&path, 1, &ShutdownSignal::new());
+        let running = successor
+            .wait(job.id, Some(Duration::from_millis(50)))
+            .await
+            .unwrap();
+        assert_eq!(running.state, JobState::Running);
+        assert_eq!(running.attempts, 1);
+        successor.cancel(job.id).unwrap();
+        successor.settled().await;
+    }
+
+    #[tokio::test]
+    async fn test_jobs_survive_shutdown() {
+        let (_dir, path) = store_path();
+        let shutdown = ShutdownSignal::new();
+        let jobs = jobs_at(&path, 1, &shutdown);
+        let retried = JobSpec {
+            max_retries: 1,
+            ..sleep(60_000)
+        };
+        let retried = jobs.submit(retried, &Caller::default()).unwrap();
+        let queued = jobs.submit(sleep(60_000), &Caller::default()).unwrap();
+        let sum = spec("sum", &[("values", "1")]);
+        let sum = jobs.submit(sum, &Caller::default()).unwrap();
+        jobs.wait(retried.id, Some(Duration::from_millis(50)))
+            .await
+            .unwrap();
+
+        // Running jobs get the drain to finish in, then a retry left requeues
+        // them. The others never ran and stay queued.
+        shutdown.trigger();
+        let settled = jobs.settled();
+        let drain = tokio::time::timeout(Duration::from_millis(50), settled).await;
+        assert!(drain.is_err());
+        assert_eq!(jobs.get(retried.id).unwrap().state, JobState::Running);
+        shutdown.advance(ShutdownPhase::Cancelling);
+        jobs.settled().await;
+        assert_eq!(jobs.get(retried.id).unwrap().state, JobState::Queued);
+        assert_eq!(jobs.get(queued.id).unwrap().state, JobState::Queued);
+        assert!(matches!(
+            jobs.submit(sleep(0), &Caller::default()),
+            Err(DaemonError::JobsClosed)
+        ));
+        jobs.flush().await;
+
+        let shutdown = ShutdownSignal::new();
+        let jobs = jobs_at(&path, 1, &shutdown);
+        let running = jobs
+            .wait(retried.id, Some(Duration::from_millis(50)))
+            .await
+            .unwrap();
+        assert_eq!(running.state, JobState::Running);
+        assert_eq!(running.attempts, 2);
+
+        // Out of retries: interrupted.
+        shutdown.trigger();
+        shutdown.advance(ShutdownPhase::Cancelling);
+        jobs.settled().await;
+        let interrupted = jobs.get(retried.id).unwrap();
+        assert_eq!(interrupted.state, JobState::Interrupted);
+        jobs.flush().await;
+
+        let shutdown = ShutdownSignal::new();
+        let jobs = jobs_at(&path, 2, &shutdown);
+        let recovered = jobs.get(retried.id).unwrap();
+        assert_eq!(recovered.state, JobState::Interrupted);
+        assert_eq!(recovered.error, interrupted.error);
+        let sum = jobs.wait(sum.id, None).await.unwrap();
+        assert_eq!(sum.state, JobState::Succeeded);
+        jobs.cancel(queued.id).unwrap();
+        // Later jobs keep counting up from the stored ones.
+        let next = jobs.submit(sleep(0), &Caller::default()).unwrap();
+        assert_eq!(next.id, JobId(4));
+    }
+}
@@ -0,0 +1,32 @@
+use tracing::info;
+
+use crate::error::{DaemonError, Result};
+use crate::infra::logging::LogFilter;
+
+pub struct LogLevelUseCase {
+    filter: Option<LogFilter>,
+}
+
+impl LogLevelUseCase {
+    pub fn new(filter: Option<LogFilter>) -> Self {
+        Self { filter }
+    }
+
+    pub fn get(&self) -> Result<String> {
+        self.filter()?.current()
+    }
+
+    /// Swaps the active filter until the next restart or `log_level` reload.
+    /// Returns the previous directives.
+    pub fn set(&self, directives: &str) -> Result<String> {
+        let previous = self.filter()?.set(directives)?;
+        info!(previous = %previous, current = %directives, "Log filter changed");
+        Ok(previous)
+    }
+
+    fn filter(&self) -> Result<&LogFilter> {
+        self.filter.as_ref().ok_or_else(|| {
+            DaemonError::LogFilterError("no reloadable subscriber installed".to_string())
+        })
+    }
+}
@@ -0,0 +1,51 @@
+use tokio::sync::broadcast;
+
+use super::shutdown::ShutdownSignal;
+use crate::domain::{LogQuery, LogRecord};
+use crate::error::{DaemonError, Result};
+use crate::infra::logging::LogBroadcast;
+
+pub struct LogsUseCase {
+    broadcast: Option<LogBroadcast>,
+    shutdown: ShutdownSignal,
+}
+
+/// Buffered records matching a query, plus a receiver for records logged afterwards.
+/// Followers should stop once `shutdown` fires so they don't hold the server open.
+pub struct LogSubscription {
+    pub replay: Vec<LogRecord>,
+    pub live: broadcast::Receiver<LogRecord>,
+    pub shutdown: broadcast::Receiver<()>,
+}
+
+impl LogsUseCase {
+    pub fn new(broadcast: Option<LogBroadcast>, shutdown: ShutdownSignal) -> Self {
+        Self {
+            broadcast,
+            shutdown,
+        }
+    }
+
+    /// Subscribes to new records and returns up to `replay` of the latest buffered ones.
+    pub fn subscribe(&self, query: &LogQuery, replay: usize) -> Result<LogSubscription> {
+        let broadcast = self.broadcast.as_ref().ok_or_else(|| {
+            DaemonError::LogStreamUnavailable("no log broadcast layer installed".to_string())
+        })?;
+
+        let shutdown = self.shutdown.subscribe();
+        let (buffered, live) = broadcast.subscribe();
+        let mut replay: Vec<LogRecord> = buffered
+            .into_iter()
+            .rev()
+            .filter(|record| query.matches(record))
+            .take(replay)
+            .collect();
+        replay.reverse();
+
+        Ok(LogSubscription {
+            replay,
+            live,
+            shutdown,
+        })
+    }
+}
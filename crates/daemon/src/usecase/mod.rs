@@ -1,3 +1,18 @@
+mod fair_queue;
+mod jobs;
+mod log_level;
+mod logs;
 mod ping;
+mod reload;
+mod shutdown;
+mod status;
+mod upgrade;
 
+pub use jobs::{JobWatch, JobsUseCase};
+pub use log_level::LogLevelUseCase;
+pub use logs::{LogSubscription, LogsUseCase};
 pub use ping::PingUseCase;
+pub use reload::ReloadUseCase;
+pub use shutdown::{ShutdownSignal, ShutdownUseCase};
+pub use status::StatusUseCase;
+pub use upgrade::{UpgradeCommand, UpgradeUseCase};
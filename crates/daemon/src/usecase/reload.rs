@@ -0,0 +1,198 @@
+use std::net::SocketAddr;
+
+use tokio::sync::watch;
+use tracing::{info, warn};
+
+use crate::config::{ConfigOverrides, DaemonConfig};
+use crate::domain::ConfigChanges;
+use crate::error::{DaemonError, Result};
+use crate::infra::auth::Authorizer;
+use crate::infra::logging::LogFilter;
+
+pub struct ReloadUseCase {
+    config: watch::Sender<DaemonConfig>,
+    overrides: ConfigOverrides,
+    log_filter: Option<LogFilter>,
+}
+
+impl ReloadUseCase {
+    pub fn new(
+        config: watch::Sender<DaemonConfig>,
+        overrides: ConfigOverrides,
+        log_filter: Option<LogFilter>,
+    ) -> Self {
+        Self {
+            config,
+            overrides,
+            log_filter,
+        }
+    }
+
+    /// Re-reads the configuration sources and applies the reloadable changes.
+    pub fn reload(&self) -> Result<ConfigChanges> {
+        let loaded = DaemonConfig::load()?;
+        self.apply(loaded)
+    }
+
+    pub fn apply(&self, loaded: DaemonConfig) -> Result<ConfigChanges> {
+        let current = self.config.borrow().clone();
+        let loaded = loaded.with_overrides(self.overrides);
+
+        let (applied, requires_restart): (Vec<_>, Vec<_>) = current
+            .changed_keys(&loaded)
+            .into_iter()
+            .partition(|key| DaemonConfig::is_reloadable(key));
+        let changes = ConfigChanges {
+            applied,
+            requires_restart,
+        };
+
+        if changes.applied.iter().any(|key| key == "tcp_addr") {
+            // The server rebinds when the new config is published; a listener it
+            // could never serve is rejected here rather than only logged there.
+            validate_tcp_addr(&loaded.tcp_addr, current.tls_cert.is_some())?;
+        }
+
+        if changes.applied.iter().any(|key| key.starts_with("authz.")) {
+            // The server swaps the policy in when the new config is published.
+            Authorizer::validate(&loaded.authz)?;
+        }
+
+        if changes.applied.iter().any(|key| key == "log_level") {
+            // Validate before publishing anything so a bad filter leaves the daemon untouched.
+            LogFilter::parse(&loaded.log_level)?;
+            if let Some(filter) = &self.log_filter {
+                filter.set(&loaded.log_level)?;
+            }
+        }
+
+        if !changes.applied.is_empty() {
+            self.config.send_replace(current.with_reloadable(&loaded));
+        }
+
+        info!(applied = ?changes.applied, "Configuration reloaded");
+        if !changes.requires_restart.is_empty() {
+            warn!(keys = ?changes.requires_restart, "Some changes require a restart");
+        }
+        Ok(changes)
+    }
+}
+
+/// Same rules as at startup: plaintext TCP only on loopback, and on platforms
+/// without Unix sockets TCP is the only listener, so it cannot be turned off.
+fn validate_tcp_addr(tcp_addr: &str, tls: bool) -> Result<()> {
+    if tcp_addr.is_empty() {
+        return if cfg!(unix) {
+            Ok(())
+        } else {
+            Err(DaemonError::NoListenersConfigured)
+        };
+    }
+    let addr: SocketAddr = tcp_addr.parse()?;
+    if tls || addr.ip().is_loopback() {
+        Ok(())
+    } else {
+        Err(DaemonError::InsecureListener(addr))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::LogFormat;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_apply_splits_reloadable_changes() {
+        let overrides = ConfigOverrides {
+            foreground: true,
+            log_format: Some(LogFormat::Json),
+        };
+        let (sender, receiver) = watch::channel(DaemonConfig::default().with_overrides(overrides));
+        let use_case = ReloadUseCase::new(sender, overrides, None);
+
+        let loaded = DaemonConfig {
+            tcp_addr: "127.0.0.1:60000".to_string(),
+            workdir: PathBuf::from("/srv"),
+            log_format: LogFormat::Logfmt,
+            ..DaemonConfig::default()
+        };
+        let changes = use_case.apply(loaded).unwrap();
+
+        assert_eq!(changes.applied, vec!["tcp_addr"]);
+        assert_eq!(changes.requires_restart, vec!["workdir"]);
+
+        let live = receiver.borrow();
+        assert_eq!(live.tcp_addr, "127.0.0.1:60000");
+        assert_eq!(live.workdir, PathBuf::from("/"));
+        assert!(live.foreground);
+        assert_eq!(live.log_format, LogFormat::Json);
+    }
+
+    #[test]
+    fn test_apply_unchanged_config() {
+        let (sender, receiver) = watch::channel(DaemonConfig::default());
+        let use_case = ReloadUseCase::new(sender, ConfigOverrides::default(), None);
+
+        assert!(use_case.apply(DaemonConfig::default()).unwrap().is_empty());
+        assert!(!receiver.has_changed().unwrap_or(true));
+    }
+
+    #[test]
+    fn test_apply_rejects_invalid_log_level() {
+        let (sender, receiver) = watch::channel(DaemonConfig::default());
+        let use_case = ReloadUseCase::new(sender, ConfigOverrides::default(), None);
+
+        let loaded = DaemonConfig {
+            tcp_addr: "127.0.0.1:60000".to_string(),
+            log_level: "daemon=loud".to_string(),
+            ..DaemonConfig::default()
+        };
+
+        assert!(use_case.apply(loaded).is_err());
+        assert_eq!(receiver.borrow().tcp_addr, DaemonConfig::default().tcp_addr);
+    }
+
+    #[test]
+    fn test_apply_rejects_undefined_authz_role() {
+        let (sender, receiver) = watch::channel(DaemonConfig::default());
+        let use_case = ReloadUseCase::new(sender, ConfigOverrides::default(), None);
+
+        let mut loaded = DaemonConfig::default();
+        loaded
+            .authz
+            .roles
+            .insert("admin".to_string(), vec!["*".to_string()]);
+        loaded
+            .authz
+            .bindings
+            .insert("token:ci".to_string(), "reader".to_string());
+
+        assert!(use_case.apply(loaded).is_err());
+        assert!(!receiver.borrow().authz.is_enabled());
+    }
+
+    #[test]
+    fn test_apply_rejects_unservable_tcp_addr() {
+        let (sender, receiver) = watch::channel(DaemonConfig::default());
+        let use_case = ReloadUseCase::new(sender, ConfigOverrides::default(), None);
+
+        for tcp_addr in ["localhost:50051", "0.0.0.0:50051"] {
+            let loaded = DaemonConfig {
+                tcp_addr: tcp_addr.to_string(),
+                ..DaemonConfig::default()
+            };
+            assert!(use_case.apply(loaded).is_err(), "{tcp_addr}");
+        }
+        assert_eq!(receiver.borrow().tcp_addr, DaemonConfig::default().tcp_addr);
+
+        #[cfg(unix)]
+        {
+            let loaded = DaemonConfig {
+                tcp_addr: String::new(),
+                ..DaemonConfig::default()
+            };
+            assert_eq!(use_case.apply(loaded).unwrap().applied, vec!["tcp_addr"]);
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::{broadcast, watch};
+use tracing::info;
+
+use crate::domain::{ShutdownPhase, ShutdownRequest};
+
+/// Tells every part of the daemon that shutdown has begun and which phase it is in.
+#[derive(Clone)]
+pub struct ShutdownSignal {
+    sender: broadcast::Sender<()>,
+    request: Arc<Mutex<Option<ShutdownRequest>>>,
+    phase: Arc<watch::Sender<ShutdownPhase>>,
+}
+
+impl ShutdownSignal {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(1);
+        Self {
+            sender,
+            request: Arc::new(Mutex::new(None)),
+            phase: Arc::new(watch::Sender::new(ShutdownPhase::Running)),
+        }
+    }
+
+    pub fn phase(&self) -> ShutdownPhase {
+        *self.phase.borrow()
+    }
+
+    /// Moves to `phase` unless shutdown is already there or further.
+    /// Returns whether the phase changed.
+    pub fn advance(&self, phase: ShutdownPhase) -> bool {
+        self.phase.send_if_modified(|current| {
+            if phase <= *current {
+                return false;
+            }
+            // Logged before subscribers wake, so the transition precedes what they log.
+            info!(phase = %phase, "Shutdown phase");
+            *current = phase;
+            true
+        })
+    }
+
+    /// Completes once shutdown has reached `phase`.
+    pub fn reached(&self, phase: ShutdownPhase) -> impl Future<Output = ()> + Send + 'static {
+        let mut receiver = self.phase.subscribe();
+        async move {
+            // The sender lives as long as any clone of this signal.
+            let _ = receiver.wait_for(|current| *current >= phase).await;
+        }
+    }
+
+    pub fn trigger(&self) {
+        let _ = self.sender.send(());
+    }
+
+    /// Records why shutdown was requested, then triggers it.
+    /// Only the first request is kept.
+    pub fn request(&self, request: ShutdownRequest) {
+        self.request
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get_or_insert(request);
+        self.trigger();
+    }
+
+    pub fn requested(&self) -> Option<ShutdownRequest> {
+        self.request
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<()> {
+        self.sender.subscribe()
+    }
+
+    pub async fn wait(&self) {
+        let mut receiver = self.subscribe();
+        let _ = receiver.recv().await;
+    }
+}
+
+impl Default for ShutdownSignal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct ShutdownUseCase {
+    signal: ShutdownSignal,
+}
+
+impl ShutdownUseCase {
+    pub fn new(signal: ShutdownSignal) -> Self {
+        Self { signal }
+    }
+
+    pub fn shutdown(&self, request: ShutdownRequest) {
+        info!(
+            reason = %request.reason(),
+            drain_timeout = ?request.drain_timeout(),
+            "Shutdown requested"
+        );
+        self.signal.request(request);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_request_keeps_first_reason_and_triggers() {
+        let signal = ShutdownSignal::new();
+        let mut receiver = signal.subscribe();
+
+        signal.request(ShutdownRequest::new("upgrade", None));
+        assert!(receiver.recv().await.is_ok());
+
+        signal.request(ShutdownRequest::new("second", None));
+        let request = signal.requested().expect("request recorded");
+        assert_eq!(request.reason(), "upgrade");
+    }
+
+    #[tokio::test]
+    async fn test_phases_only_move_forward() {
+        let signal = ShutdownSignal::new();
+        let cleaning_up = tokio::spawn(signal.reached(ShutdownPhase::CleaningUp));
+
+        assert!(signal.advance(ShutdownPhase::Draining));
+        assert!(!signal.advance(ShutdownPhase::Draining));
+        assert_eq!(signal.phase(), ShutdownPhase::Draining);
+
+        // A second signal skips cancelling; the drain timeout elapsing afterwards changes nothing.
+        assert!(signal.advance(ShutdownPhase::CleaningUp));
+        assert!(!signal.advance(ShutdownPhase::Cancelling));
+        assert_eq!(signal.phase(), ShutdownPhase::CleaningUp);
+        cleaning_up.await.unwrap();
+    }
+}
@@ -0,0 +1,60 @@
+use std::time::{Instant, SystemTime};
+
+use tokio::sync::watch;
+
+use crate::config::{DaemonConfig, GIT_HASH, VERSION};
+use crate::domain::DaemonStatus;
+
+pub struct StatusUseCase {
+    started_at: SystemTime,
+    started: Instant,
+    listeners: watch::Receiver<Vec<String>>,
+    config: watch::Receiver<DaemonConfig>,
+}
+
+impl StatusUseCase {
+    pub fn new(
+        config: watch::Receiver<DaemonConfig>,
+        listeners: watch::Receiver<Vec<String>>,
+    ) -> Self {
+        Self {
+            started_at: SystemTime::now(),
+            started: Instant::now(),
+            listeners,
+            config,
+        }
+    }
+
+    pub fn status(&self) -> DaemonStatus {
+        DaemonStatus {
+            pid: std::process::id(),
+            version: VERSION,
+            git_hash: GIT_HASH,
+            started_at: self.started_at,
+            uptime: self.started.elapsed(),
+            listeners: self.listeners.borrow().clone(),
+            config: self.config.borrow().redacted(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_reports_process_and_listeners() {
+        let (_config_tx, config) = watch::channel(DaemonConfig::default());
+        let (listeners_tx, listeners) = watch::channel(vec!["tcp://127.0.0.1:50051".to_string()]);
+        let use_case = StatusUseCase::new(config, listeners);
+        let status = use_case.status();
+
+        assert_eq!(status.pid, std::process::id());
+        assert_eq!(status.version, VERSION);
+        assert_eq!(status.listeners, vec!["tcp://127.0.0.1:50051"]);
+        assert!(status.start_time_unix_secs() > 0);
+
+        listeners_tx.send_replace(vec!["tcp://127.0.0.1:60000".to_string()]);
+        assert_eq!(use_case.status().listeners, vec!["tcp://127.0.0.1:60000"]);
+    }
+}
@@ -0,0 +1,67 @@
+use tokio::sync::{mpsc, oneshot};
+use tracing::info;
+
+use crate::domain::{UpgradeRequest, Upgraded};
+use crate::error::{DaemonError, Result};
+
+/// An upgrade for the server loop, which owns the listeners being handed over.
+pub struct UpgradeCommand {
+    pub request: UpgradeRequest,
+    pub reply: oneshot::Sender<Result<Upgraded>>,
+}
+
+pub struct UpgradeUseCase {
+    commands: mpsc::Sender<UpgradeCommand>,
+}
+
+impl UpgradeUseCase {
+    pub fn new(commands: mpsc::Sender<UpgradeCommand>) -> Self {
+        Self { commands }
+    }
+
+    /// Resolves once the new process serves, or with the reason it did not.
+    pub async fn upgrade(&self, request: UpgradeRequest) -> Result<Upgraded> {
+        info!(drain_timeout = ?request.drain_timeout, "Upgrade requested");
+        let (reply, result) = oneshot::channel();
+        // The server loop stops taking commands once it begins shutting down.
+        let shutting_down = || DaemonError::UpgradeError("daemon is shutting down".to_string());
+        self.commands
+            .try_send(UpgradeCommand { request, reply })
+            .map_err(|e| match e {
+                mpsc::error::TrySendError::Full(_) => {
+                    DaemonError::UpgradeError("an upgrade is already in progress".to_string())
+                }
+                mpsc::error::TrySendError::Closed(_) => shutting_down(),
+            })?;
+        result.await.map_err(|_| shutting_down())?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_forwards_to_server_loop() {
+        let (commands, mut received) = mpsc::channel(1);
+        let use_case = UpgradeUseCase::new(commands);
+
+        let server = tokio::spawn(async move {
+            let command: UpgradeCommand = received.recv().await.unwrap();
+            let _ = command.reply.send(Ok(Upgraded {
+                previous_pid: 1,
+                pid: 2,
+            }));
+            // Dropping the receiver is what a loop that stopped looks like.
+        });
+        let upgraded = use_case.upgrade(UpgradeRequest::default()).await.unwrap();
+        assert_eq!(upgraded.pid, 2);
+        server.await.unwrap();
+
+        let err = use_case
+            .upgrade(UpgradeRequest::default())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("shutting down"));
+    }
+}